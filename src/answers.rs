@@ -0,0 +1,355 @@
+//! Known-good answers from `answers.toml`, checked by the runner, the day binaries and `verify`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::solution::{Answer, Part};
+use crate::toml::{self, Value};
+
+/// `answers.toml` at the project root.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+/// Outcome of checking an answer against the registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "PASS"),
+            Verdict::Wrong { .. } => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "SKIP"),
+        }
+    }
+}
+
+/// Expected answers keyed by day and part, compared as displayed text.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Answers {
+    expected: BTreeMap<(u8, Part), String>,
+}
+
+impl Answers {
+    /// Parse `[dayXX]` sections holding `part1` / `part2` integers or strings.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let document = toml::parse(text)?;
+        let mut expected = BTreeMap::new();
+
+        for section in &document.sections {
+            if section.name.is_empty() && section.entries.is_empty() {
+                continue;
+            }
+            let day = section
+                .name
+                .strip_prefix("day")
+                .and_then(|n| n.parse::<u8>().ok())
+                .ok_or_else(|| format!("invalid section `{}`, expected `dayXX`", section.name))?;
+
+            for (key, value) in &section.entries {
+                let part = match key.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(format!("day{day:02}: unknown key `{key}`")),
+                };
+                let answer = match value {
+                    Value::Integer(n) => n.to_string(),
+                    Value::String(s) => s.clone(),
+                    _ => return Err(format!("day{day:02}.{key}: expected integer or string")),
+                };
+                expected.insert((day, part), answer);
+            }
+        }
+
+        Ok(Self { expected })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| format!("failed to read {}: {error}", path.display()))?;
+        Self::parse(&text).map_err(|error| format!("{}: {error}", path.display()))
+    }
+
+    pub fn load_default() -> Result<Self, String> {
+        Self::load(&default_path())
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.expected.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Drop the answers for `day`, e.g. while it runs with non-default parameters.
+    pub fn forget(&mut self, day: u8) {
+        self.expected.retain(|&(d, _), _| d != day);
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer.to_string() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    /// Fail with one line per wrong answer; unknown answers pass.
+    pub fn check_all(&self, day: u8, results: &[(Part, Answer)]) -> Result<(), String> {
+        let wrong: Vec<String> = results
+            .iter()
+            .filter_map(|(part, answer)| match self.check(day, *part, answer) {
+                Verdict::Wrong { expected } => Some(format!(
+                    "day {day:02} part {part}: got {answer}, expected {expected}"
+                )),
+                _ => None,
+            })
+            .collect();
+
+        if wrong.is_empty() {
+            Ok(())
+        } else {
+            Err(wrong.join("\n"))
+        }
+    }
+
+    /// The README "Progress" table for `days`, with `-` for missing answers.
+    pub fn progress_table(&self, days: impl IntoIterator<Item = u8>) -> String {
+        let mut table = String::from("| Day | Part 1 | Part 2 |\n|-----|--------|--------|\n");
+        for day in days {
+            let answer = |part| self.get(day, part).unwrap_or("-");
+            table += &format!(
+                "| {day:02}  | {:<6} | {:<6} |\n",
+                answer(Part::One),
+                answer(Part::Two)
+            );
+        }
+        table
+    }
+}
+
+/// Replace the first table under `## Progress` in `readme` with `table`.
+///
+/// Text between the heading and the table is kept; without a table, one is added right
+/// below the heading.
+pub fn replace_progress_table(readme: &str, table: &str) -> Result<String, String> {
+    let heading = readme
+        .find("## Progress\n")
+        .ok_or("README has no `## Progress` section")?;
+    let body = heading + "## Progress\n".len();
+
+    let mut start = None;
+    let mut end = readme.len();
+    let mut offset = body;
+    for line in readme[body..].split_inclusive('\n') {
+        match (start, line.starts_with('|')) {
+            (None, true) => start = Some(offset),
+            (Some(_), false) => {
+                end = offset;
+                break;
+            }
+            (None, false) if line.starts_with('#') => break,
+            _ => {}
+        }
+        offset += line.len();
+    }
+
+    Ok(match start {
+        Some(start) => format!("{}{table}{}", &readme[..start], &readme[end..]),
+        None => format!("{}\n{table}{}", &readme[..body], &readme[body..]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::temp::Temp;
+
+    use super::*;
+
+    const SAMPLE: &str = "\
+[day01]
+part1 = 1105
+part2 = 6599
+
+[day12]
+part1 = 595
+part2 = \"N/A\"
+";
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(SAMPLE).unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("1105"));
+        assert_eq!(answers.get(12, Part::Two), Some("N/A"));
+        assert_eq!(answers.get(2, Part::One), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Answers::parse("[days]\npart1 = 1"),
+            Err("invalid section `days`, expected `dayXX`".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[day03]\npart3 = 1"),
+            Err("day03: unknown key `part3`".to_string())
+        );
+        assert_eq!(
+            Answers::parse("[day03]\npart1 = true"),
+            Err("day03.part1: expected integer or string".to_string())
+        );
+        assert_eq!(
+            Answers::parse("part1 = 1"),
+            Err("invalid section ``, expected `dayXX`".to_string())
+        );
+        assert!(Answers::parse("[day01").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(SAMPLE).unwrap();
+        assert_eq!(
+            answers.check(1, Part::One, &Answer::Int(1105)),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.check(1, Part::Two, &Answer::Int(6600)),
+            Verdict::Wrong {
+                expected: "6599".to_string()
+            }
+        );
+        assert_eq!(
+            answers.check(12, Part::Two, &Answer::NotApplicable),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.check(5, Part::One, &Answer::Int(1)),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn test_check_all() {
+        let answers = Answers::parse(SAMPLE).unwrap();
+        assert_eq!(
+            answers.check_all(1, &[(Part::One, Answer::Int(1105))]),
+            Ok(())
+        );
+        assert_eq!(answers.check_all(7, &[(Part::One, Answer::Int(1))]), Ok(()));
+        assert_eq!(
+            answers.check_all(
+                1,
+                &[(Part::One, Answer::Int(1)), (Part::Two, Answer::Int(2))]
+            ),
+            Err(
+                "day 01 part 1: got 1, expected 1105\nday 01 part 2: got 2, expected 6599"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_verdict_display() {
+        assert_eq!(Verdict::Correct.to_string(), "PASS");
+        assert_eq!(
+            Verdict::Wrong {
+                expected: "1".to_string()
+            }
+            .to_string(),
+            "FAIL"
+        );
+        assert_eq!(Verdict::Unknown.to_string(), "SKIP");
+    }
+
+    #[test]
+    fn test_load_default_covers_every_day() {
+        let answers = Answers::load_default().unwrap();
+        for day in 1..=12 {
+            assert!(answers.get(day, Part::One).is_some(), "day {day}");
+            assert!(answers.get(day, Part::Two).is_some(), "day {day}");
+        }
+        assert_eq!(answers.get(8, Part::One), Some("115885"));
+    }
+
+    #[test]
+    fn test_load_errors() {
+        let error = Answers::load(Path::new("does/not/exist.toml")).unwrap_err();
+        assert!(error.starts_with("failed to read does/not/exist.toml"));
+
+        let path = Temp::file("answers.toml", "[bad]\n");
+        let error = Answers::load(&path).unwrap_err();
+        assert!(error.ends_with(": invalid section `bad`, expected `dayXX`"));
+    }
+
+    #[test]
+    fn test_progress_table() {
+        let answers = Answers::parse(SAMPLE).unwrap();
+        assert_eq!(
+            answers.progress_table([1, 2, 12]),
+            "\
+| Day | Part 1 | Part 2 |
+|-----|--------|--------|
+| 01  | 1105   | 6599   |
+| 02  | -      | -      |
+| 12  | 595    | N/A    |
+"
+        );
+    }
+
+    #[test]
+    fn test_progress_table_matches_readme() {
+        let readme =
+            std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("docs/README.md"))
+                .unwrap();
+        let table = Answers::load_default().unwrap().progress_table(1..=12);
+        assert_eq!(replace_progress_table(&readme, &table), Ok(readme));
+    }
+
+    #[test]
+    fn test_replace_progress_table() {
+        let readme = "# T\n\n## Progress\n\n| old |\n|-----|\n\n## Next\ntext\n";
+        assert_eq!(
+            replace_progress_table(readme, "| new |\n"),
+            Ok("# T\n\n## Progress\n\n| new |\n\n## Next\ntext\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_replace_progress_table_at_end() {
+        let readme = "## Progress\n\n| old |\n| row |";
+        assert_eq!(
+            replace_progress_table(readme, "| new |\n"),
+            Ok("## Progress\n\n| new |\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_replace_progress_table_keeps_intro() {
+        let readme = "## Progress\n\nGenerated.\n\n| old |\n\nAfter.\n";
+        assert_eq!(
+            replace_progress_table(readme, "| new |\n"),
+            Ok("## Progress\n\nGenerated.\n\n| new |\n\nAfter.\n".to_string())
+        );
+    }
+
+    #[test]
+    fn test_replace_progress_table_without_table() {
+        assert_eq!(
+            replace_progress_table("## Progress\nSoon.\n", "| new |\n"),
+            Ok("## Progress\n\n| new |\nSoon.\n".to_string())
+        );
+        assert_eq!(
+            replace_progress_table("## Progress\n\n## Other\n| x |\n", "| new |\n"),
+            Ok("## Progress\n\n| new |\n\n## Other\n| x |\n".to_string())
+        );
+        assert_eq!(
+            replace_progress_table("# Nothing\n", "| new |\n"),
+            Err("README has no `## Progress` section".to_string())
+        );
+    }
+}
@@ -0,0 +1,434 @@
+//! Repeated timing of parsing and each part, with JSON baselines to catch regressions.
+
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::ParseError;
+use crate::json::{self, Value};
+use crate::params::Params;
+use crate::solution::Solution;
+
+/// Summary statistics over a set of timing samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Statistics for `samples`, which must not be empty.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  mean {:>10.2?}  stddev {:>10.2?}",
+            self.min, self.median, self.mean, self.stddev
+        )
+    }
+}
+
+/// One timed stage of solving a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        }
+    }
+}
+
+/// Timings of every phase of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayBench {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayBench {
+    pub fn stats(&self, phase: Phase) -> &Stats {
+        match phase {
+            Phase::Parse => &self.parse,
+            Phase::Part1 => &self.part1,
+            Phase::Part2 => &self.part2,
+        }
+    }
+}
+
+/// Time parsing and both parts of `S` with `params` over `iterations` runs each.
+pub fn measure<S: Solution>(
+    input: &str,
+    iterations: usize,
+    params: &Params,
+) -> Result<DayBench, ParseError> {
+    let parsed = S::parse(input)?;
+
+    Ok(DayBench {
+        day: S::DAY,
+        iterations,
+        parse: sample(iterations, || {
+            let _ = black_box(S::parse(black_box(input)));
+        }),
+        part1: sample(iterations, || {
+            black_box(S::part1_with(black_box(&parsed), params));
+        }),
+        part2: sample(iterations, || {
+            black_box(S::part2_with(black_box(&parsed), params));
+        }),
+    })
+}
+
+fn sample(iterations: usize, mut f: impl FnMut()) -> Stats {
+    let samples: Vec<Duration> = (0..iterations.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&samples)
+}
+
+/// Human-readable report for one day.
+pub fn report(bench: &DayBench, title: &str) -> String {
+    let mut output = format!(
+        "Day {:02}: {} ({} iterations)\n",
+        bench.day, title, bench.iterations
+    );
+    for phase in Phase::ALL {
+        output += &format!("  {:<6} {}\n", phase.name(), bench.stats(phase));
+    }
+    output
+}
+
+/// Serialize results as a baseline document.
+pub fn to_json(benches: &[DayBench]) -> String {
+    let days = benches
+        .iter()
+        .map(|bench| {
+            let mut entries = vec![
+                ("day".to_string(), Value::from(bench.day as u64)),
+                (
+                    "iterations".to_string(),
+                    Value::from(bench.iterations as u64),
+                ),
+            ];
+            for phase in Phase::ALL {
+                let stats = bench.stats(phase);
+                let nanos = |d: Duration| Value::from(d.as_nanos() as u64);
+                entries.push((
+                    phase.name().to_string(),
+                    Value::Object(vec![
+                        ("min_ns".to_string(), nanos(stats.min)),
+                        ("median_ns".to_string(), nanos(stats.median)),
+                        ("mean_ns".to_string(), nanos(stats.mean)),
+                        ("stddev_ns".to_string(), nanos(stats.stddev)),
+                    ]),
+                ));
+            }
+            Value::Object(entries)
+        })
+        .collect();
+
+    let document = Value::Object(vec![("days".to_string(), Value::Array(days))]);
+    format!("{document}\n")
+}
+
+/// Read a baseline document written by [`to_json`].
+pub fn from_json(text: &str) -> Result<Vec<DayBench>, String> {
+    let document = json::parse(text)?;
+    let days = document
+        .get("days")
+        .and_then(Value::as_array)
+        .ok_or("baseline has no `days` array")?;
+
+    days.iter()
+        .map(|entry| {
+            let number = |value: Option<&Value>, what: &str| {
+                value
+                    .and_then(Value::as_f64)
+                    .ok_or_else(|| format!("baseline entry is missing `{what}`"))
+            };
+            let stats = |phase: Phase| -> Result<Stats, String> {
+                let object = entry.get(phase.name());
+                let nanos = |key: &str| {
+                    number(object.and_then(|o| o.get(key)), key)
+                        .map(|n| Duration::from_nanos(n as u64))
+                };
+                Ok(Stats {
+                    min: nanos("min_ns")?,
+                    median: nanos("median_ns")?,
+                    mean: nanos("mean_ns")?,
+                    stddev: nanos("stddev_ns")?,
+                })
+            };
+
+            Ok(DayBench {
+                day: number(entry.get("day"), "day")? as u8,
+                iterations: number(entry.get("iterations"), "iterations")? as usize,
+                parse: stats(Phase::Parse)?,
+                part1: stats(Phase::Part1)?,
+                part2: stats(Phase::Part2)?,
+            })
+        })
+        .collect()
+}
+
+/// Median timing of one phase compared against its baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub phase: Phase,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Relative change in percent; positive means slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {:02} {:<6} {:>10.2?} -> {:>10.2?} ({:+.1}%)",
+            self.day,
+            self.phase.name(),
+            self.baseline,
+            self.current,
+            self.change
+        )?;
+        if self.regressed {
+            write!(f, "  REGRESSION")?;
+        }
+        Ok(())
+    }
+}
+
+/// Compare medians of every phase present in both runs.
+///
+/// A phase regresses when its median is more than `threshold` percent slower than the baseline.
+pub fn compare(current: &[DayBench], baseline: &[DayBench], threshold: f64) -> Vec<Comparison> {
+    current
+        .iter()
+        .filter_map(|bench| {
+            let old = baseline.iter().find(|b| b.day == bench.day)?;
+            Some(Phase::ALL.map(|phase| {
+                let before = old.stats(phase).median;
+                let after = bench.stats(phase).median;
+                let change = if before.is_zero() {
+                    0.0
+                } else {
+                    (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0
+                };
+                Comparison {
+                    day: bench.day,
+                    phase,
+                    baseline: before,
+                    current: after,
+                    change,
+                    regressed: change > threshold,
+                }
+            }))
+        })
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day01::Day01;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn stats(median: u64) -> Stats {
+        Stats {
+            min: ms(median - 1),
+            median: ms(median),
+            mean: ms(median),
+            stddev: ms(1),
+        }
+    }
+
+    fn bench(day: u8, medians: [u64; 3]) -> DayBench {
+        DayBench {
+            day,
+            iterations: 5,
+            parse: stats(medians[0]),
+            part1: stats(medians[1]),
+            part2: stats(medians[2]),
+        }
+    }
+
+    #[test]
+    fn test_stats_odd_samples() {
+        let stats = Stats::from_samples(&[ms(3), ms(1), ms(2)]);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(2));
+        assert_eq!(stats.mean, ms(2));
+        let expected = (2.0f64 / 3.0).sqrt() / 1000.0;
+        assert!((stats.stddev.as_secs_f64() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stats_even_samples() {
+        let stats = Stats::from_samples(&[ms(4), ms(1), ms(2), ms(10)]);
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.mean, ms(17) / 4);
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let stats = Stats::from_samples(&[ms(7)]);
+        assert_eq!((stats.min, stats.median, stats.mean), (ms(7), ms(7), ms(7)));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_phase_names() {
+        let names: Vec<&str> = Phase::ALL.iter().map(|p| p.name()).collect();
+        assert_eq!(names, ["parse", "part1", "part2"]);
+    }
+
+    #[test]
+    fn test_measure() {
+        let params = Params::defaults(Day01::PARAMS);
+        let bench = measure::<Day01>("L68\nL30\nR48", 3, &params).unwrap();
+        assert_eq!((bench.day, bench.iterations), (1, 3));
+        assert!(bench.parse.min <= bench.parse.median);
+    }
+
+    #[test]
+    fn test_measure_parse_error() {
+        let params = Params::defaults(Day01::PARAMS);
+        let error = measure::<Day01>("L68\nX30", 3, &params).unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+
+    #[test]
+    fn test_sample_runs_at_least_once() {
+        let mut calls = 0;
+        sample(0, || calls += 1);
+        assert_eq!(calls, 1);
+        sample(4, || calls += 1);
+        assert_eq!(calls, 5);
+    }
+
+    #[test]
+    fn test_report() {
+        let report = report(&bench(3, [2, 3, 4]), "Lobby");
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines[0], "Day 03: Lobby (5 iterations)");
+        assert!(lines[1].starts_with("  parse  min "));
+        assert!(lines[2].contains("median     3.00ms"));
+        assert!(lines[3].starts_with("  part2 "));
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let benches = vec![bench(1, [2, 3, 4]), bench(9, [5, 60, 700])];
+        let text = to_json(&benches);
+        assert!(text.starts_with(r#"{"days":[{"day":1,"iterations":5,"parse":{"min_ns":1000000,"#));
+        assert_eq!(from_json(&text), Ok(benches));
+    }
+
+    #[test]
+    fn test_from_json_errors() {
+        assert!(from_json("not json").is_err());
+        assert_eq!(
+            from_json("{}"),
+            Err("baseline has no `days` array".to_string())
+        );
+        assert_eq!(
+            from_json(r#"{"days":[{"day":1,"iterations":5}]}"#),
+            Err("baseline entry is missing `min_ns`".to_string())
+        );
+        assert_eq!(
+            from_json(r#"{"days":[{"iterations":5}]}"#),
+            Err("baseline entry is missing `day`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_compare_flags_regressions() {
+        let baseline = vec![bench(1, [10, 10, 10])];
+        let current = vec![bench(1, [10, 11, 20])];
+        let comparisons = compare(&current, &baseline, 15.0);
+
+        assert_eq!(comparisons.len(), 3);
+        assert!(!comparisons[0].regressed);
+        assert!(!comparisons[1].regressed);
+        assert!((comparisons[1].change - 10.0).abs() < 1e-9);
+        assert!(comparisons[2].regressed);
+        assert_eq!(comparisons[2].phase, Phase::Part2);
+    }
+
+    #[test]
+    fn test_compare_skips_days_without_baseline() {
+        let comparisons = compare(&[bench(2, [5, 5, 5])], &[bench(1, [5, 5, 5])], 10.0);
+        assert!(comparisons.is_empty());
+    }
+
+    #[test]
+    fn test_compare_zero_baseline() {
+        let mut old = bench(1, [5, 5, 5]);
+        old.part1.median = Duration::ZERO;
+        let comparisons = compare(&[bench(1, [5, 5, 5])], &[old], 10.0);
+        assert_eq!(comparisons[1].change, 0.0);
+        assert!(!comparisons[1].regressed);
+    }
+
+    #[test]
+    fn test_comparison_display() {
+        let comparisons = compare(&[bench(4, [10, 10, 15])], &[bench(4, [10, 10, 10])], 10.0);
+        assert_eq!(
+            comparisons[0].to_string(),
+            "Day 04 parse     10.00ms ->    10.00ms (+0.0%)"
+        );
+        assert_eq!(
+            comparisons[2].to_string(),
+            "Day 04 part2     10.00ms ->    15.00ms (+50.0%)  REGRESSION"
+        );
+    }
+}
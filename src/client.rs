@@ -0,0 +1,705 @@
+//! Advent of Code website client: session settings, polite rate limiting, input downloads
+//! and answer submission.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::history::{Attempt, History};
+use crate::http::{Http, Request, Transport};
+use crate::solution::Part;
+use crate::toml;
+
+pub const YEAR: u16 = 2025;
+/// The 2025 event has twelve puzzles.
+pub const LAST_DAY: u8 = 12;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Minimum time between two requests to the site, across runs.
+pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = "github.com/roman-16/aoc-2025";
+
+/// `.aoc/` at the project root: private, git-ignored state such as `config.toml`.
+pub fn state_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(".aoc")
+}
+
+/// Where requests go and who they are made as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Settings {
+    /// Read `AOC_SESSION` / `AOC_BASE_URL`, falling back to `session` / `base_url` in
+    /// `.aoc/config.toml`.
+    pub fn load() -> Result<Self, String> {
+        let path = state_dir().join("config.toml");
+        let config = match std::fs::read_to_string(&path) {
+            Ok(text) => Some(text),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => None,
+            Err(error) => return Err(format!("failed to read {}: {error}", path.display())),
+        };
+        Self::resolve(
+            std::env::var("AOC_SESSION").ok(),
+            std::env::var("AOC_BASE_URL").ok(),
+            config.as_deref(),
+        )
+        .map_err(|error| format!("{}: {error}", path.display()))
+    }
+
+    /// Combine environment values with the config file text; the environment wins.
+    pub fn resolve(
+        session: Option<String>,
+        base_url: Option<String>,
+        config: Option<&str>,
+    ) -> Result<Self, String> {
+        let document = config.map(toml::parse).transpose()?.unwrap_or_default();
+        let from_config = |key: &str| match document.get("", key) {
+            Some(value) => value
+                .as_str()
+                .map(|s| Some(s.to_string()))
+                .ok_or_else(|| format!("`{key}` must be a string")),
+            None => Ok(None),
+        };
+
+        let session = match session {
+            Some(session) => Some(session),
+            None => from_config("session")?,
+        };
+        let base_url = match base_url {
+            Some(url) => url,
+            None => from_config("base_url")?.unwrap_or_else(|| DEFAULT_BASE_URL.to_string()),
+        };
+
+        Ok(Self {
+            session: session
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty()),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+}
+
+/// Spaces requests at least `interval` apart, remembering the last one in a stamp file so
+/// the limit also holds across separate runs.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    stamp: PathBuf,
+    interval: Duration,
+}
+
+impl RateLimiter {
+    pub fn new(stamp: PathBuf, interval: Duration) -> Self {
+        Self { stamp, interval }
+    }
+
+    /// [`MIN_INTERVAL`] with the stamp in [`state_dir`].
+    pub fn site() -> Self {
+        Self::new(state_dir().join("last-request"), MIN_INTERVAL)
+    }
+
+    /// Sleep until `interval` has passed since the last request, then record a new one.
+    ///
+    /// Returns how long it slept.
+    pub fn wait(&self) -> Result<Duration, String> {
+        let last = std::fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|text| text.trim().parse::<u64>().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        let elapsed = last.map_or(self.interval, |last| {
+            SystemTime::now().duration_since(last).unwrap_or_default()
+        });
+        let delay = self.interval.saturating_sub(elapsed);
+        std::thread::sleep(delay);
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let error = |e: std::io::Error| format!("failed to write {}: {e}", self.stamp.display());
+        if let Some(parent) = self.stamp.parent() {
+            std::fs::create_dir_all(parent).map_err(error)?;
+        }
+        std::fs::write(&self.stamp, now.to_string()).map_err(error)?;
+        Ok(delay)
+    }
+}
+
+/// The site's verdict on a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Submitted too soon after the previous attempt.
+    Wait {
+        seconds: u64,
+    },
+    /// The part was solved before, so the answer was not checked.
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Recognise the verdict in the HTML returned for a submission.
+    pub fn parse(html: &str) -> Option<Self> {
+        if html.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if html.contains("You gave an answer too recently") {
+            Some(Outcome::Wait {
+                seconds: parse_wait(html).unwrap_or(60),
+            })
+        } else if html.contains("You don't seem to be solving the right level") {
+            Some(Outcome::AlreadySolved)
+        } else if html.contains("That's not the right answer") {
+            Some(if html.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if html.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Stable identifier used in the history file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Wrong => "wrong",
+            Outcome::Wait { .. } => "wait",
+            Outcome::AlreadySolved => "already_solved",
+        }
+    }
+
+    pub fn from_name(name: &str, wait_seconds: u64) -> Option<Self> {
+        Some(match name {
+            "correct" => Outcome::Correct,
+            "too_high" => Outcome::TooHigh,
+            "too_low" => Outcome::TooLow,
+            "wrong" => Outcome::Wrong,
+            "wait" => Outcome::Wait {
+                seconds: wait_seconds,
+            },
+            "already_solved" => Outcome::AlreadySolved,
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait { seconds } => write!(f, "too recent, wait {seconds}s"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// Seconds in `You have 4m 39s left to wait`.
+fn parse_wait(html: &str) -> Option<u64> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+    html[start..end]
+        .split_whitespace()
+        .try_fold(0, |total, token| {
+            let (number, unit) = token.split_at(token.len().checked_sub(1)?);
+            let number: u64 = number.parse().ok()?;
+            let scale = match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+            Some(total + number * scale)
+        })
+}
+
+/// Seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+pub struct Client<T: Transport = Http> {
+    transport: T,
+    settings: Settings,
+    limiter: RateLimiter,
+}
+
+impl<T: Transport> Client<T> {
+    pub fn new(transport: T, settings: Settings, limiter: RateLimiter) -> Self {
+        Self {
+            transport,
+            settings,
+            limiter,
+        }
+    }
+
+    fn session(&self) -> Result<&str, String> {
+        self.settings.session.as_deref().ok_or_else(|| {
+            "no session token: set AOC_SESSION or `session` in .aoc/config.toml".to_string()
+        })
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{YEAR}/day/{day}{path}", self.settings.base_url)
+    }
+
+    /// Download the puzzle input for `day`.
+    pub fn input(&self, day: u8) -> Result<String, String> {
+        let session = self.session()?;
+        let url = self.url(day, "/input");
+        self.limiter.wait()?;
+
+        let request = Request::get(&url)
+            .header("Cookie", format!("session={session}"))
+            .header("User-Agent", USER_AGENT);
+        let response = self.transport.send(&request)?;
+        match response.status {
+            200 => Ok(response.body),
+            404 => Err(format!("day {day} is not unlocked yet")),
+            400 | 500 => Err(format!(
+                "session token rejected (HTTP {}); log in again and update it",
+                response.status
+            )),
+            status => Err(format!("unexpected HTTP {status} from {url}")),
+        }
+    }
+
+    /// Post `answer` for `day`/`part` and read the verdict.
+    pub fn answer(&self, day: u8, part: Part, answer: &str) -> Result<Outcome, String> {
+        let session = self.session()?;
+        let url = self.url(day, "/answer");
+        self.limiter.wait()?;
+
+        let level = part.number().to_string();
+        let request = Request::post_form(&url, &[("level", &level), ("answer", answer)])
+            .header("Cookie", format!("session={session}"))
+            .header("User-Agent", USER_AGENT);
+        let response = self.transport.send(&request)?;
+        match response.status {
+            200 => Outcome::parse(&response.body)
+                .ok_or_else(|| format!("unrecognised response from {url}")),
+            400 | 500 => Err(format!(
+                "session token rejected (HTTP {}); log in again and update it",
+                response.status
+            )),
+            status => Err(format!("unexpected HTTP {status} from {url}")),
+        }
+    }
+}
+
+/// Outcome of [`fetch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The file already held an input, so nothing was requested.
+    Cached,
+    Downloaded {
+        bytes: usize,
+    },
+}
+
+/// Download `day`'s input to `path` unless that file already exists. An empty file, such as
+/// the one `new` leaves for the input, counts as missing.
+pub fn fetch<T: Transport>(client: &Client<T>, day: u8, path: &Path) -> Result<Fetched, String> {
+    if std::fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached);
+    }
+    let input = client.input(day)?;
+    let error = |e: std::io::Error| format!("failed to write {}: {e}", path.display());
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(error)?;
+    }
+    std::fs::write(path, &input).map_err(error)?;
+    Ok(Fetched::Downloaded { bytes: input.len() })
+}
+
+/// Submit `answer` unless the attempts in `history` already rule it out, then record the
+/// verdict there.
+pub fn submit<T: Transport>(
+    client: &Client<T>,
+    history: &Path,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Outcome, String> {
+    let mut attempts = History::load(history)?;
+    attempts.check(day, part, answer, now())?;
+
+    let outcome = client.answer(day, part, answer)?;
+    attempts.record(Attempt {
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome,
+        at: now(),
+    });
+    attempts.save(history)?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+    use crate::http::stand_in;
+    use crate::scaffold;
+    use crate::temp::Temp;
+
+    fn client(base_url: &str, stamp: &Temp) -> Client {
+        let settings = Settings {
+            session: Some("abc".to_string()),
+            base_url: base_url.to_string(),
+        };
+        Client::new(
+            Http,
+            settings,
+            RateLimiter::new(stamp.to_path_buf(), Duration::ZERO),
+        )
+    }
+
+    #[test]
+    fn test_settings_from_config() {
+        let config = "session = \"from-file\"\nbase_url = \"http://127.0.0.1:8080/\"\n";
+        assert_eq!(
+            Settings::resolve(None, None, Some(config)),
+            Ok(Settings {
+                session: Some("from-file".to_string()),
+                base_url: "http://127.0.0.1:8080".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_settings_env_wins() {
+        let config = "session = \"from-file\"\nbase_url = \"http://file\"\n";
+        assert_eq!(
+            Settings::resolve(
+                Some(" from-env\n".to_string()),
+                Some("http://env".to_string()),
+                Some(config)
+            ),
+            Ok(Settings {
+                session: Some("from-env".to_string()),
+                base_url: "http://env".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_settings_defaults() {
+        assert_eq!(
+            Settings::resolve(None, None, None),
+            Ok(Settings {
+                session: None,
+                base_url: DEFAULT_BASE_URL.to_string(),
+            })
+        );
+        assert_eq!(
+            Settings::resolve(Some(String::new()), None, None).map(|s| s.session),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_settings_errors() {
+        assert_eq!(
+            Settings::resolve(None, None, Some("session = 1")),
+            Err("`session` must be a string".to_string())
+        );
+        assert_eq!(
+            Settings::resolve(None, None, Some("session")),
+            Err("line 1: expected `key = value`, found `session`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rate_limiter_waits_between_requests() {
+        let stamp = Temp::path("client-stamp-wait");
+        let limiter = RateLimiter::new(stamp.to_path_buf(), Duration::from_millis(200));
+
+        assert_eq!(limiter.wait(), Ok(Duration::ZERO));
+        let start = Instant::now();
+        let delay = limiter.wait().unwrap();
+        assert!(delay > Duration::from_millis(100), "{delay:?}");
+        assert!(start.elapsed() >= delay);
+    }
+
+    #[test]
+    fn test_rate_limiter_ignores_old_or_corrupt_stamp() {
+        let stamp = Temp::path("client-stamp-old");
+        let limiter = RateLimiter::new(stamp.to_path_buf(), Duration::from_secs(60));
+        std::fs::write(&stamp, "1000").unwrap();
+        assert_eq!(limiter.wait(), Ok(Duration::ZERO));
+        std::fs::write(&stamp, "garbage").unwrap();
+        assert_eq!(limiter.wait(), Ok(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_input() {
+        let (url, server) = stand_in(vec![(200, "L68\nL30\n")]);
+        let stamp = Temp::path("client-stamp-input");
+        assert_eq!(client(&url, &stamp).input(1), Ok("L68\nL30\n".to_string()));
+        let request = &server.join().unwrap()[0];
+        assert!(request.starts_with("GET /2025/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc\r\n"));
+        assert!(request.contains(&format!("\r\nUser-Agent: {USER_AGENT}\r\n")));
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let (url, server) = stand_in(vec![
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (503, "busy"),
+        ]);
+        let stamp = Temp::path("client-stamp-errors");
+        let client = client(&url, &stamp);
+        assert_eq!(
+            client.input(12),
+            Err("day 12 is not unlocked yet".to_string())
+        );
+        assert_eq!(
+            client.input(3),
+            Err("session token rejected (HTTP 400); log in again and update it".to_string())
+        );
+        assert_eq!(
+            client.input(3),
+            Err(format!("unexpected HTTP 503 from {url}/2025/day/3/input"))
+        );
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_input_without_session() {
+        let settings =
+            Settings::resolve(None, Some("http://127.0.0.1:1".to_string()), None).unwrap();
+        let client = Client::new(
+            Http,
+            settings,
+            RateLimiter::new(Temp::path("client-unused").to_path_buf(), Duration::ZERO),
+        );
+        assert_eq!(
+            client.input(1),
+            Err("no session token: set AOC_SESSION or `session` in .aoc/config.toml".to_string())
+        );
+    }
+
+    #[test]
+    fn test_fetch_downloads_then_uses_cache() {
+        // The stand-in answers exactly once, so a second download would fail.
+        let (url, server) = stand_in(vec![(200, "3-5\n10-14\n\n1\n")]);
+        let stamp = Temp::path("client-stamp-fetch");
+        let client = client(&url, &stamp);
+        let cache = Temp::path("client-cache");
+        let path = cache.join("day05/input.txt");
+
+        assert_eq!(
+            fetch(&client, 5, &path),
+            Ok(Fetched::Downloaded { bytes: 13 })
+        );
+        assert_eq!(fetch(&client, 5, &path), Ok(Fetched::Cached));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "3-5\n10-14\n\n1\n");
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_fetch_after_new() {
+        let (url, server) = stand_in(vec![(200, "3-5\n\n1\n")]);
+        let stamp = Temp::path("client-stamp-new");
+        let client = client(&url, &stamp);
+        let root = Temp::path("client-new");
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("src/lib.rs"), "pub mod day01;\n").unwrap();
+        std::fs::write(
+            root.join("src/registry.rs"),
+            "use crate::{day01};\n\npub const DAYS: &[Day] = &[\n    Day::of::<day01::Day01>(),\n];\n",
+        )
+        .unwrap();
+        scaffold::generate(&root, 5, "Cafeteria", 0).unwrap();
+        let path = root.join("src/bin/day05/input.txt");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "");
+
+        assert_eq!(
+            fetch(&client, 5, &path),
+            Ok(Fetched::Downloaded { bytes: 7 })
+        );
+        assert_eq!(fetch(&client, 5, &path), Ok(Fetched::Cached));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "3-5\n\n1\n");
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_fetch_error_writes_nothing() {
+        let (url, server) = stand_in(vec![(404, "")]);
+        let stamp = Temp::path("client-stamp-missing");
+        let path = Temp::path("client-missing.txt");
+        assert!(fetch(&client(&url, &stamp), 9, &path).is_err());
+        assert!(!path.exists());
+        server.join().unwrap();
+    }
+
+    const RIGHT: &str =
+        "<article><p>That's the right answer!  You are one gold star closer.</p></article>";
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high.  If you're stuck...</p></article>";
+    const TOO_LOW: &str =
+        "<article><p>That's not the right answer; your answer is too low.</p></article>";
+    const RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 39s left to wait. <a href=\"/2025/day/1\">[Return to Day 1]</a></p></article>";
+    const SOLVED: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+
+    #[test]
+    fn test_outcome_parse() {
+        assert_eq!(Outcome::parse(RIGHT), Some(Outcome::Correct));
+        assert_eq!(Outcome::parse(TOO_HIGH), Some(Outcome::TooHigh));
+        assert_eq!(Outcome::parse(TOO_LOW), Some(Outcome::TooLow));
+        assert_eq!(
+            Outcome::parse("<p>That's not the right answer.  If you're stuck...</p>"),
+            Some(Outcome::Wrong)
+        );
+        assert_eq!(Outcome::parse(RECENT), Some(Outcome::Wait { seconds: 279 }));
+        assert_eq!(
+            Outcome::parse("You gave an answer too recently"),
+            Some(Outcome::Wait { seconds: 60 })
+        );
+        assert_eq!(Outcome::parse(SOLVED), Some(Outcome::AlreadySolved));
+        assert_eq!(Outcome::parse("<html>Log in</html>"), None);
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(parse_wait("You have 39s left to wait."), Some(39));
+        assert_eq!(parse_wait("You have 1h 2m 3s left to wait"), Some(3723));
+        assert_eq!(parse_wait("You have soon left to wait"), None);
+        assert_eq!(parse_wait("You have  left to wait"), Some(0));
+        assert_eq!(parse_wait("nothing"), None);
+    }
+
+    #[test]
+    fn test_outcome_names_round_trip() {
+        for outcome in [
+            Outcome::Correct,
+            Outcome::TooHigh,
+            Outcome::TooLow,
+            Outcome::Wrong,
+            Outcome::Wait { seconds: 30 },
+            Outcome::AlreadySolved,
+        ] {
+            assert_eq!(Outcome::from_name(outcome.name(), 30), Some(outcome));
+        }
+        assert_eq!(Outcome::from_name("maybe", 0), None);
+    }
+
+    #[test]
+    fn test_outcome_display() {
+        assert_eq!(Outcome::TooHigh.to_string(), "too high");
+        assert_eq!(
+            Outcome::Wait { seconds: 5 }.to_string(),
+            "too recent, wait 5s"
+        );
+        assert_eq!(Outcome::AlreadySolved.to_string(), "already solved");
+    }
+
+    #[test]
+    fn test_answer() {
+        let (url, server) = stand_in(vec![(200, RIGHT)]);
+        let stamp = Temp::path("client-stamp-answer");
+        assert_eq!(
+            client(&url, &stamp).answer(7, Part::Two, "1690"),
+            Ok(Outcome::Correct)
+        );
+        let request = &server.join().unwrap()[0];
+        assert!(request.starts_with("POST /2025/day/7/answer HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=1690"));
+    }
+
+    #[test]
+    fn test_answer_errors() {
+        let (url, server) = stand_in(vec![(200, "<html>?</html>"), (500, ""), (302, "")]);
+        let stamp = Temp::path("client-stamp-answer-errors");
+        let client = client(&url, &stamp);
+        assert_eq!(
+            client.answer(1, Part::One, "1"),
+            Err(format!(
+                "unrecognised response from {url}/2025/day/1/answer"
+            ))
+        );
+        assert!(client
+            .answer(1, Part::One, "1")
+            .unwrap_err()
+            .starts_with("session token rejected"));
+        assert!(client
+            .answer(1, Part::One, "1")
+            .unwrap_err()
+            .starts_with("unexpected HTTP 302"));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_submit_records_and_refuses_contradictions() {
+        // Only two responses: the refused submissions must never reach the server.
+        let (url, server) = stand_in(vec![(200, TOO_HIGH), (200, TOO_LOW)]);
+        let stamp = Temp::path("client-stamp-submit");
+        let client = client(&url, &stamp);
+        let history = Temp::path("client-history.json");
+
+        assert_eq!(
+            submit(&client, &history, 1, Part::One, "500"),
+            Ok(Outcome::TooHigh)
+        );
+        assert_eq!(
+            submit(&client, &history, 1, Part::One, "600"),
+            Err("600 is not below 500, which was too high".to_string())
+        );
+        assert_eq!(
+            submit(&client, &history, 1, Part::One, "100"),
+            Ok(Outcome::TooLow)
+        );
+        assert_eq!(
+            submit(&client, &history, 1, Part::One, "100"),
+            Err("100 was already submitted: too low".to_string())
+        );
+        assert_eq!(server.join().unwrap().len(), 2);
+
+        let saved = History::load(&history).unwrap();
+        assert_eq!(saved.attempts().len(), 2);
+        assert_eq!(saved.attempts()[1].outcome, Outcome::TooLow);
+    }
+
+    #[test]
+    fn test_submit_failure_records_nothing() {
+        let (url, server) = stand_in(vec![(404, "")]);
+        let stamp = Temp::path("client-stamp-submit-failure");
+        let history = Temp::path("client-history-failure.json");
+        assert!(submit(&client(&url, &stamp), &history, 2, Part::One, "1").is_err());
+        assert!(!history.exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_state_dir() {
+        assert!(state_dir().ends_with(".aoc"));
+        assert_eq!(RateLimiter::site().interval, MIN_INTERVAL);
+    }
+}
@@ -0,0 +1,698 @@
+//! Day 1: Secret Entrance
+
+use crate::dial::{Dial, DialHistory};
+use crate::error::ParseError;
+use crate::input::{self, InputSource};
+use crate::lock::{Lock, MAX_WHEELS};
+use crate::params::{Config, Param, Params};
+use crate::solution::{Answer, Solution};
+
+/// Where the dial points before the first move.
+pub const START: u64 = 50;
+/// How many numbers the dial has, `0` to `SIZE - 1`.
+pub const SIZE: u64 = 100;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Secret Entrance";
+    type Input<'a> = Vec<(bool, i32)>;
+    const PARAMS: &'static [Param] = &[
+        Param::int(
+            "start",
+            "50",
+            0,
+            i64::MAX,
+            "where the dial points first, taken modulo the size",
+        ),
+        Param::int("size", "100", 1, i64::MAX, "numbers on the dial"),
+        Param::text("markers", "0", "space-separated numbers that count as zero"),
+        Param::int(
+            "repeats",
+            "1",
+            1,
+            i64::MAX,
+            "times the move list is applied in a row",
+        ),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_moves(input)
+    }
+
+    fn part1(moves: &Self::Input<'_>) -> Answer {
+        Self::part1_with(moves, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part2(moves: &Self::Input<'_>) -> Answer {
+        Self::part2_with(moves, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part1_with(moves: &Self::Input<'_>, params: &Params) -> Answer {
+        let repeats = params.int("repeats") as u64;
+        big_answer(count_repeated(moves, dial(params), repeats).landings)
+    }
+
+    fn part2_with(moves: &Self::Input<'_>, params: &Params) -> Answer {
+        let repeats = params.int("repeats") as u64;
+        big_answer(count_repeated(moves, dial(params), repeats).passes)
+    }
+
+    fn check_params(params: &Params) -> Result<(), String> {
+        dial_for(params).map(drop)
+    }
+}
+
+/// The dial `params` describe, failing on markers that are not numbers on it.
+pub fn dial_for(params: &Params) -> Result<Dial, String> {
+    let markers = params
+        .text("markers")
+        .split_whitespace()
+        .map(|marker| {
+            marker
+                .parse()
+                .map_err(|_| format!("parameter `markers`: `{marker}` is not a number"))
+        })
+        .collect::<Result<Vec<u64>, _>>()?;
+    let (size, start) = (params.int("size") as u64, params.int("start") as u64);
+    Dial::new(size, start, markers).map_err(|error| format!("parameter `markers`: {error}"))
+}
+
+/// Day 1's dial as `aoc.toml` and `overrides` set it up, and the moves in `source` as
+/// `parse` reads them: what `query`, `lock` and `run 1 --trace` all start from.
+pub fn load_with<T>(
+    source: &InputSource,
+    overrides: &[(String, String)],
+    parse: impl FnOnce(&str) -> Result<T, ParseError>,
+) -> Result<(Dial, T), String> {
+    let params = Config::load_default()?.params(Day01::DAY, Day01::PARAMS, overrides)?;
+    let dial = dial_for(&params)?;
+    let input = input::load(Day01::DAY, source).map_err(|error| error.to_string())?;
+    let moves = parse(&input).map_err(|error| error.render())?;
+    Ok((dial, moves))
+}
+
+/// [`dial_for`], panicking on bad markers inside a part; [`Day01::check_params`] rejects
+/// them before any part runs.
+fn dial(params: &Params) -> Dial {
+    dial_for(params).unwrap_or_else(|error| panic!("{error}"))
+}
+
+fn big_answer(count: u128) -> Answer {
+    i128::try_from(count)
+        .expect("a count of clicks fits in an i128")
+        .into()
+}
+
+pub fn parse_moves(input: &str) -> Result<Vec<(bool, i32)>, ParseError> {
+    input.lines().map(|line| parse_move(input, line)).collect()
+}
+
+fn parse_move(input: &str, line: &str) -> Result<(bool, i32), ParseError> {
+    let is_left = match line.chars().next() {
+        Some('L') => true,
+        Some('R') => false,
+        Some(c) => {
+            return Err(ParseError::new(
+                Day01::DAY,
+                input,
+                &line[..c.len_utf8()],
+                "direction `L` or `R`",
+            ))
+        }
+        None => {
+            return Err(ParseError::new(
+                Day01::DAY,
+                input,
+                line,
+                "direction `L` or `R`",
+            ))
+        }
+    };
+
+    let distance = &line[1..];
+    match distance.parse() {
+        Ok(distance) if distance >= 0 => Ok((is_left, distance)),
+        _ => Err(ParseError::new(
+            Day01::DAY,
+            input,
+            distance,
+            "distance as a non-negative integer",
+        )),
+    }
+}
+
+/// Parse moves for a multi-wheel lock: `2:L68` turns wheel 2, and a bare `L68` wheel 1.
+pub fn parse_lock_moves(input: &str) -> Result<Vec<(usize, bool, i32)>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (wheel, turn) = match line.split_once(':') {
+                Some((wheel, turn)) => match wheel.parse() {
+                    Ok(number) if (1..=MAX_WHEELS).contains(&number) => (number, turn),
+                    _ => {
+                        return Err(ParseError::new(
+                            Day01::DAY,
+                            input,
+                            wheel,
+                            format!("wheel number from 1 to {MAX_WHEELS}"),
+                        ))
+                    }
+                },
+                None => (1, line),
+            };
+            let (is_left, distance) = parse_move(input, turn)?;
+            Ok((wheel, is_left, distance))
+        })
+        .collect()
+}
+
+/// The highest wheel `moves` turn, or 1 when there are none.
+pub fn wheels_turned(moves: &[(usize, bool, i32)]) -> usize {
+    moves.iter().map(|&(wheel, _, _)| wheel).max().unwrap_or(1)
+}
+
+/// Turn a lock of `wheels` copies of `dial` through `moves`, which number wheels from 1.
+pub fn run_lock(moves: &[(usize, bool, i32)], dial: &Dial, wheels: usize) -> Result<Lock, String> {
+    if wheels > MAX_WHEELS {
+        return Err(format!(
+            "a lock has at most {MAX_WHEELS} wheels, not {wheels}"
+        ));
+    }
+    if let Some(&(wheel, _, _)) = moves.iter().find(|&&(wheel, _, _)| wheel > wheels) {
+        return Err(format!(
+            "a move turns wheel {wheel}, but the lock has {wheels} wheels"
+        ));
+    }
+    let mut lock = Lock::new(vec![dial.clone(); wheels])?;
+    for &(wheel, is_left, distance) in moves {
+        lock.turn(wheel - 1, is_left, distance as u64);
+    }
+    Ok(lock)
+}
+
+/// Where turning the puzzle's dial from `position` by `distance` clicks leaves it; a
+/// negative distance turns it the other way.
+pub fn apply_move(position: i32, distance: i32, is_left: bool) -> i32 {
+    let mut dial = puzzle_dial(position);
+    let is_left = is_left != (distance < 0);
+    dial.turn(is_left, distance.unsigned_abs().into()).position as i32
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let input = input::normalize(input);
+    Ok(count_landings(parse_moves(&input)?))
+}
+
+pub fn solve_part2(input: &str) -> Result<i32, ParseError> {
+    let input = input::normalize(input);
+    Ok(count_passes(parse_moves(&input)?))
+}
+
+/// The puzzle's dial of [`SIZE`] numbers with zero marked, pointing at `position`.
+fn puzzle_dial(position: i32) -> Dial {
+    let position = position.rem_euclid(SIZE as i32) as u64;
+    Dial::new(SIZE, position, [0]).expect("zero is on the dial")
+}
+
+/// Count moves that end with the dial on zero.
+pub fn count_landings(moves: impl IntoIterator<Item = (bool, i32)>) -> usize {
+    count_landings_on(moves, puzzle_dial(START as i32))
+}
+
+/// Count moves that end with `dial` on one of its markers.
+pub fn count_landings_on(moves: impl IntoIterator<Item = (bool, i32)>, mut dial: Dial) -> usize {
+    moves
+        .into_iter()
+        .filter(|&(is_left, distance)| dial.turn(is_left, distance as u64).landed)
+        .count()
+}
+
+/// Count every click that points the dial at zero, including mid-rotation.
+pub fn count_passes(moves: impl IntoIterator<Item = (bool, i32)>) -> i32 {
+    count_passes_on(moves, puzzle_dial(START as i32)) as i32
+}
+
+/// Count every click that points `dial` at one of its markers. A small dial can pass them
+/// more often than fits in an `i32`.
+pub fn count_passes_on(moves: impl IntoIterator<Item = (bool, i32)>, mut dial: Dial) -> u64 {
+    moves
+        .into_iter()
+        .map(|(is_left, distance)| dial.turn(is_left, distance as u64).hits)
+        .sum()
+}
+
+/// How often turning the puzzle's dial from `position` by `distance` clicks points it at
+/// zero; a negative distance takes no clicks.
+pub fn count_zeros(position: i32, distance: i32, is_left: bool) -> i32 {
+    let Ok(distance) = u64::try_from(distance) else {
+        return 0;
+    };
+    let mut dial = puzzle_dial(position);
+    dial.turn(is_left, distance).hits as i32
+}
+
+/// Moves that ended on a marker, and clicks that pointed at one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Tally {
+    pub landings: u128,
+    pub passes: u128,
+}
+
+/// Every position and running count of the puzzle's dial over the moves in `input`.
+pub fn history(input: &str) -> Result<DialHistory, ParseError> {
+    let input = input::normalize(input);
+    Ok(history_on(&parse_moves(&input)?, puzzle_dial(START as i32)))
+}
+
+/// The [`DialHistory`] of `dial` over `moves`.
+pub fn history_on(moves: &[(bool, i32)], dial: Dial) -> DialHistory {
+    let moves = moves
+        .iter()
+        .map(|&(is_left, distance)| (is_left, distance as u64));
+    DialHistory::new(dial, moves)
+}
+
+/// Both counts with the puzzle's dial and the move list applied `repeats` times in a row.
+pub fn solve_repeated(input: &str, repeats: u64) -> Result<Tally, ParseError> {
+    let input = input::normalize(input);
+    Ok(count_repeated(
+        &parse_moves(&input)?,
+        puzzle_dial(START as i32),
+        repeats,
+    ))
+}
+
+/// Count landings and passes with `moves` applied `repeats` times in a row from `dial`,
+/// in closed form: see [`Dial::count_repeated`].
+pub fn count_repeated(moves: &[(bool, i32)], dial: Dial, repeats: u64) -> Tally {
+    let moves: Vec<(bool, u64)> = moves
+        .iter()
+        .map(|&(is_left, distance)| (is_left, distance as u64))
+        .collect();
+    let (landings, passes) = dial.count_repeated(&moves, repeats);
+    Tally { landings, passes }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prop;
+    use crate::registry;
+
+    use super::*;
+
+    const EXAMPLE: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_part1(EXAMPLE), Ok(3));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(solve_part2(EXAMPLE), Ok(6));
+    }
+
+    #[test]
+    fn test_part2_large_rotation() {
+        assert_eq!(solve_part2("R1000"), Ok(10));
+    }
+
+    #[test]
+    fn test_part1_invalid_direction() {
+        let error = solve_part1("X50").unwrap_err();
+        assert_eq!(error.expected, "direction `L` or `R`");
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.found, "X");
+    }
+
+    #[test]
+    fn test_part2_invalid_direction() {
+        let error = solve_part2("L10\nR5\nX50").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (1, 3, 1));
+    }
+
+    #[test]
+    fn test_parse_moves() {
+        assert_eq!(parse_moves("L68\nR5"), Ok(vec![(true, 68), (false, 5)]));
+    }
+
+    #[test]
+    fn test_parse_moves_empty_line() {
+        let error = parse_moves("L1\n\nR2").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.found, "");
+    }
+
+    #[test]
+    fn test_parse_moves_multibyte_direction() {
+        let error = parse_moves("é5").unwrap_err();
+        assert_eq!(error.found, "é");
+    }
+
+    #[test]
+    fn test_parse_moves_invalid_distance() {
+        let error = parse_moves("L1\nRx2").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.found, "x2");
+        assert_eq!(error.expected, "distance as a non-negative integer");
+        assert!(parse_moves("L-5").is_err());
+        assert!(parse_moves("L").is_err());
+    }
+
+    /// Click by click, counting every stop on 0.
+    fn naive_count_zeros(position: i32, distance: i32, is_left: bool) -> i32 {
+        let step = if is_left { -1 } else { 1 };
+        let mut position = position;
+        let mut zeros = 0;
+        for _ in 0..distance {
+            position = (position + step).rem_euclid(100);
+            zeros += (position == 0) as i32;
+        }
+        zeros
+    }
+
+    #[test]
+    fn test_count_zeros_matches_naive() {
+        prop::check(
+            "day01 count_zeros",
+            |rng| {
+                (
+                    rng.range(0..=99) as i32,
+                    rng.range(0..=1000) as i32,
+                    rng.bool(),
+                )
+            },
+            |&(position, distance, is_left)| {
+                prop::agree(
+                    count_zeros(position, distance, is_left),
+                    naive_count_zeros(position, distance, is_left),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_negative_distances() {
+        assert_eq!(apply_move(50, -60, true), 10);
+        assert_eq!(apply_move(5, -10, false), 95);
+        assert_eq!(apply_move(0, -100, true), 0);
+        assert_eq!(apply_move(50, i32::MIN, false), 2);
+        for (position, distance, is_left) in
+            [(50, -60, true), (0, -100, false), (1, i32::MIN, true)]
+        {
+            assert_eq!(
+                count_zeros(position, distance, is_left),
+                naive_count_zeros(position, distance, is_left)
+            );
+        }
+    }
+
+    #[test]
+    fn test_dial_params() {
+        let moves = parse_moves(EXAMPLE).unwrap();
+        let dial = |start: &str, size: &str| {
+            Params::resolve(
+                Day01::PARAMS,
+                &[
+                    ("start".to_string(), start.to_string()),
+                    ("size".to_string(), size.to_string()),
+                ],
+            )
+            .unwrap()
+        };
+        for (start, size, landings, passes) in [
+            ("50", "100", 3u64, 6u64),
+            ("0", "10", 3, 45),
+            ("5", "10", 2, 46),
+            ("15", "10", 2, 46),
+        ] {
+            let params = dial(start, size);
+            assert_eq!(Day01::part1_with(&moves, &params), Answer::from(landings));
+            assert_eq!(Day01::part2_with(&moves, &params), Answer::from(passes));
+        }
+    }
+
+    #[test]
+    fn test_markers_param() {
+        let moves = parse_moves(EXAMPLE).unwrap();
+        let params = |markers: &str| {
+            Params::resolve(
+                Day01::PARAMS,
+                &[
+                    ("size".to_string(), "10".to_string()),
+                    ("markers".to_string(), markers.to_string()),
+                ],
+            )
+            .unwrap()
+        };
+        // On a dial of 10, every move of the example ends on 0, 2, 4, 5 or 9.
+        assert_eq!(
+            Day01::part1_with(&moves, &params("9 5 4 2 0")),
+            Answer::from(10u64)
+        );
+        assert_eq!(
+            Day01::part2_with(&moves, &params("0 1 2 3 4 5 6 7 8 9")),
+            Answer::from(462u64)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "parameter `markers`: marker 10 is not on a dial of 10 positions")]
+    fn test_markers_param_off_dial() {
+        let params = Params::resolve(
+            Day01::PARAMS,
+            &[
+                ("size".to_string(), "10".to_string()),
+                ("markers".to_string(), "0 10".to_string()),
+            ],
+        )
+        .unwrap();
+        Day01::part1_with(&vec![(true, 1)], &params);
+    }
+
+    #[test]
+    fn test_history() {
+        let history = history(EXAMPLE).unwrap();
+        assert_eq!(history.hits_between(1, history.len()), Some(6));
+        assert_eq!(history.landings_between(1, history.len()), Some(3));
+        assert_eq!(history.position_after(3), Some(0));
+        assert!(super::history("L1\nX2").is_err());
+    }
+
+    #[test]
+    fn test_dial_for() {
+        let params = |markers: &str| {
+            Params::resolve(
+                Day01::PARAMS,
+                &[("markers".to_string(), markers.to_string())],
+            )
+            .unwrap()
+        };
+        assert_eq!(dial_for(&params("50 0")).unwrap().markers(), &[0, 50]);
+        assert_eq!(
+            dial_for(&params("0 x")),
+            Err("parameter `markers`: `x` is not a number".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_lock_moves() {
+        assert_eq!(
+            parse_lock_moves("2:L68\nR5\n10:R0"),
+            Ok(vec![(2, true, 68), (1, false, 5), (10, false, 0)])
+        );
+        assert_eq!(parse_lock_moves("64:R1"), Ok(vec![(64, false, 1)]));
+        for (input, found, column) in [
+            ("0:L1", "0", 1),
+            ("x:L1", "x", 1),
+            ("2:X1", "X", 3),
+            ("L1\n65:R2", "65", 1),
+            ("50000000:L1", "50000000", 1),
+        ] {
+            let error = parse_lock_moves(input).unwrap_err();
+            assert_eq!(
+                (error.found.as_str(), error.column),
+                (found, column),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            parse_lock_moves("L1\n:R2").unwrap_err().expected,
+            "wheel number from 1 to 64"
+        );
+    }
+
+    #[test]
+    fn test_run_lock() {
+        let moves = parse_lock_moves(EXAMPLE).unwrap();
+        let dial = puzzle_dial(START as i32);
+        // With one wheel the lock is the puzzle: zeros are part 2's passes.
+        let lock = run_lock(&moves, &dial, 1).unwrap();
+        assert_eq!(lock.zeros(), &[6]);
+        assert_eq!(lock.combination(), vec![32]);
+
+        // Turning right past 99 carries into wheel 2, turning left past 0 borrows from it.
+        let lock = run_lock(&moves, &dial, wheels_turned(&moves) + 1).unwrap();
+        assert_eq!(lock.combination(), vec![32, 48]);
+        assert_eq!(lock.zeros(), &[6, 0]);
+
+        let moves = parse_lock_moves("2:L50\nR150\n3:R10").unwrap();
+        assert_eq!(wheels_turned(&moves), 3);
+        let lock = run_lock(&moves, &dial, 3).unwrap();
+        assert_eq!(lock.combination(), vec![0, 2, 60]);
+        assert_eq!(lock.zeros(), &[2, 1, 0]);
+        assert_eq!(
+            run_lock(&moves, &dial, 2),
+            Err("a move turns wheel 3, but the lock has 2 wheels".to_string())
+        );
+        assert_eq!(
+            run_lock(&moves, &dial, 50_000_000),
+            Err("a lock has at most 64 wheels, not 50000000".to_string())
+        );
+        assert_eq!(wheels_turned(&[]), 1);
+    }
+
+    #[test]
+    fn test_count_passes_on_huge_dial() {
+        let dial = |size, start| Dial::new(size, start, [0]).unwrap();
+        assert_eq!(
+            count_passes_on([(false, i32::MAX)], dial(i32::MAX as u64, 1)),
+            1
+        );
+        assert_eq!(
+            count_passes_on([(true, i32::MAX)], dial(1, 0)),
+            i32::MAX as u64
+        );
+        assert_eq!(
+            count_passes_on([(false, 3)], dial(u64::MAX, u64::MAX - 1)),
+            1
+        );
+    }
+
+    /// Every pass over the list simulated, one after another.
+    fn naive_count_repeated(moves: &[(bool, i32)], dial: &Dial, repeats: u64) -> Tally {
+        let mut dial = dial.clone();
+        let mut tally = Tally::default();
+        for _ in 0..repeats {
+            for &(is_left, distance) in moves {
+                let turn = dial.turn(is_left, distance as u64);
+                tally.landings += turn.landed as u128;
+                tally.passes += turn.hits as u128;
+            }
+        }
+        tally
+    }
+
+    #[test]
+    fn test_solve_repeated() {
+        let once = solve_repeated(EXAMPLE, 1).unwrap();
+        assert_eq!(
+            once,
+            Tally {
+                landings: 3,
+                passes: 6
+            }
+        );
+        // The example turns the dial 18 to the left per pass, so it returns to 50 after 50
+        // passes.
+        let cycle = solve_repeated(EXAMPLE, 50).unwrap();
+        let cycles = 20_000_000_000_000_000;
+        assert_eq!(
+            solve_repeated(EXAMPLE, 1_000_000_000_000_000_000).unwrap(),
+            Tally {
+                landings: cycle.landings * cycles,
+                passes: cycle.passes * cycles
+            }
+        );
+        assert_eq!(solve_repeated(EXAMPLE, 0).unwrap(), Tally::default());
+        assert_eq!(
+            solve_repeated("R1000", 1_000_000_000_000_000_000)
+                .unwrap()
+                .passes,
+            10_000_000_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_repeats_param() {
+        let moves = parse_moves(EXAMPLE).unwrap();
+        let params = Params::resolve(
+            Day01::PARAMS,
+            &[("repeats".to_string(), "9000000000000000000".to_string())],
+        )
+        .unwrap();
+        let expected = solve_repeated(EXAMPLE, 9_000_000_000_000_000_000).unwrap();
+        assert_eq!(
+            Day01::part1_with(&moves, &params),
+            Answer::from(expected.landings as i128)
+        );
+        assert_eq!(
+            Day01::part2_with(&moves, &params),
+            Answer::from(expected.passes as i128)
+        );
+    }
+
+    #[test]
+    fn test_repeats_on_long_period() {
+        // The example drifts 18 a pass, so on a prime-sized dial it takes `size` passes to
+        // return to its start. Over those passes, each move ends on 0 once and sweeps 0 once
+        // for every click it turns.
+        let size: i64 = 1_000_000_007;
+        let periods: i64 = 999_999_999;
+        let moves = parse_moves(EXAMPLE).unwrap();
+        let params = Params::resolve(
+            Day01::PARAMS,
+            &[
+                ("size".to_string(), size.to_string()),
+                ("repeats".to_string(), (size * periods).to_string()),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            Day01::part1_with(&moves, &params),
+            Answer::from(10 * periods as i128)
+        );
+        assert_eq!(
+            Day01::part2_with(&moves, &params),
+            Answer::from(462 * periods as i128)
+        );
+    }
+
+    #[test]
+    fn test_count_repeated_matches_naive() {
+        prop::check(
+            "day01 count_repeated",
+            |rng| {
+                let size = rng.range(1..=40) as u64;
+                let markers: Vec<u64> = (0..rng.range(1..=3)).map(|_| rng.below(size)).collect();
+                let moves: Vec<(bool, i32)> = (0..rng.range(0..=6))
+                    .map(|_| (rng.bool(), rng.range(0..=150) as i32))
+                    .collect();
+                let start = rng.below(size);
+                (size, start, markers, moves, rng.range(0..=120) as u64)
+            },
+            |(size, start, markers, moves, repeats)| {
+                let dial = Dial::new(*size, *start, markers.iter().copied()).unwrap();
+                prop::agree(
+                    count_repeated(moves, dial.clone(), *repeats),
+                    naive_count_repeated(moves, &dial, *repeats),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        registry::assert_windows_agnostic(Day01::DAY, EXAMPLE);
+        let windows = input::windows(EXAMPLE);
+        assert_eq!(solve_part1(&windows), solve_part1(EXAMPLE));
+        assert_eq!(solve_part2(&windows), solve_part2(EXAMPLE));
+        assert_eq!(history(&windows), history(EXAMPLE));
+        assert_eq!(
+            solve_repeated(&windows, 1_000_000_000_000_000_000),
+            solve_repeated(EXAMPLE, 1_000_000_000_000_000_000)
+        );
+    }
+}
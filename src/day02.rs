@@ -0,0 +1,251 @@
+//! Day 2: Gift Shop
+
+use std::ops::RangeInclusive;
+
+use crate::error::ParseError;
+use crate::input;
+use crate::solution::{Answer, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Gift Shop";
+    type Input<'a> = Vec<RangeInclusive<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_ranges(input)
+    }
+
+    fn part1(ranges: &Self::Input<'_>) -> Answer {
+        sum_matching(ranges, is_doubled).into()
+    }
+
+    fn part2(ranges: &Self::Input<'_>) -> Answer {
+        sum_matching(ranges, is_repeated).into()
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<u64, ParseError> {
+    let input = input::normalize(input);
+    Ok(sum_matching(&parse_ranges(&input)?, is_doubled))
+}
+
+pub fn solve_part2(input: &str) -> Result<u64, ParseError> {
+    let input = input::normalize(input);
+    Ok(sum_matching(&parse_ranges(&input)?, is_repeated))
+}
+
+pub fn parse_ranges(input: &str) -> Result<Vec<RangeInclusive<u64>>, ParseError> {
+    input
+        .trim()
+        .split(',')
+        .map(|range| parse_range_in(input, range))
+        .collect()
+}
+
+/// Sum every ID in `ranges` accepted by `is_invalid`.
+pub fn sum_matching(ranges: &[RangeInclusive<u64>], is_invalid: fn(u64) -> bool) -> u64 {
+    ranges
+        .iter()
+        .flat_map(|range| range.clone())
+        .filter(|&n| is_invalid(n))
+        .sum()
+}
+
+pub fn is_repeated(n: u64) -> bool {
+    let s = n.to_string();
+    let len = s.len();
+
+    for pattern_len in 1..=len / 2 {
+        if len.is_multiple_of(pattern_len) {
+            let pattern = &s[..pattern_len];
+            if s.as_bytes()
+                .chunks(pattern_len)
+                .all(|chunk| chunk == pattern.as_bytes())
+            {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+pub fn parse_range(range: &str) -> Result<RangeInclusive<u64>, ParseError> {
+    parse_range_in(range, range)
+}
+
+/// Parse `range`, reporting errors at its position within `input`.
+fn parse_range_in(input: &str, range: &str) -> Result<RangeInclusive<u64>, ParseError> {
+    let (start, end) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(Day02::DAY, input, range, "range with a dash separator"))?;
+    let start: u64 = start
+        .parse()
+        .map_err(|_| ParseError::new(Day02::DAY, input, start, "start as a valid number"))?;
+    let end: u64 = end
+        .parse()
+        .map_err(|_| ParseError::new(Day02::DAY, input, end, "end as a valid number"))?;
+    Ok(start..=end)
+}
+
+pub fn is_doubled(n: u64) -> bool {
+    let s = n.to_string();
+    let len = s.len();
+
+    if !len.is_multiple_of(2) {
+        return false;
+    }
+
+    let mid = len / 2;
+    s[..mid] == s[mid..]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+
+    use super::*;
+
+    const EXAMPLE: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+    #[test]
+    fn test_is_doubled_two_digit() {
+        assert!(is_doubled(11));
+        assert!(is_doubled(22));
+        assert!(is_doubled(99));
+    }
+
+    #[test]
+    fn test_is_doubled_four_digit() {
+        assert!(is_doubled(1010));
+        assert!(is_doubled(6464));
+        assert!(is_doubled(1212));
+    }
+
+    #[test]
+    fn test_is_doubled_six_digit() {
+        assert!(is_doubled(123123));
+        assert!(is_doubled(222222));
+        assert!(is_doubled(446446));
+    }
+
+    #[test]
+    fn test_is_doubled_large_numbers() {
+        assert!(is_doubled(1188511885));
+        assert!(is_doubled(38593859));
+    }
+
+    #[test]
+    fn test_is_doubled_false_odd_length() {
+        assert!(!is_doubled(1));
+        assert!(!is_doubled(101));
+        assert!(!is_doubled(12345));
+    }
+
+    #[test]
+    fn test_is_doubled_false_even_length_not_doubled() {
+        assert!(!is_doubled(12));
+        assert!(!is_doubled(1234));
+        assert!(!is_doubled(123456));
+    }
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_part1(EXAMPLE), Ok(1227775554));
+    }
+
+    #[test]
+    fn test_part1_single_range() {
+        assert_eq!(solve_part1("11-22"), Ok(11 + 22));
+    }
+
+    #[test]
+    fn test_part1_no_invalid_ids() {
+        assert_eq!(solve_part1("1698522-1698528"), Ok(0));
+    }
+
+    #[test]
+    fn test_is_repeated_twice() {
+        assert!(is_repeated(11));
+        assert!(is_repeated(1010));
+        assert!(is_repeated(12341234));
+    }
+
+    #[test]
+    fn test_is_repeated_three_times() {
+        assert!(is_repeated(111));
+        assert!(is_repeated(123123123));
+        assert!(is_repeated(999));
+    }
+
+    #[test]
+    fn test_is_repeated_many_times() {
+        assert!(is_repeated(1111111)); // 1 seven times
+        assert!(is_repeated(1212121212)); // 12 five times
+        assert!(is_repeated(565656)); // 56 three times
+        assert!(is_repeated(824824824)); // 824 three times
+        assert!(is_repeated(2121212121)); // 21 five times
+    }
+
+    #[test]
+    fn test_is_repeated_false() {
+        assert!(!is_repeated(12));
+        assert!(!is_repeated(123));
+        assert!(!is_repeated(1234));
+        assert!(!is_repeated(12345));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(solve_part2(EXAMPLE), Ok(4174379265));
+    }
+
+    #[test]
+    fn test_parse_range_no_dash() {
+        let error = solve_part1("1234").unwrap_err();
+        assert_eq!(error.expected, "range with a dash separator");
+        assert_eq!(error.found, "1234");
+    }
+
+    #[test]
+    fn test_parse_range_invalid_start() {
+        let error = solve_part1("abc-123").unwrap_err();
+        assert_eq!(error.expected, "start as a valid number");
+        assert_eq!((error.column, error.found.as_str()), (1, "abc"));
+    }
+
+    #[test]
+    fn test_parse_range_invalid_end() {
+        let error = solve_part1("123-abc").unwrap_err();
+        assert_eq!(error.expected, "end as a valid number");
+        assert_eq!((error.column, error.found.as_str()), (5, "abc"));
+    }
+
+    #[test]
+    fn test_parse_ranges_error_column() {
+        let error = parse_ranges("11-22,95-1x5").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (2, 1, 10));
+    }
+
+    #[test]
+    fn test_parse_ranges_trailing_comma() {
+        let error = parse_ranges("11-22,").unwrap_err();
+        assert_eq!(error.found, "");
+        assert_eq!(error.column, 7);
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("3-5"), Ok(3..=5));
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        registry::assert_windows_agnostic(Day02::DAY, EXAMPLE);
+        let windows = input::windows(EXAMPLE);
+        assert_eq!(solve_part1(&windows), solve_part1(EXAMPLE));
+        assert_eq!(solve_part2(&windows), solve_part2(EXAMPLE));
+    }
+}
@@ -0,0 +1,329 @@
+//! Day 3: Lobby
+
+use crate::error::ParseError;
+use crate::input;
+use crate::params::{Param, Params};
+use crate::solution::{Answer, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Lobby";
+    type Input<'a> = Vec<&'a str>;
+    // A joltage of more than 19 digits would not fit in a u64; totals are summed in a u128.
+    const PARAMS: &'static [Param] = &[
+        Param::int(
+            "part1_batteries",
+            "2",
+            1,
+            19,
+            "batteries turned on per bank in part 1",
+        ),
+        Param::int(
+            "part2_batteries",
+            "12",
+            1,
+            19,
+            "batteries turned on per bank in part 2",
+        ),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_banks(input)
+    }
+
+    fn part1(banks: &Self::Input<'_>) -> Answer {
+        Self::part1_with(banks, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part2(banks: &Self::Input<'_>) -> Answer {
+        Self::part2_with(banks, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part1_with(banks: &Self::Input<'_>, params: &Params) -> Answer {
+        total_joltage(banks, params.int("part1_batteries") as usize).into()
+    }
+
+    fn part2_with(banks: &Self::Input<'_>, params: &Params) -> Answer {
+        total_joltage(banks, params.int("part2_batteries") as usize).into()
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<u128, ParseError> {
+    let input = input::normalize(input);
+    Ok(total_joltage(&parse_banks(&input)?, 2))
+}
+
+pub fn solve_part2(input: &str) -> Result<u128, ParseError> {
+    let input = input::normalize(input);
+    Ok(total_joltage(&parse_banks(&input)?, 12))
+}
+
+pub fn parse_banks(input: &str) -> Result<Vec<&str>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(
+            |bank| match bank.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+                Some((i, c)) => Err(ParseError::new(
+                    Day03::DAY,
+                    input,
+                    &bank[i..i + c.len_utf8()],
+                    "battery joltage digit",
+                )),
+                None => Ok(bank),
+            },
+        )
+        .collect()
+}
+
+pub fn total_joltage(banks: &[&str], k: usize) -> u128 {
+    banks
+        .iter()
+        .map(|bank| u128::from(max_joltage_k(bank, k)))
+        .sum()
+}
+
+pub fn max_joltage_k(bank: &str, k: usize) -> u64 {
+    let n = bank.len();
+    assert!(
+        n >= k,
+        "Bank must have at least {} batteries, got {}: '{}'",
+        k,
+        n,
+        bank
+    );
+
+    let digits: Vec<u8> = bank
+        .bytes()
+        .map(|b| {
+            assert!(
+                b.is_ascii_digit(),
+                "Invalid character in bank: '{}'",
+                b as char
+            );
+            b - b'0'
+        })
+        .collect();
+
+    let mut drop = n - k;
+    let mut stack: Vec<u8> = Vec::with_capacity(n);
+
+    for &d in &digits {
+        while !stack.is_empty() && drop > 0 && *stack.last().unwrap() < d {
+            stack.pop();
+            drop -= 1;
+        }
+        stack.push(d);
+    }
+
+    stack.truncate(k);
+    stack.iter().fold(0u64, |acc, &d| acc * 10 + d as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prop;
+    use crate::registry;
+
+    use super::*;
+
+    const EXAMPLE: &str = "987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+    #[test]
+    fn test_part1_example_1() {
+        assert_eq!(max_joltage_k("987654321111111", 2), 98);
+    }
+
+    #[test]
+    fn test_part1_example_2() {
+        assert_eq!(max_joltage_k("811111111111119", 2), 89);
+    }
+
+    #[test]
+    fn test_part1_example_3() {
+        assert_eq!(max_joltage_k("234234234234278", 2), 78);
+    }
+
+    #[test]
+    fn test_part1_example_4() {
+        assert_eq!(max_joltage_k("818181911112111", 2), 92);
+    }
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_part1(EXAMPLE), Ok(357));
+    }
+
+    #[test]
+    fn test_part1_two_digits() {
+        assert_eq!(max_joltage_k("12", 2), 12);
+        assert_eq!(max_joltage_k("91", 2), 91);
+        assert_eq!(max_joltage_k("19", 2), 19);
+    }
+
+    #[test]
+    fn test_part1_all_same() {
+        assert_eq!(max_joltage_k("9999", 2), 99);
+        assert_eq!(max_joltage_k("1111", 2), 11);
+    }
+
+    #[test]
+    fn test_part1_descending() {
+        assert_eq!(max_joltage_k("987654321", 2), 98);
+    }
+
+    #[test]
+    fn test_part1_ascending() {
+        assert_eq!(max_joltage_k("123456789", 2), 89);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bank must have at least 2 batteries")]
+    fn test_part1_single_digit() {
+        max_joltage_k("5", 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bank must have at least 2 batteries")]
+    fn test_part1_empty() {
+        max_joltage_k("", 2);
+    }
+
+    #[test]
+    fn test_parse_banks() {
+        assert_eq!(parse_banks("12\n345\n"), Ok(vec!["12", "345"]));
+    }
+
+    #[test]
+    fn test_parse_banks_invalid_char() {
+        let error = parse_banks("1234\n12a34").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (3, 2, 3));
+        assert_eq!(error.found, "a");
+        assert_eq!(error.expected, "battery joltage digit");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid character in bank")]
+    fn test_invalid_char() {
+        max_joltage_k("12a34", 2);
+    }
+
+    #[test]
+    fn test_part2_example_1() {
+        assert_eq!(max_joltage_k("987654321111111", 12), 987654321111);
+    }
+
+    #[test]
+    fn test_part2_example_2() {
+        assert_eq!(max_joltage_k("811111111111119", 12), 811111111119);
+    }
+
+    #[test]
+    fn test_part2_example_3() {
+        assert_eq!(max_joltage_k("234234234234278", 12), 434234234278);
+    }
+
+    #[test]
+    fn test_part2_example_4() {
+        assert_eq!(max_joltage_k("818181911112111", 12), 888911112111);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(solve_part2(EXAMPLE), Ok(3121910778619));
+    }
+
+    #[test]
+    fn test_part2_exact_length() {
+        assert_eq!(max_joltage_k("123456789012", 12), 123456789012);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bank must have at least 12 batteries")]
+    fn test_part2_too_short() {
+        max_joltage_k("12345678901", 12);
+    }
+
+    /// Best number over every way of keeping `k` digits in order.
+    fn naive_max_joltage_k(bank: &[u8], k: usize) -> u64 {
+        if k == 0 {
+            return 0;
+        }
+        (0..=bank.len() - k)
+            .map(|i| {
+                let rest = naive_max_joltage_k(&bank[i + 1..], k - 1);
+                (bank[i] - b'0') as u64 * 10u64.pow(k as u32 - 1) + rest
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_max_joltage_k_matches_naive() {
+        prop::check(
+            "day03 max_joltage_k",
+            |rng| {
+                let len = rng.range(1..=12) as usize;
+                let digits = rng.range(1..=9) as u8;
+                let bank: String = (0..len)
+                    .map(|_| (b'0' + rng.below(digits as u64 + 1) as u8) as char)
+                    .collect();
+                let k = rng.range(1..=len as i64) as usize;
+                (bank, k)
+            },
+            |(bank, k)| {
+                prop::agree(
+                    max_joltage_k(bank, *k),
+                    naive_max_joltage_k(bank.as_bytes(), *k),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_batteries_params() {
+        let banks = parse_banks(EXAMPLE).unwrap();
+        let swapped = Params::resolve(
+            Day03::PARAMS,
+            &[
+                ("part1_batteries".to_string(), "12".to_string()),
+                ("part2_batteries".to_string(), "2".to_string()),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            Day03::part1_with(&banks, &swapped),
+            Answer::from(3121910778619u64)
+        );
+        assert_eq!(Day03::part2_with(&banks, &swapped), Answer::from(357u64));
+        assert_eq!(Day03::part1(&banks), Answer::from(357u64));
+    }
+
+    #[test]
+    fn test_batteries_at_most() {
+        let input = vec!["9".repeat(19); 3].join("\n");
+        let banks = parse_banks(&input).unwrap();
+        let most = Params::resolve(
+            Day03::PARAMS,
+            &[("part2_batteries".to_string(), "19".to_string())],
+        )
+        .unwrap();
+        assert_eq!(
+            Day03::part2_with(&banks, &most),
+            Answer::BigInt(29_999_999_999_999_999_997)
+        );
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        registry::assert_windows_agnostic(Day03::DAY, EXAMPLE);
+        let windows = input::windows(EXAMPLE);
+        assert_eq!(solve_part1(&windows), solve_part1(EXAMPLE));
+        assert_eq!(solve_part2(&windows), solve_part2(EXAMPLE));
+    }
+}
@@ -0,0 +1,249 @@
+//! Day 4: Printing Department
+
+use crate::error::ParseError;
+use crate::input;
+use crate::solution::{Answer, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Printing Department";
+    type Input<'a> = Vec<&'a [u8]>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_grid(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Answer {
+        find_accessible_rolls(grid).len().into()
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Answer {
+        remove_accessible_rolls(grid.iter().map(|row| row.to_vec()).collect()).into()
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let input = input::normalize(input);
+    Ok(find_accessible_rolls(&parse_grid(&input)?).len())
+}
+
+pub fn solve_part2(input: &str) -> Result<usize, ParseError> {
+    let input = input::normalize(input);
+    let grid = parse_grid(&input)?.iter().map(|row| row.to_vec()).collect();
+    Ok(remove_accessible_rolls(grid))
+}
+
+/// Parse a rectangular grid of `.` and `@`.
+pub fn parse_grid(input: &str) -> Result<Vec<&[u8]>, ParseError> {
+    let lines: Vec<&str> = input.trim().lines().collect();
+    let width = lines.first().map_or(0, |line| line.len());
+
+    for line in &lines {
+        if let Some((i, c)) = line.char_indices().find(|&(_, c)| c != '.' && c != '@') {
+            let found = &line[i..i + c.len_utf8()];
+            return Err(ParseError::new(Day04::DAY, input, found, "`.` or `@`"));
+        }
+        if line.len() != width {
+            let expected = format!("row of width {width}");
+            return Err(ParseError::new(Day04::DAY, input, line, expected));
+        }
+    }
+
+    Ok(lines.into_iter().map(str::as_bytes).collect())
+}
+
+/// Repeatedly remove accessible rolls until none are left, returning how many were removed.
+pub fn remove_accessible_rolls(mut grid: Vec<Vec<u8>>) -> usize {
+    let mut total_removed = 0;
+
+    loop {
+        let accessible = find_accessible_rolls(&grid);
+        if accessible.is_empty() {
+            break;
+        }
+
+        for (row, col) in &accessible {
+            grid[*row][*col] = b'.';
+        }
+        total_removed += accessible.len();
+    }
+
+    total_removed
+}
+
+pub fn find_accessible_rolls<R: AsRef<[u8]>>(grid: &[R]) -> Vec<(usize, usize)> {
+    let cols = grid.first().map_or(0, |row| row.as_ref().len());
+
+    (0..grid.len())
+        .flat_map(|row| (0..cols).map(move |col| (row, col)))
+        .filter(|&(row, col)| {
+            grid[row].as_ref()[col] == b'@' && count_adjacent_rolls(grid, row, col) < 4
+        })
+        .collect()
+}
+
+pub fn count_adjacent_rolls<R: AsRef<[u8]>>(grid: &[R], row: usize, col: usize) -> usize {
+    let rows = grid.len();
+    let cols = grid.first().map_or(0, |r| r.as_ref().len());
+
+    (-1i32..=1)
+        .flat_map(|dr| (-1i32..=1).map(move |dc| (dr, dc)))
+        .filter(|&(dr, dc)| dr != 0 || dc != 0)
+        .filter_map(|(dr, dc)| {
+            let new_row = row.checked_add_signed(dr as isize)?;
+            let new_col = col.checked_add_signed(dc as isize)?;
+            (new_row < rows && new_col < cols).then_some((new_row, new_col))
+        })
+        .filter(|&(r, c)| grid[r].as_ref()[c] == b'@')
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+
+    use super::*;
+
+    const EXAMPLE: &str = "..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_part1(EXAMPLE), Ok(13));
+    }
+
+    #[test]
+    fn test_count_adjacent_corner_top_left() {
+        let grid: Vec<&[u8]> = vec![b"@.", b".."];
+        assert_eq!(count_adjacent_rolls(&grid, 0, 0), 0);
+    }
+
+    #[test]
+    fn test_count_adjacent_corner_with_neighbors() {
+        let grid: Vec<&[u8]> = vec![b"@@", b"@."];
+        assert_eq!(count_adjacent_rolls(&grid, 0, 0), 2);
+    }
+
+    #[test]
+    fn test_count_adjacent_center_surrounded() {
+        let grid: Vec<&[u8]> = vec![b"@@@", b"@@@", b"@@@"];
+        assert_eq!(count_adjacent_rolls(&grid, 1, 1), 8);
+    }
+
+    #[test]
+    fn test_count_adjacent_center_no_neighbors() {
+        let grid: Vec<&[u8]> = vec![b"...", b".@.", b"..."];
+        assert_eq!(count_adjacent_rolls(&grid, 1, 1), 0);
+    }
+
+    #[test]
+    fn test_single_roll_accessible() {
+        let input = "@";
+        assert_eq!(solve_part1(input), Ok(1));
+    }
+
+    #[test]
+    fn test_all_empty() {
+        let input = "...\n...\n...";
+        assert_eq!(solve_part1(input), Ok(0));
+    }
+
+    #[test]
+    fn test_roll_with_exactly_three_neighbors() {
+        // Center roll has exactly 3 neighbors - should be accessible
+        let input = ".@.\n@@.\n...";
+        // Top-middle: 2 neighbors (accessible)
+        // Middle-left: 2 neighbors (accessible)
+        // Middle-middle: not a roll
+        assert_eq!(solve_part1(input), Ok(3));
+    }
+
+    #[test]
+    fn test_roll_with_exactly_four_neighbors() {
+        // Center roll has exactly 4 neighbors - NOT accessible
+        let input = ".@.\n@@@\n.@.";
+        // Center has 4 neighbors, not accessible
+        // Each edge roll has 1 neighbor, accessible
+        assert_eq!(solve_part1(input), Ok(4));
+    }
+
+    #[test]
+    fn test_edge_roll() {
+        let grid: Vec<&[u8]> = vec![b".@.", b"..."];
+        assert_eq!(count_adjacent_rolls(&grid, 0, 1), 0);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(solve_part2(EXAMPLE), Ok(43));
+    }
+
+    #[test]
+    fn test_part2_single_roll() {
+        assert_eq!(solve_part2("@"), Ok(1));
+    }
+
+    #[test]
+    fn test_part2_all_empty() {
+        assert_eq!(solve_part2("...\n...\n..."), Ok(0));
+    }
+
+    #[test]
+    fn test_part2_chain_removal() {
+        // A line of rolls - first the ends are accessible, then progressively inward
+        let input = "@@@@@";
+        // Initially: ends have 1 neighbor each (accessible)
+        // After removing ends: new ends have 1 neighbor each
+        // Continue until all removed
+        assert_eq!(solve_part2(input), Ok(5));
+    }
+
+    #[test]
+    fn test_part2_dense_block_partial() {
+        // 3x3 block - center has 8 neighbors, never accessible
+        // But outer rolls have fewer neighbors
+        let input = "@@@\n@@@\n@@@";
+        // Corner: 3 neighbors (accessible)
+        // Edge: 5 neighbors (not accessible initially)
+        // Center: 8 neighbors (not accessible)
+        // After corners removed, edges become accessible, then center
+        assert_eq!(solve_part2(input), Ok(9));
+    }
+
+    #[test]
+    fn test_parse_grid_invalid_char() {
+        let error = parse_grid("..@\n.x@").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (4, 2, 2));
+        assert_eq!(error.found, "x");
+    }
+
+    #[test]
+    fn test_parse_grid_ragged_rows() {
+        let error = parse_grid("..@\n.@").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "row of width 3");
+    }
+
+    #[test]
+    fn test_parse_grid_empty() {
+        assert_eq!(parse_grid(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        registry::assert_windows_agnostic(Day04::DAY, EXAMPLE);
+        let windows = input::windows(EXAMPLE);
+        assert_eq!(solve_part1(&windows), solve_part1(EXAMPLE));
+        assert_eq!(solve_part2(&windows), solve_part2(EXAMPLE));
+    }
+}
@@ -0,0 +1,349 @@
+//! Day 5: Cafeteria
+
+use crate::error::ParseError;
+use crate::input;
+use crate::solution::{Answer, Solution};
+
+pub struct Day05;
+
+/// Fresh ID ranges and the available IDs, in input order.
+pub type Inventory = (Vec<(u64, u64)>, Vec<u64>);
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Cafeteria";
+    type Input<'a> = Inventory;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((ranges, ids): &Self::Input<'_>) -> Answer {
+        count_fresh(ranges, ids).into()
+    }
+
+    fn part2((ranges, _): &Self::Input<'_>) -> Answer {
+        count_fresh_ids(ranges.clone()).into()
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let input = input::normalize(input);
+    let (ranges, ids) = parse_input(&input)?;
+    Ok(count_fresh(&ranges, &ids))
+}
+
+pub fn solve_part2(input: &str) -> Result<u64, ParseError> {
+    let input = input::normalize(input);
+    let (ranges, _) = parse_input(&input)?;
+    Ok(count_fresh_ids(ranges))
+}
+
+/// Count the available IDs that fall into any fresh range.
+pub fn count_fresh(ranges: &[(u64, u64)], ids: &[u64]) -> usize {
+    ids.iter().filter(|&&id| is_fresh(id, ranges)).count()
+}
+
+/// Count every ID covered by at least one fresh range.
+pub fn count_fresh_ids(ranges: Vec<(u64, u64)>) -> u64 {
+    let merged = merge_ranges(ranges);
+    merged.iter().map(|&(start, end)| end - start + 1).sum()
+}
+
+pub fn merge_ranges(mut ranges: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+    if ranges.is_empty() {
+        return ranges;
+    }
+
+    ranges.sort_by_key(|&(start, _)| start);
+
+    let mut merged: Vec<(u64, u64)> = Vec::new();
+    let mut current = ranges[0];
+
+    for &(start, end) in &ranges[1..] {
+        if start <= current.1 + 1 {
+            // Overlapping or adjacent - extend current range
+            current.1 = current.1.max(end);
+        } else {
+            // Gap - push current and start new
+            merged.push(current);
+            current = (start, end);
+        }
+    }
+    merged.push(current);
+
+    merged
+}
+
+pub fn parse_input(input: &str) -> Result<Inventory, ParseError> {
+    let mut sections = input.trim().split("\n\n");
+    let end_of_input = &input[input.len()..];
+
+    let ranges_section = sections.next().unwrap_or(end_of_input);
+    let ids_section = sections
+        .next()
+        .ok_or_else(|| ParseError::new(Day05::DAY, input, end_of_input, "IDs section"))?;
+
+    let ranges = ranges_section
+        .lines()
+        .map(|line| parse_range_in(input, line))
+        .collect::<Result<_, _>>()?;
+
+    let ids = ids_section
+        .lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|_| ParseError::new(Day05::DAY, input, line, "ID as a valid number"))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((ranges, ids))
+}
+
+pub fn parse_range(line: &str) -> Result<(u64, u64), ParseError> {
+    parse_range_in(line, line)
+}
+
+/// Parse the range on `line`, reporting errors at its position within `input`.
+fn parse_range_in(input: &str, line: &str) -> Result<(u64, u64), ParseError> {
+    let (start, end) = line
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(Day05::DAY, input, line, "range with a dash separator"))?;
+    let start: u64 = start
+        .parse()
+        .map_err(|_| ParseError::new(Day05::DAY, input, start, "start as a valid number"))?;
+    let end: u64 = end
+        .parse()
+        .map_err(|_| ParseError::new(Day05::DAY, input, end, "end as a valid number"))?;
+    Ok((start, end))
+}
+
+pub fn is_fresh(id: u64, ranges: &[(u64, u64)]) -> bool {
+    ranges.iter().any(|&(start, end)| id >= start && id <= end)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prop;
+    use crate::registry;
+
+    use super::*;
+
+    const EXAMPLE: &str = "3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_part1(EXAMPLE), Ok(3));
+    }
+
+    #[test]
+    fn test_is_fresh_in_range() {
+        let ranges = vec![(3, 5), (10, 14)];
+        assert!(is_fresh(3, &ranges));
+        assert!(is_fresh(4, &ranges));
+        assert!(is_fresh(5, &ranges));
+        assert!(is_fresh(10, &ranges));
+        assert!(is_fresh(14, &ranges));
+    }
+
+    #[test]
+    fn test_is_fresh_not_in_range() {
+        let ranges = vec![(3, 5), (10, 14)];
+        assert!(!is_fresh(1, &ranges));
+        assert!(!is_fresh(2, &ranges));
+        assert!(!is_fresh(6, &ranges));
+        assert!(!is_fresh(9, &ranges));
+        assert!(!is_fresh(15, &ranges));
+    }
+
+    #[test]
+    fn test_is_fresh_overlapping_ranges() {
+        let ranges = vec![(10, 14), (12, 18)];
+        assert!(is_fresh(13, &ranges)); // In both ranges
+        assert!(is_fresh(17, &ranges)); // Only in second range
+        assert!(is_fresh(11, &ranges)); // Only in first range
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(parse_range("3-5"), Ok((3, 5)));
+        assert_eq!(parse_range("10-14"), Ok((10, 14)));
+        assert_eq!(parse_range("100-200"), Ok((100, 200)));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let (ranges, ids) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(ranges, vec![(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert_eq!(ids, vec![1, 5, 8, 11, 17, 32]);
+    }
+
+    #[test]
+    fn test_single_range_single_id() {
+        let input = "5-10\n\n7";
+        assert_eq!(solve_part1(input), Ok(1));
+    }
+
+    #[test]
+    fn test_single_range_id_outside() {
+        let input = "5-10\n\n3";
+        assert_eq!(solve_part1(input), Ok(0));
+    }
+
+    #[test]
+    fn test_boundary_exact_match() {
+        let input = "5-5\n\n5";
+        assert_eq!(solve_part1(input), Ok(1));
+    }
+
+    #[test]
+    fn test_invalid_range_no_dash() {
+        let error = parse_range("510").unwrap_err();
+        assert_eq!(error.expected, "range with a dash separator");
+    }
+
+    #[test]
+    fn test_invalid_range_bad_start() {
+        let error = parse_range("abc-10").unwrap_err();
+        assert_eq!(error.expected, "start as a valid number");
+        assert_eq!((error.column, error.found.as_str()), (1, "abc"));
+    }
+
+    #[test]
+    fn test_invalid_range_bad_end() {
+        let error = parse_range("5-xyz").unwrap_err();
+        assert_eq!(error.expected, "end as a valid number");
+        assert_eq!((error.column, error.found.as_str()), (3, "xyz"));
+    }
+
+    #[test]
+    fn test_parse_input_error_location() {
+        let error = parse_input("3-5\n10-1x\n\n1").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (5, 2, 4));
+    }
+
+    #[test]
+    fn test_parse_input_invalid_id() {
+        let error = parse_input("3-5\n\n1\nseven").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.expected, "ID as a valid number");
+    }
+
+    #[test]
+    fn test_parse_input_missing_ids() {
+        let error = parse_input("3-5\n10-14\n").unwrap_err();
+        assert_eq!(error.expected, "IDs section");
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.found, "");
+    }
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(solve_part2(EXAMPLE), Ok(14));
+    }
+
+    #[test]
+    fn test_merge_ranges_no_overlap() {
+        let ranges = vec![(1, 3), (5, 7), (10, 12)];
+        assert_eq!(merge_ranges(ranges), vec![(1, 3), (5, 7), (10, 12)]);
+    }
+
+    #[test]
+    fn test_merge_ranges_overlap() {
+        let ranges = vec![(1, 5), (3, 8)];
+        assert_eq!(merge_ranges(ranges), vec![(1, 8)]);
+    }
+
+    #[test]
+    fn test_merge_ranges_adjacent() {
+        let ranges = vec![(1, 5), (6, 10)];
+        assert_eq!(merge_ranges(ranges), vec![(1, 10)]);
+    }
+
+    #[test]
+    fn test_merge_ranges_unsorted() {
+        let ranges = vec![(10, 14), (3, 5), (12, 18), (16, 20)];
+        assert_eq!(merge_ranges(ranges), vec![(3, 5), (10, 20)]);
+    }
+
+    #[test]
+    fn test_merge_ranges_contained() {
+        let ranges = vec![(1, 10), (3, 5)];
+        assert_eq!(merge_ranges(ranges), vec![(1, 10)]);
+    }
+
+    #[test]
+    fn test_merge_ranges_empty() {
+        let ranges: Vec<(u64, u64)> = vec![];
+        assert_eq!(merge_ranges(ranges), vec![]);
+    }
+
+    #[test]
+    fn test_part2_single_range() {
+        let input = "5-10\n\n7";
+        assert_eq!(solve_part2(input), Ok(6));
+    }
+
+    /// Maximal runs of covered IDs, found by marking each ID.
+    fn naive_merge_ranges(ranges: &[(u64, u64)], base: u64, span: u64) -> Vec<(u64, u64)> {
+        let mut covered = vec![false; span as usize + 1];
+        for &(start, end) in ranges {
+            for id in start..=end {
+                covered[(id - base) as usize] = true;
+            }
+        }
+        let mut runs: Vec<(u64, u64)> = Vec::new();
+        for (offset, _) in covered.iter().enumerate().filter(|(_, &c)| c) {
+            let id = base + offset as u64;
+            match runs.last_mut() {
+                Some(run) if run.1 + 1 == id => run.1 = id,
+                _ => runs.push((id, id)),
+            }
+        }
+        runs
+    }
+
+    #[test]
+    fn test_merge_ranges_matches_naive() {
+        const SPAN: u64 = 120;
+        prop::check(
+            "day05 merge_ranges",
+            |rng| {
+                let base = rng.below(1_000_000_000_000_000);
+                let count = rng.below(12) as usize;
+                let ranges: Vec<(u64, u64)> = (0..count)
+                    .map(|_| {
+                        let start = base + rng.below(SPAN + 1);
+                        let end = (start + rng.below(20)).min(base + SPAN);
+                        (start, end)
+                    })
+                    .collect();
+                (base, ranges)
+            },
+            |(base, ranges)| {
+                prop::agree(
+                    merge_ranges(ranges.clone()),
+                    naive_merge_ranges(ranges, *base, SPAN),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        registry::assert_windows_agnostic(Day05::DAY, EXAMPLE);
+        let windows = input::windows(EXAMPLE);
+        assert_eq!(solve_part1(&windows), solve_part1(EXAMPLE));
+        assert_eq!(solve_part2(&windows), solve_part2(EXAMPLE));
+    }
+}
@@ -0,0 +1,383 @@
+//! Day 6: Trash Compactor
+
+use crate::error::ParseError;
+use crate::input;
+use crate::solution::{Answer, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Trash Compactor";
+    /// Problems read row-wise (part 1) and column-wise (part 2).
+    type Input<'a> = (Vec<(Vec<u64>, char)>, Vec<(Vec<u64>, char)>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok((parse_problems(input)?, parse_problems_part2(input)?))
+    }
+
+    fn part1((problems, _): &Self::Input<'_>) -> Answer {
+        grand_total(problems).into()
+    }
+
+    fn part2((_, problems): &Self::Input<'_>) -> Answer {
+        grand_total(problems).into()
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<u64, ParseError> {
+    let input = input::normalize(input);
+    Ok(grand_total(&parse_problems(&input)?))
+}
+
+pub fn solve_part2(input: &str) -> Result<u64, ParseError> {
+    let input = input::normalize(input);
+    Ok(grand_total(&parse_problems_part2(&input)?))
+}
+
+pub fn grand_total(problems: &[(Vec<u64>, char)]) -> u64 {
+    problems
+        .iter()
+        .map(|(numbers, operator)| solve_problem(numbers, *operator))
+        .sum()
+}
+
+pub fn solve_problem(numbers: &[u64], operator: char) -> u64 {
+    match operator {
+        '+' => numbers.iter().sum(),
+        '*' => numbers.iter().product(),
+        _ => panic!("Unknown operator: {}", operator),
+    }
+}
+
+pub fn parse_problems_part2(input: &str) -> Result<Vec<(Vec<u64>, char)>, ParseError> {
+    let lines: Vec<&str> = input.lines().filter(|l| !l.is_empty()).collect();
+    if lines.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let max_len = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+
+    // For each column, determine if it's a separator (all spaces)
+    let is_separator: Vec<bool> = (0..max_len)
+        .map(|col| {
+            lines
+                .iter()
+                .all(|line| line.chars().nth(col).is_none_or(|c| c == ' '))
+        })
+        .collect();
+
+    // Find problem regions (runs of non-separator columns)
+    let mut problems = vec![];
+    let mut start = None;
+
+    for (col, &is_sep) in is_separator.iter().enumerate() {
+        match (start, is_sep) {
+            (None, false) => start = Some(col),
+            (Some(s), true) => {
+                problems.push(extract_problem_part2(input, &lines, s, col)?);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    // Handle last problem if input doesn't end with separator
+    if let Some(s) = start {
+        problems.push(extract_problem_part2(input, &lines, s, max_len)?);
+    }
+
+    Ok(problems)
+}
+
+/// Extract a problem for part 2: each column is a number (digits top-to-bottom),
+/// columns are read right-to-left
+pub fn extract_problem_part2(
+    input: &str,
+    lines: &[&str],
+    start_col: usize,
+    end_col: usize,
+) -> Result<(Vec<u64>, char), ParseError> {
+    let num_rows = lines.len() - 1;
+    let operator_row = lines[num_rows];
+
+    // Read columns right-to-left, each column forms a number (digits top-to-bottom)
+    let mut numbers = vec![];
+    for col in (start_col..end_col).rev() {
+        let mut digits = String::new();
+        for line in lines.iter().take(num_rows) {
+            match line.chars().nth(col) {
+                Some(c) if c.is_ascii_digit() => digits.push(c),
+                Some(c) if c.is_whitespace() => {}
+                Some(_) => {
+                    let found = column_slice(line, col, col + 1);
+                    return Err(ParseError::new(Day06::DAY, input, found, "digit or space"));
+                }
+                None => {}
+            }
+        }
+        if !digits.is_empty() {
+            let number = digits.parse().map_err(|_| {
+                let found = column_slice(lines[0], col, col + 1);
+                ParseError::new(
+                    Day06::DAY,
+                    input,
+                    found,
+                    "column number that fits in 64 bits",
+                )
+            })?;
+            numbers.push(number);
+        }
+    }
+
+    let operator = extract_operator(input, operator_row, start_col, end_col)?;
+
+    Ok((numbers, operator))
+}
+
+pub fn parse_problems(input: &str) -> Result<Vec<(Vec<u64>, char)>, ParseError> {
+    let lines: Vec<&str> = input.lines().filter(|l| !l.is_empty()).collect();
+    if lines.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let max_len = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+
+    // For each column, determine if it's a separator (all spaces)
+    let is_separator: Vec<bool> = (0..max_len)
+        .map(|col| {
+            lines
+                .iter()
+                .all(|line| line.chars().nth(col).is_none_or(|c| c == ' '))
+        })
+        .collect();
+
+    // Find problem regions (runs of non-separator columns)
+    let mut problems = vec![];
+    let mut start = None;
+
+    for (col, &is_sep) in is_separator.iter().enumerate() {
+        match (start, is_sep) {
+            (None, false) => start = Some(col),
+            (Some(s), true) => {
+                problems.push(extract_problem(input, &lines, s, col)?);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    // Handle last problem if input doesn't end with separator
+    if let Some(s) = start {
+        problems.push(extract_problem(input, &lines, s, max_len)?);
+    }
+
+    Ok(problems)
+}
+
+pub fn extract_problem(
+    input: &str,
+    lines: &[&str],
+    start_col: usize,
+    end_col: usize,
+) -> Result<(Vec<u64>, char), ParseError> {
+    let num_rows = lines.len() - 1;
+    let operator_row = lines[num_rows];
+
+    let mut numbers = vec![];
+    for line in lines.iter().take(num_rows) {
+        let segment = column_slice(line, start_col, end_col);
+        let num_str: String = segment.chars().filter(|c| !c.is_whitespace()).collect();
+        if !num_str.is_empty() {
+            let number = num_str.parse().map_err(|_| {
+                ParseError::new(Day06::DAY, input, segment.trim(), "number in problem")
+            })?;
+            numbers.push(number);
+        }
+    }
+
+    let operator = extract_operator(input, operator_row, start_col, end_col)?;
+
+    Ok((numbers, operator))
+}
+
+/// Find the `+` or `*` in the operator row within a problem's columns.
+fn extract_operator(
+    input: &str,
+    operator_row: &str,
+    start_col: usize,
+    end_col: usize,
+) -> Result<char, ParseError> {
+    let segment = column_slice(operator_row, start_col, end_col);
+    match segment.trim() {
+        "+" => Ok('+'),
+        "*" => Ok('*'),
+        "" => Err(ParseError::new(
+            Day06::DAY,
+            input,
+            segment,
+            "operator `+` or `*`",
+        )),
+        other => Err(ParseError::new(
+            Day06::DAY,
+            input,
+            other,
+            "operator `+` or `*`",
+        )),
+    }
+}
+
+/// The substring of `line` covering character columns `start..end`, clamped to the line.
+fn column_slice(line: &str, start: usize, end: usize) -> &str {
+    let byte_at = |col: usize| line.char_indices().nth(col).map_or(line.len(), |(i, _)| i);
+    &line[byte_at(start)..byte_at(end)]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+
+    use super::*;
+
+    const EXAMPLE: &str = "123 328  51 64
+ 45 64  387 23
+  6 98  215 314
+*   +   *   +  ";
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_part1(EXAMPLE), Ok(4277556));
+    }
+
+    #[test]
+    fn test_parse_problems_example() {
+        let problems = parse_problems(EXAMPLE).unwrap();
+        assert_eq!(problems.len(), 4);
+        assert_eq!(problems[0], (vec![123, 45, 6], '*'));
+        assert_eq!(problems[1], (vec![328, 64, 98], '+'));
+        assert_eq!(problems[2], (vec![51, 387, 215], '*'));
+        assert_eq!(problems[3], (vec![64, 23, 314], '+'));
+    }
+
+    #[test]
+    fn test_solve_problem_multiply() {
+        assert_eq!(solve_problem(&[123, 45, 6], '*'), 33210);
+    }
+
+    #[test]
+    fn test_solve_problem_add() {
+        assert_eq!(solve_problem(&[328, 64, 98], '+'), 490);
+    }
+
+    #[test]
+    fn test_single_problem_multiply() {
+        let input = "10\n20\n*";
+        assert_eq!(solve_part1(input), Ok(200));
+    }
+
+    #[test]
+    fn test_single_problem_add() {
+        let input = "10\n20\n+";
+        assert_eq!(solve_part1(input), Ok(30));
+    }
+
+    #[test]
+    fn test_single_number() {
+        let input = "42\n*";
+        assert_eq!(solve_part1(input), Ok(42));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert_eq!(parse_problems(""), Ok(vec![]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown operator")]
+    fn test_unknown_operator() {
+        solve_problem(&[1, 2], '-');
+    }
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(solve_part2(EXAMPLE), Ok(3263827));
+    }
+
+    #[test]
+    fn test_parse_problems_part2_example() {
+        let problems = parse_problems_part2(EXAMPLE).unwrap();
+        assert_eq!(problems.len(), 4);
+        // Leftmost problem read column-wise right-to-left: 356 * 24 * 1
+        assert_eq!(problems[0], (vec![356, 24, 1], '*'));
+        // Second from left: 8 + 248 + 369
+        assert_eq!(problems[1], (vec![8, 248, 369], '+'));
+        // Third from left: 175 * 581 * 32
+        assert_eq!(problems[2], (vec![175, 581, 32], '*'));
+        // Rightmost: 4 + 431 + 623
+        assert_eq!(problems[3], (vec![4, 431, 623], '+'));
+    }
+
+    #[test]
+    fn test_part2_single_column() {
+        let input = "1\n2\n3\n+";
+        assert_eq!(solve_part2(input), Ok(123));
+    }
+
+    #[test]
+    fn test_part2_empty_input() {
+        assert_eq!(parse_problems_part2(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_parse_problems_invalid_number() {
+        let error = parse_problems("12 3x\n4  5\n*  +").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (6, 1, 4));
+        assert_eq!(error.found, "3x");
+        assert_eq!(error.expected, "number in problem");
+    }
+
+    #[test]
+    fn test_parse_problems_invalid_operator() {
+        let error = parse_problems("1 2\n3 4\n* -").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.found, "-");
+        assert_eq!(error.expected, "operator `+` or `*`");
+    }
+
+    #[test]
+    fn test_parse_problems_missing_operator() {
+        let error = parse_problems("1 2\n3 4\n*").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.found, "");
+    }
+
+    #[test]
+    fn test_parse_problems_part2_invalid_digit() {
+        let error = parse_problems_part2("1 2\n3 x\n* +").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, "digit or space");
+    }
+
+    #[test]
+    fn test_parse_problems_part2_number_too_large() {
+        let input = format!("{}*", "9\n".repeat(25));
+        let error = parse_problems_part2(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.expected, "column number that fits in 64 bits");
+    }
+
+    #[test]
+    fn test_column_slice() {
+        assert_eq!(column_slice("abcdef", 1, 3), "bc");
+        assert_eq!(column_slice("abc", 2, 10), "c");
+        assert_eq!(column_slice("abc", 5, 10), "");
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        registry::assert_windows_agnostic(Day06::DAY, EXAMPLE);
+        let windows = input::windows(EXAMPLE);
+        assert_eq!(solve_part1(&windows), solve_part1(EXAMPLE));
+        assert_eq!(solve_part2(&windows), solve_part2(EXAMPLE));
+    }
+}
@@ -0,0 +1,344 @@
+//! Day 7: Laboratories
+
+use std::collections::{HashMap, HashSet};
+
+use crate::error::ParseError;
+use crate::input;
+use crate::solution::{Answer, Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Laboratories";
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_manifold(input)
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> Answer {
+        count_splits(lines).into()
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> Answer {
+        count_timelines(lines).into()
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let input = input::normalize(input);
+    Ok(count_splits(&parse_manifold(&input)?))
+}
+
+pub fn solve_part2(input: &str) -> Result<u64, ParseError> {
+    let input = input::normalize(input);
+    Ok(count_timelines(&parse_manifold(&input)?))
+}
+
+/// Split the manifold into rows, checking it only holds `.`, `^` and a start `S`.
+pub fn parse_manifold(input: &str) -> Result<Vec<&str>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+
+    for line in &lines {
+        if let Some((i, c)) = line
+            .char_indices()
+            .find(|&(_, c)| !matches!(c, '.' | '^' | 'S'))
+        {
+            let found = &line[i..i + c.len_utf8()];
+            return Err(ParseError::new(Day07::DAY, input, found, "`.`, `^` or `S`"));
+        }
+    }
+
+    if !lines.is_empty() && find_start(&lines).is_none() {
+        let end_of_input = &input[input.len()..];
+        return Err(ParseError::new(
+            Day07::DAY,
+            input,
+            end_of_input,
+            "start position `S`",
+        ));
+    }
+
+    Ok(lines)
+}
+
+pub fn count_splits(lines: &[&str]) -> usize {
+    if lines.is_empty() {
+        return 0;
+    }
+
+    let Some((start_row, start_col)) = find_start(lines) else {
+        return 0;
+    };
+    simulate_beams(lines, start_row, start_col)
+}
+
+pub fn count_timelines(lines: &[&str]) -> u64 {
+    if lines.is_empty() {
+        return 0;
+    }
+
+    let Some((start_row, start_col)) = find_start(lines) else {
+        return 0;
+    };
+    simulate_timelines(lines, start_row, start_col)
+}
+
+pub fn find_start(lines: &[&str]) -> Option<(usize, usize)> {
+    lines
+        .iter()
+        .enumerate()
+        .find_map(|(row, line)| line.find('S').map(|col| (row, col)))
+}
+
+pub fn simulate_beams(lines: &[&str], start_row: usize, start_col: usize) -> usize {
+    let mut beams: HashSet<usize> = HashSet::new();
+    beams.insert(start_col);
+
+    let mut split_count = 0;
+    let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+
+    for line in lines.iter().skip(start_row + 1) {
+        let mut new_beams: HashSet<usize> = HashSet::new();
+
+        for &col in &beams {
+            let char_at = line.chars().nth(col).unwrap_or('.');
+
+            if char_at == '^' {
+                split_count += 1;
+                // Spawn beams to left and right
+                if col > 0 {
+                    new_beams.insert(col - 1);
+                }
+                if col + 1 < width {
+                    new_beams.insert(col + 1);
+                }
+            } else {
+                // Beam continues downward
+                new_beams.insert(col);
+            }
+        }
+
+        beams = new_beams;
+
+        if beams.is_empty() {
+            break;
+        }
+    }
+
+    split_count
+}
+
+pub fn simulate_timelines(lines: &[&str], start_row: usize, start_col: usize) -> u64 {
+    let mut timelines: HashMap<usize, u64> = HashMap::new();
+    timelines.insert(start_col, 1);
+
+    let width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+
+    for line in lines.iter().skip(start_row + 1) {
+        let mut new_timelines: HashMap<usize, u64> = HashMap::new();
+
+        for (&col, &count) in &timelines {
+            let char_at = line.chars().nth(col).unwrap_or('.');
+
+            if char_at == '^' {
+                // Split: each timeline at this position creates two new timelines
+                if col > 0 {
+                    *new_timelines.entry(col - 1).or_insert(0) += count;
+                }
+                if col + 1 < width {
+                    *new_timelines.entry(col + 1).or_insert(0) += count;
+                }
+            } else {
+                // Continue: timelines pass through
+                *new_timelines.entry(col).or_insert(0) += count;
+            }
+        }
+
+        timelines = new_timelines;
+
+        if timelines.is_empty() {
+            break;
+        }
+    }
+
+    timelines.values().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_part1(EXAMPLE), Ok(21));
+    }
+
+    #[test]
+    fn test_find_start() {
+        assert_eq!(find_start(&[".......S......."]), Some((0, 7)));
+        assert_eq!(find_start(&["...", ".S.", "..."]), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_find_start_missing() {
+        assert_eq!(find_start(&["...", "...", "..."]), None);
+    }
+
+    #[test]
+    fn test_parse_manifold_missing_start() {
+        let error = parse_manifold(
+            "...
+...
+",
+        )
+        .unwrap_err();
+        assert_eq!(error.expected, "start position `S`");
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+
+    #[test]
+    fn test_parse_manifold_invalid_char() {
+        let error = parse_manifold(
+            "..S..
+.....
+..#..",
+        )
+        .unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (7, 3, 3));
+        assert_eq!(error.found, "#");
+    }
+
+    #[test]
+    fn test_count_without_start() {
+        assert_eq!(count_splits(&["..."]), 0);
+        assert_eq!(count_timelines(&["..."]), 0);
+    }
+
+    #[test]
+    fn test_single_splitter() {
+        let input = "S\n.\n^\n.";
+        assert_eq!(solve_part1(input), Ok(1));
+    }
+
+    #[test]
+    fn test_no_splitters() {
+        let input = "S\n.\n.\n.";
+        assert_eq!(solve_part1(input), Ok(0));
+    }
+
+    #[test]
+    fn test_beam_merging() {
+        // Two splitters side by side, beams merge in the middle
+        let input = "\
+..S..
+.....
+..^..
+.....
+.^.^.
+.....";
+        // First split at row 2, creates beams at col 1 and 3
+        // Second row has splitters at col 1 and 3
+        // Each beam hits a splitter = 2 more splits
+        assert_eq!(solve_part1(input), Ok(3));
+    }
+
+    #[test]
+    fn test_beam_exits_edge() {
+        // Beam goes off left edge
+        let input = "\
+S...
+....
+^...
+....";
+        // Splitter at col 0, beam splits to col -1 (exits) and col 1
+        assert_eq!(solve_part1(input), Ok(1));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert_eq!(solve_part1(""), Ok(0));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(solve_part2(EXAMPLE), Ok(40));
+    }
+
+    #[test]
+    fn test_part2_single_splitter() {
+        // One split = 2 timelines (need wider grid so beams don't exit)
+        let input = ".S.\n...\n.^.\n...";
+        assert_eq!(solve_part2(input), Ok(2));
+    }
+
+    #[test]
+    fn test_part2_no_splitters() {
+        // No splits = 1 timeline
+        let input = "S\n.\n.\n.";
+        assert_eq!(solve_part2(input), Ok(1));
+    }
+
+    #[test]
+    fn test_part2_chain_splits() {
+        // Two sequential splitters on same path = 4 timelines
+        let input = "\
+..S..
+.....
+..^..
+.....
+.^.^.
+.....";
+        // First split: 2 timelines (col 1 and col 3)
+        // Second split at col 1: 2 more (col 0 and col 2)
+        // Second split at col 3: 2 more (col 2 and col 4)
+        // Total: 4 timelines at cols 0, 2, 2, 4 = positions 0(1), 2(2), 4(1)
+        assert_eq!(solve_part2(input), Ok(4));
+    }
+
+    #[test]
+    fn test_part2_empty_input() {
+        assert_eq!(solve_part2(""), Ok(0));
+    }
+
+    #[test]
+    fn test_part2_edge_exit() {
+        // Splitter at edge, one beam exits
+        let input = "\
+S...
+....
+^...
+....";
+        // Split at col 0: left exits (lost), right continues = 1 timeline
+        assert_eq!(solve_part2(input), Ok(1));
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        registry::assert_windows_agnostic(Day07::DAY, EXAMPLE);
+        let windows = input::windows(EXAMPLE);
+        assert_eq!(solve_part1(&windows), solve_part1(EXAMPLE));
+        assert_eq!(solve_part2(&windows), solve_part2(EXAMPLE));
+    }
+}
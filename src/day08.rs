@@ -0,0 +1,471 @@
+//! Day 8: Playground
+
+use crate::error::ParseError;
+use crate::input;
+use crate::params::{Param, Params};
+use crate::solution::{Answer, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Playground";
+    type Input<'a> = Vec<(i64, i64, i64)>;
+    const PARAMS: &'static [Param] = &[
+        Param::int(
+            "connections",
+            "1000",
+            0,
+            i64::MAX,
+            "closest pairs to connect in part 1",
+        ),
+        // Every pair of boxes is held in memory, so there are far fewer than 2^32 boxes; the
+        // product of the largest 4 circuits among them fits in a u128, a fifth could overflow.
+        Param::int("circuits", "3", 1, 4, "largest circuits to multiply"),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_coordinates(input)
+    }
+
+    fn part1(coords: &Self::Input<'_>) -> Answer {
+        Self::part1_with(coords, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part1_with(coords: &Self::Input<'_>, params: &Params) -> Answer {
+        largest_circuits_product_of(
+            coords,
+            params.int("connections") as usize,
+            params.int("circuits") as usize,
+        )
+        .into()
+    }
+
+    fn part2(coords: &Self::Input<'_>) -> Answer {
+        last_connection_product(coords).into()
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<u128, ParseError> {
+    let input = input::normalize(input);
+    solve_with_connections(&input, 1000)
+}
+
+pub fn solve_part2(input: &str) -> Result<i64, ParseError> {
+    let input = input::normalize(input);
+    Ok(last_connection_product(&parse_coordinates(&input)?))
+}
+
+/// Product of the X coordinates of the pair whose connection joins everything into one circuit.
+pub fn last_connection_product(coords: &[(i64, i64, i64)]) -> i64 {
+    if coords.len() < 2 {
+        return 0;
+    }
+
+    let pairs = generate_sorted_pairs(coords);
+    let mut uf = UnionFind::new(coords.len());
+
+    let mut last_i = 0;
+    let mut last_j = 0;
+
+    for (i, j, _dist) in pairs {
+        // Only track connections that actually merge two circuits
+        if uf.union(i, j) {
+            last_i = i;
+            last_j = j;
+        }
+
+        // Stop when all boxes are in one circuit
+        if uf.circuit_count() == 1 {
+            break;
+        }
+    }
+
+    coords[last_i].0 * coords[last_j].0
+}
+
+pub fn solve_with_connections(input: &str, num_connections: usize) -> Result<u128, ParseError> {
+    Ok(largest_circuits_product(
+        &parse_coordinates(input)?,
+        num_connections,
+    ))
+}
+
+/// Product of the three largest circuit sizes after the `num_connections` closest pairs are joined.
+pub fn largest_circuits_product(coords: &[(i64, i64, i64)], num_connections: usize) -> u128 {
+    largest_circuits_product_of(coords, num_connections, 3)
+}
+
+/// Product of the `circuits` largest circuit sizes after the `num_connections` closest pairs
+/// are joined.
+pub fn largest_circuits_product_of(
+    coords: &[(i64, i64, i64)],
+    num_connections: usize,
+    circuits: usize,
+) -> u128 {
+    if coords.len() < 2 {
+        return if coords.len() == 1 { 1 } else { 0 };
+    }
+
+    let pairs = generate_sorted_pairs(coords);
+    let mut uf = UnionFind::new(coords.len());
+
+    for (i, j, _dist) in pairs.into_iter().take(num_connections) {
+        // Attempt connection (may be redundant if already in same circuit)
+        uf.union(i, j);
+    }
+
+    let sizes = uf.circuit_sizes();
+    let mut sorted_sizes: Vec<usize> = sizes.into_iter().collect();
+    sorted_sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+    sorted_sizes
+        .iter()
+        .take(circuits)
+        .map(|&s| s as u128)
+        .product()
+}
+
+pub fn parse_coordinates(input: &str) -> Result<Vec<(i64, i64, i64)>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let parts = line
+                .split(',')
+                .map(|part| {
+                    part.parse()
+                        .map_err(|_| ParseError::new(Day08::DAY, input, part, "integer coordinate"))
+                })
+                .collect::<Result<Vec<i64>, _>>()?;
+            match parts[..] {
+                [x, y, z] => Ok((x, y, z)),
+                _ => Err(ParseError::new(
+                    Day08::DAY,
+                    input,
+                    line,
+                    "three comma-separated coordinates",
+                )),
+            }
+        })
+        .collect()
+}
+
+pub fn distance_squared(a: (i64, i64, i64), b: (i64, i64, i64)) -> i64 {
+    let dx = a.0 - b.0;
+    let dy = a.1 - b.1;
+    let dz = a.2 - b.2;
+    dx * dx + dy * dy + dz * dz
+}
+
+pub fn generate_sorted_pairs(coords: &[(i64, i64, i64)]) -> Vec<(usize, usize, i64)> {
+    let mut pairs = Vec::new();
+    for i in 0..coords.len() {
+        for j in (i + 1)..coords.len() {
+            let dist = distance_squared(coords[i], coords[j]);
+            pairs.push((i, j, dist));
+        }
+    }
+    pairs.sort_unstable_by_key(|&(_, _, dist)| dist);
+    pairs
+}
+
+pub struct UnionFind {
+    pub parent: Vec<usize>,
+    pub rank: Vec<usize>,
+    pub size: Vec<usize>,
+}
+
+impl UnionFind {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            size: vec![1; n],
+        }
+    }
+
+    pub fn find(&mut self, x: usize) -> usize {
+        if self.parent[x] != x {
+            self.parent[x] = self.find(self.parent[x]);
+        }
+        self.parent[x]
+    }
+
+    pub fn union(&mut self, x: usize, y: usize) -> bool {
+        let root_x = self.find(x);
+        let root_y = self.find(y);
+
+        if root_x == root_y {
+            return false;
+        }
+
+        match self.rank[root_x].cmp(&self.rank[root_y]) {
+            std::cmp::Ordering::Less => {
+                self.parent[root_x] = root_y;
+                self.size[root_y] += self.size[root_x];
+            }
+            std::cmp::Ordering::Greater => {
+                self.parent[root_y] = root_x;
+                self.size[root_x] += self.size[root_y];
+            }
+            std::cmp::Ordering::Equal => {
+                self.parent[root_y] = root_x;
+                self.size[root_x] += self.size[root_y];
+                self.rank[root_x] += 1;
+            }
+        }
+        true
+    }
+
+    pub fn circuit_sizes(&mut self) -> Vec<usize> {
+        let n = self.parent.len();
+        let mut sizes = Vec::new();
+        for i in 0..n {
+            if self.find(i) == i {
+                sizes.push(self.size[i]);
+            }
+        }
+        sizes
+    }
+
+    pub fn circuit_count(&mut self) -> usize {
+        let n = self.parent.len();
+        (0..n).filter(|&i| self.find(i) == i).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+    use crate::solution::Part;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_with_connections(EXAMPLE, 10), Ok(40));
+    }
+
+    #[test]
+    fn test_parse_coordinates() {
+        assert_eq!(
+            parse_coordinates("1,2,3\n4,5,6"),
+            Ok(vec![(1, 2, 3), (4, 5, 6)])
+        );
+    }
+
+    #[test]
+    fn test_parse_coordinates_empty() {
+        assert_eq!(parse_coordinates(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_parse_coordinates_with_empty_lines() {
+        assert_eq!(
+            parse_coordinates("1,2,3\n\n4,5,6\n"),
+            Ok(vec![(1, 2, 3), (4, 5, 6)])
+        );
+    }
+
+    #[test]
+    fn test_parse_coordinates_invalid() {
+        // Only 2 values - reported instead of skipped
+        let error = parse_coordinates("1,2,3\n1,2\n4,5,6").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (8, 2, 1));
+        assert_eq!(error.expected, "three comma-separated coordinates");
+    }
+
+    #[test]
+    fn test_parse_coordinates_not_a_number() {
+        let error = parse_coordinates("1,2,3\n1,z,3").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.found, "z");
+    }
+
+    #[test]
+    fn test_distance_squared() {
+        assert_eq!(distance_squared((0, 0, 0), (1, 0, 0)), 1);
+        assert_eq!(distance_squared((0, 0, 0), (1, 1, 1)), 3);
+        assert_eq!(distance_squared((0, 0, 0), (3, 4, 0)), 25);
+    }
+
+    #[test]
+    fn test_distance_squared_negative() {
+        assert_eq!(distance_squared((5, 5, 5), (2, 1, 5)), 25);
+    }
+
+    #[test]
+    fn test_union_find_basic() {
+        let mut uf = UnionFind::new(5);
+        assert!(uf.union(0, 1));
+        assert!(uf.union(2, 3));
+        assert_eq!(uf.find(0), uf.find(1));
+        assert_ne!(uf.find(0), uf.find(2));
+    }
+
+    #[test]
+    fn test_union_find_same_circuit() {
+        let mut uf = UnionFind::new(3);
+        assert!(uf.union(0, 1));
+        assert!(uf.union(1, 2));
+        assert!(!uf.union(0, 2)); // Already connected
+    }
+
+    #[test]
+    fn test_union_find_sizes() {
+        let mut uf = UnionFind::new(5);
+        uf.union(0, 1);
+        uf.union(1, 2);
+        let sizes = uf.circuit_sizes();
+        assert!(sizes.contains(&3));
+        assert!(sizes.contains(&1));
+        assert_eq!(sizes.len(), 3);
+    }
+
+    #[test]
+    fn test_generate_sorted_pairs() {
+        let coords = vec![(0, 0, 0), (1, 0, 0), (10, 0, 0)];
+        let pairs = generate_sorted_pairs(&coords);
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs[0], (0, 1, 1)); // Closest pair first
+    }
+
+    #[test]
+    fn test_solve_empty() {
+        assert_eq!(solve_with_connections("", 10), Ok(0));
+    }
+
+    #[test]
+    fn test_solve_single_box() {
+        assert_eq!(solve_with_connections("1,2,3", 10), Ok(1));
+    }
+
+    #[test]
+    fn test_solve_two_boxes() {
+        assert_eq!(solve_with_connections("0,0,0\n1,1,1", 1), Ok(2));
+    }
+
+    #[test]
+    fn test_solve_no_connections() {
+        // 3 boxes, 0 connections = 3 circuits of size 1 = 1*1*1 = 1
+        assert_eq!(solve_with_connections("0,0,0\n1,0,0\n2,0,0", 0), Ok(1));
+    }
+
+    #[test]
+    fn test_solve_all_connected() {
+        // 3 boxes, 2+ connections = 1 circuit of size 3
+        // Product of top 3 sizes: 3 * 1 * 1 (padding with 1s) = 3
+        // Actually only 1 circuit exists, so it's just 3
+        let result = solve_with_connections("0,0,0\n1,0,0\n2,0,0", 3);
+        assert_eq!(result, Ok(3));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        // Last connection is between 216,146,977 and 117,168,530
+        // Product of X coords: 216 * 117 = 25272
+        assert_eq!(solve_part2(EXAMPLE), Ok(25272));
+    }
+
+    #[test]
+    fn test_part2_two_boxes() {
+        // Two boxes: first connection unites them
+        // X coords: 5 * 10 = 50
+        assert_eq!(solve_part2("5,0,0\n10,0,0"), Ok(50));
+    }
+
+    #[test]
+    fn test_part2_empty() {
+        assert_eq!(solve_part2(""), Ok(0));
+    }
+
+    #[test]
+    fn test_part2_single_box() {
+        assert_eq!(solve_part2("1,2,3"), Ok(0));
+    }
+
+    #[test]
+    fn test_circuit_count() {
+        let mut uf = UnionFind::new(5);
+        assert_eq!(uf.circuit_count(), 5);
+        uf.union(0, 1);
+        assert_eq!(uf.circuit_count(), 4);
+        uf.union(2, 3);
+        assert_eq!(uf.circuit_count(), 3);
+        uf.union(0, 2);
+        assert_eq!(uf.circuit_count(), 2);
+    }
+
+    #[test]
+    fn test_part1_params() {
+        let overrides = |pairs: &[(&str, &str)]| {
+            let pairs: Vec<_> = pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            Params::resolve(Day08::PARAMS, &pairs).unwrap()
+        };
+        let day = registry::find(Day08::DAY).unwrap();
+        let part1 = |params: &Params| {
+            day.run_with(EXAMPLE, &[Part::One], params).unwrap().parts[0]
+                .answer
+                .clone()
+        };
+
+        assert_eq!(
+            part1(&overrides(&[("connections", "10")])),
+            Answer::from(40u64)
+        );
+        assert_eq!(
+            part1(&overrides(&[("connections", "10"), ("circuits", "1")])),
+            Answer::from(5u64)
+        );
+        assert_eq!(
+            largest_circuits_product_of(&parse_coordinates(EXAMPLE).unwrap(), 10, 3),
+            40
+        );
+        assert_eq!(
+            part1(&overrides(&[("connections", "10"), ("circuits", "4")])),
+            Answer::from(80u64)
+        );
+        assert_eq!(
+            Params::resolve(
+                Day08::PARAMS,
+                &[("circuits".to_string(), "400".to_string())]
+            )
+            .map(drop),
+            Err("parameter `circuits`: expected an integer from 1 to 4, got `400`".to_string())
+        );
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        registry::assert_windows_agnostic(Day08::DAY, EXAMPLE);
+        let windows = input::windows(EXAMPLE);
+        assert_eq!(solve_part1(&windows), solve_part1(EXAMPLE));
+        assert_eq!(solve_part2(&windows), solve_part2(EXAMPLE));
+    }
+}
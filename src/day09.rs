@@ -0,0 +1,480 @@
+//! Day 9: Movie Theater
+
+use std::collections::{HashMap, HashSet};
+
+use crate::error::ParseError;
+use crate::input;
+use crate::solution::{Answer, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Movie Theater";
+    type Input<'a> = Vec<(i64, i64)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_coordinates(input)
+    }
+
+    fn part1(red_tiles: &Self::Input<'_>) -> Answer {
+        largest_rectangle(red_tiles).into()
+    }
+
+    fn part2(red_tiles: &Self::Input<'_>) -> Answer {
+        largest_colored_rectangle(red_tiles).into()
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<i64, ParseError> {
+    let input = input::normalize(input);
+    Ok(largest_rectangle(&parse_coordinates(&input)?))
+}
+
+pub fn solve_part2(input: &str) -> Result<i64, ParseError> {
+    let input = input::normalize(input);
+    Ok(largest_colored_rectangle(&parse_coordinates(&input)?))
+}
+
+/// Largest rectangle with red tiles in two opposite corners.
+pub fn largest_rectangle(coords: &[(i64, i64)]) -> i64 {
+    if coords.len() < 2 {
+        return 0;
+    }
+
+    let mut max_area = 0;
+
+    for i in 0..coords.len() {
+        for j in (i + 1)..coords.len() {
+            let area = rectangle_area(coords[i], coords[j]);
+            max_area = max_area.max(area);
+        }
+    }
+
+    max_area
+}
+
+pub fn parse_coordinates(input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let parts = line
+                .split(',')
+                .map(|part| {
+                    part.parse()
+                        .map_err(|_| ParseError::new(Day09::DAY, input, part, "integer coordinate"))
+                })
+                .collect::<Result<Vec<i64>, _>>()?;
+            match parts[..] {
+                [x, y] => Ok((x, y)),
+                _ => Err(ParseError::new(
+                    Day09::DAY,
+                    input,
+                    line,
+                    "two comma-separated coordinates",
+                )),
+            }
+        })
+        .collect()
+}
+
+pub fn rectangle_area(p1: (i64, i64), p2: (i64, i64)) -> i64 {
+    let width = (p2.0 - p1.0).abs() + 1;
+    let height = (p2.1 - p1.1).abs() + 1;
+    width * height
+}
+
+/// Largest rectangle with red corners that only covers red or green tiles.
+pub fn largest_colored_rectangle(red_tiles: &[(i64, i64)]) -> i64 {
+    if red_tiles.len() < 2 {
+        return 0;
+    }
+
+    // Coordinate compression; `c + 1` keeps a cell for the gap after each coordinate, which
+    // may lie outside the loop even when both of its neighbours are on it
+    let (x_to_idx, idx_to_x) = compress_coords(red_tiles.iter().flat_map(|p| [p.0, p.0 + 1]));
+    let (y_to_idx, idx_to_y) = compress_coords(red_tiles.iter().flat_map(|p| [p.1, p.1 + 1]));
+
+    // Convert red tiles to compressed coordinates
+    let compressed_red: Vec<(usize, usize)> = red_tiles
+        .iter()
+        .map(|p| (x_to_idx[&p.0], y_to_idx[&p.1]))
+        .collect();
+
+    // Build colored tiles in compressed space
+    let colored = build_colored_compressed(&compressed_red, idx_to_x.len(), idx_to_y.len());
+
+    let gaps = gap_prefix_sums(&colored, idx_to_x.len(), idx_to_y.len());
+
+    // Find largest valid rectangle
+    let mut max_area = 0;
+
+    for i in 0..red_tiles.len() {
+        for j in (i + 1)..red_tiles.len() {
+            let p1_comp = compressed_red[i];
+            let p2_comp = compressed_red[j];
+
+            if is_valid_rectangle_sums(&gaps, p1_comp, p2_comp) {
+                let area = rectangle_area(red_tiles[i], red_tiles[j]);
+                max_area = max_area.max(area);
+            }
+        }
+    }
+
+    max_area
+}
+
+pub fn compress_coords<I: Iterator<Item = i64>>(coords: I) -> (HashMap<i64, usize>, Vec<i64>) {
+    let mut unique: Vec<i64> = coords.collect();
+    unique.sort();
+    unique.dedup();
+
+    let to_idx: HashMap<i64, usize> = unique.iter().enumerate().map(|(i, &v)| (v, i)).collect();
+    (to_idx, unique)
+}
+
+pub fn build_colored_compressed(
+    red_tiles: &[(usize, usize)],
+    width: usize,
+    height: usize,
+) -> HashSet<(usize, usize)> {
+    let mut boundary: HashSet<(usize, usize)> = HashSet::new();
+
+    // Add all red tiles
+    for &tile in red_tiles {
+        boundary.insert(tile);
+    }
+
+    // Connect consecutive red tiles with lines (wrapping)
+    for i in 0..red_tiles.len() {
+        let p1 = red_tiles[i];
+        let p2 = red_tiles[(i + 1) % red_tiles.len()];
+        add_line_compressed(&mut boundary, p1, p2);
+    }
+
+    // Fill interior using flood fill from outside
+    fill_interior_compressed(&boundary, width, height)
+}
+
+pub fn add_line_compressed(
+    colored: &mut HashSet<(usize, usize)>,
+    p1: (usize, usize),
+    p2: (usize, usize),
+) {
+    let (x1, y1) = p1;
+    let (x2, y2) = p2;
+
+    if x1 == x2 {
+        // Vertical line
+        let min_y = y1.min(y2);
+        let max_y = y1.max(y2);
+        for y in min_y..=max_y {
+            colored.insert((x1, y));
+        }
+    } else {
+        // Horizontal line
+        let min_x = x1.min(x2);
+        let max_x = x1.max(x2);
+        for x in min_x..=max_x {
+            colored.insert((x, y1));
+        }
+    }
+}
+
+pub fn fill_interior_compressed(
+    boundary: &HashSet<(usize, usize)>,
+    width: usize,
+    height: usize,
+) -> HashSet<(usize, usize)> {
+    // Flood fill from outside (using padded grid)
+    let mut outside: HashSet<(i32, i32)> = HashSet::new();
+    let mut stack = vec![(-1_i32, -1_i32)];
+
+    let w = width as i32;
+    let h = height as i32;
+
+    while let Some((x, y)) = stack.pop() {
+        if x < -1 || x > w || y < -1 || y > h {
+            continue;
+        }
+        if outside.contains(&(x, y)) {
+            continue;
+        }
+        // Check if this is a boundary point (only for valid indices)
+        if x >= 0 && x < w && y >= 0 && y < h && boundary.contains(&(x as usize, y as usize)) {
+            continue;
+        }
+        outside.insert((x, y));
+        stack.push((x - 1, y));
+        stack.push((x + 1, y));
+        stack.push((x, y - 1));
+        stack.push((x, y + 1));
+    }
+
+    // All tiles in grid that are not outside are colored (boundary or interior)
+    let mut colored: HashSet<(usize, usize)> = HashSet::new();
+    for x in 0..width {
+        for y in 0..height {
+            if !outside.contains(&(x as i32, y as i32)) {
+                colored.insert((x, y));
+            }
+        }
+    }
+    colored
+}
+
+/// `sums[x][y]` counts the uncolored cells in `0..x` by `0..y`.
+pub fn gap_prefix_sums(
+    colored: &HashSet<(usize, usize)>,
+    width: usize,
+    height: usize,
+) -> Vec<Vec<usize>> {
+    let mut sums = vec![vec![0; height + 1]; width + 1];
+    for x in 0..width {
+        for y in 0..height {
+            let gap = usize::from(!colored.contains(&(x, y)));
+            sums[x + 1][y + 1] = sums[x][y + 1] + sums[x + 1][y] - sums[x][y] + gap;
+        }
+    }
+    sums
+}
+
+/// Whether every cell between corners `p1` and `p2` is colored, in O(1) using
+/// [`gap_prefix_sums`].
+pub fn is_valid_rectangle_sums(
+    sums: &[Vec<usize>],
+    p1: (usize, usize),
+    p2: (usize, usize),
+) -> bool {
+    let (x0, x1) = (p1.0.min(p2.0), p1.0.max(p2.0) + 1);
+    let (y0, y1) = (p1.1.min(p2.1), p1.1.max(p2.1) + 1);
+    sums[x1][y1] + sums[x0][y0] == sums[x0][y1] + sums[x1][y0]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator;
+    use crate::prop;
+    use crate::registry;
+    use crate::rng::Rng;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3";
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_part1(EXAMPLE), Ok(50));
+    }
+
+    #[test]
+    fn test_rectangle_area_example_50() {
+        assert_eq!(rectangle_area((2, 5), (11, 1)), 50);
+    }
+
+    #[test]
+    fn test_rectangle_area_example_35() {
+        assert_eq!(rectangle_area((7, 1), (11, 7)), 35);
+    }
+
+    #[test]
+    fn test_rectangle_area_example_24() {
+        assert_eq!(rectangle_area((2, 5), (9, 7)), 24);
+    }
+
+    #[test]
+    fn test_rectangle_area_example_6() {
+        assert_eq!(rectangle_area((7, 3), (2, 3)), 6);
+    }
+
+    #[test]
+    fn test_rectangle_area_same_point() {
+        assert_eq!(rectangle_area((5, 5), (5, 5)), 1);
+    }
+
+    #[test]
+    fn test_rectangle_area_same_column() {
+        assert_eq!(rectangle_area((3, 1), (3, 5)), 5);
+    }
+
+    #[test]
+    fn test_parse_coordinates() {
+        assert_eq!(parse_coordinates("1,2\n3,4"), Ok(vec![(1, 2), (3, 4)]));
+    }
+
+    #[test]
+    fn test_parse_coordinates_empty() {
+        assert_eq!(parse_coordinates(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_parse_coordinates_with_empty_lines() {
+        assert_eq!(parse_coordinates("1,2\n\n3,4\n"), Ok(vec![(1, 2), (3, 4)]));
+    }
+
+    #[test]
+    fn test_parse_coordinates_invalid() {
+        let error = parse_coordinates("1,2\n3\n4,5").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (9, 2, 1));
+        assert_eq!(error.expected, "two comma-separated coordinates");
+    }
+
+    #[test]
+    fn test_parse_coordinates_not_a_number() {
+        let error = parse_coordinates("1,2\n4,-x").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.found, "-x");
+    }
+
+    #[test]
+    fn test_solve_empty() {
+        assert_eq!(solve_part1(""), Ok(0));
+    }
+
+    #[test]
+    fn test_solve_single_tile() {
+        assert_eq!(solve_part1("5,5"), Ok(0));
+    }
+
+    #[test]
+    fn test_solve_two_tiles() {
+        assert_eq!(solve_part1("0,0\n2,3"), Ok(12));
+    }
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(solve_part2(EXAMPLE), Ok(24));
+    }
+
+    #[test]
+    fn test_part2_empty() {
+        assert_eq!(solve_part2(""), Ok(0));
+    }
+
+    #[test]
+    fn test_part2_single_tile() {
+        assert_eq!(solve_part2("5,5"), Ok(0));
+    }
+
+    #[test]
+    fn test_compress_coords() {
+        let coords = vec![100, 50, 200, 50];
+        let (to_idx, to_val) = compress_coords(coords.into_iter());
+        assert_eq!(to_idx[&50], 0);
+        assert_eq!(to_idx[&100], 1);
+        assert_eq!(to_idx[&200], 2);
+        assert_eq!(to_val, vec![50, 100, 200]);
+    }
+
+    #[test]
+    fn test_add_line_compressed_horizontal() {
+        let mut colored = HashSet::new();
+        add_line_compressed(&mut colored, (1, 2), (4, 2));
+        assert_eq!(colored.len(), 4);
+        for x in 1..=4 {
+            assert!(colored.contains(&(x, 2)));
+        }
+    }
+
+    #[test]
+    fn test_add_line_compressed_vertical() {
+        let mut colored = HashSet::new();
+        add_line_compressed(&mut colored, (2, 1), (2, 3));
+        assert_eq!(colored.len(), 3);
+        for y in 1..=3 {
+            assert!(colored.contains(&(2, y)));
+        }
+    }
+
+    #[test]
+    fn test_is_valid_rectangle_sums() {
+        let mut colored = HashSet::new();
+        for x in 0..=3 {
+            for y in 0..=2 {
+                colored.insert((x, y));
+            }
+        }
+        colored.remove(&(2, 1));
+        let sums = gap_prefix_sums(&colored, 5, 3);
+        assert_eq!(sums[5][3], 4);
+        assert!(is_valid_rectangle_sums(&sums, (0, 0), (1, 2)));
+        assert!(is_valid_rectangle_sums(&sums, (3, 2), (3, 0)));
+        assert!(!is_valid_rectangle_sums(&sums, (0, 0), (3, 2)));
+        assert!(!is_valid_rectangle_sums(&sums, (2, 1), (2, 1)));
+        assert!(!is_valid_rectangle_sums(&sums, (3, 0), (4, 0)));
+    }
+
+    /// Same flood fill as the solver, but over every tile instead of compressed coordinates.
+    fn naive_largest_colored_rectangle(red_tiles: &[(i64, i64)]) -> i64 {
+        let min_x = red_tiles.iter().map(|p| p.0).min().unwrap() - 1;
+        let min_y = red_tiles.iter().map(|p| p.1).min().unwrap() - 1;
+        let width = (red_tiles.iter().map(|p| p.0).max().unwrap() - min_x + 2) as usize;
+        let height = (red_tiles.iter().map(|p| p.1).max().unwrap() - min_y + 2) as usize;
+        let index = |(x, y): (i64, i64)| (x - min_x) as usize * height + (y - min_y) as usize;
+
+        let mut boundary = vec![false; width * height];
+        for i in 0..red_tiles.len() {
+            let (a, b) = (red_tiles[i], red_tiles[(i + 1) % red_tiles.len()]);
+            for x in a.0.min(b.0)..=a.0.max(b.0) {
+                for y in a.1.min(b.1)..=a.1.max(b.1) {
+                    boundary[index((x, y))] = true;
+                }
+            }
+        }
+
+        let mut outside = vec![false; width * height];
+        let mut stack = vec![(min_x, min_y)];
+        while let Some((x, y)) = stack.pop() {
+            let inside = (min_x..min_x + width as i64).contains(&x)
+                && (min_y..min_y + height as i64).contains(&y);
+            if !inside || outside[index((x, y))] || boundary[index((x, y))] {
+                continue;
+            }
+            outside[index((x, y))] = true;
+            stack.extend([(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]);
+        }
+
+        let mut best = 0;
+        for (i, &a) in red_tiles.iter().enumerate() {
+            for &b in &red_tiles[i + 1..] {
+                let colored = (a.0.min(b.0)..=a.0.max(b.0))
+                    .all(|x| (a.1.min(b.1)..=a.1.max(b.1)).all(|y| !outside[index((x, y))]));
+                if colored {
+                    best = best.max(rectangle_area(a, b));
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn test_largest_colored_rectangle_matches_naive() {
+        let generate = |rng: &mut Rng| {
+            let columns = rng.range(1..=5) as usize;
+            generator::rectilinear_loop(rng, columns, 4, 8)
+        };
+        prop::check("day09 largest_colored_rectangle", generate, |tiles| {
+            prop::agree(
+                largest_colored_rectangle(tiles),
+                naive_largest_colored_rectangle(tiles),
+            )
+        });
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        registry::assert_windows_agnostic(Day09::DAY, EXAMPLE);
+        let windows = input::windows(EXAMPLE);
+        assert_eq!(solve_part1(&windows), solve_part1(EXAMPLE));
+        assert_eq!(solve_part2(&windows), solve_part2(EXAMPLE));
+    }
+}
@@ -0,0 +1,790 @@
+//! Day 10: Factory
+
+use crate::error::ParseError;
+use crate::input;
+use crate::solution::{Answer, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Factory";
+    type Input<'a> = (Vec<Machine>, Vec<JoltageMachine>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok((parse_machines(input)?, parse_joltage_machines(input)?))
+    }
+
+    fn part1((machines, _): &Self::Input<'_>) -> Answer {
+        machines.iter().map(min_presses).sum::<u64>().into()
+    }
+
+    fn part2((_, machines): &Self::Input<'_>) -> Answer {
+        machines
+            .iter()
+            .map(|m| min_presses_gauss(&m.buttons, &m.targets))
+            .sum::<u64>()
+            .into()
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<u64, ParseError> {
+    let input = input::normalize(input);
+    Ok(parse_machines(&input)?.iter().map(min_presses).sum())
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    pub target: u64,
+    pub buttons: Vec<u64>,
+}
+
+/// A machine as seen in part 2: buttons increment counters towards joltage targets.
+#[derive(Debug)]
+pub struct JoltageMachine {
+    pub buttons: Vec<Vec<usize>>,
+    pub targets: Vec<u64>,
+}
+
+pub fn parse_machines(input: &str) -> Result<Vec<Machine>, ParseError> {
+    parse_lines(input, parse_machine)
+}
+
+pub fn parse_joltage_machines(input: &str) -> Result<Vec<JoltageMachine>, ParseError> {
+    parse_lines(input, |line| {
+        Ok(JoltageMachine {
+            buttons: parse_buttons_indices(line)?,
+            targets: parse_joltage(line)?,
+        })
+    })
+}
+
+/// Parse every non-empty line, moving line-relative errors to their line in `input`.
+fn parse_lines<T>(
+    input: &str,
+    parse: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| parse(line).map_err(|error| error.on_line(i + 1)))
+        .collect()
+}
+
+pub fn parse_machine(line: &str) -> Result<Machine, ParseError> {
+    let target = parse_target(line)?;
+    let buttons = parse_buttons(line)?;
+    Ok(Machine { target, buttons })
+}
+
+pub fn parse_target(line: &str) -> Result<u64, ParseError> {
+    let pattern = delimited(line, '[', ']', "light diagram")?;
+    if pattern.len() > 64 {
+        return Err(ParseError::new(
+            Day10::DAY,
+            line,
+            pattern,
+            "at most 64 indicator lights",
+        ));
+    }
+
+    pattern
+        .char_indices()
+        .try_fold(0u64, |acc, (i, c)| match c {
+            '#' => Ok(acc | (1 << i)),
+            '.' => Ok(acc),
+            _ => Err(ParseError::new(
+                Day10::DAY,
+                line,
+                &pattern[i..i + c.len_utf8()],
+                "`.` or `#`",
+            )),
+        })
+}
+
+pub fn parse_buttons(line: &str) -> Result<Vec<u64>, ParseError> {
+    button_contents(line)?
+        .into_iter()
+        .map(|content| parse_button_in(line, content))
+        .collect()
+}
+
+pub fn parse_button(content: &str) -> Result<u64, ParseError> {
+    parse_button_in(content, content)
+}
+
+fn parse_button_in(line: &str, content: &str) -> Result<u64, ParseError> {
+    button_indices(line, content)?
+        .into_iter()
+        .try_fold(0u64, |acc, (i, fragment)| {
+            if i < 64 {
+                Ok(acc | (1 << i))
+            } else {
+                Err(ParseError::new(
+                    Day10::DAY,
+                    line,
+                    fragment,
+                    "light index below 64",
+                ))
+            }
+        })
+}
+
+/// The contents of every `(...)` button wiring group in `line`.
+fn button_contents(line: &str) -> Result<Vec<&str>, ParseError> {
+    let mut contents = Vec::new();
+    let mut pos = 0;
+
+    while let Some(open) = line[pos..].find('(') {
+        let start = pos + open + 1;
+        let end = line[start..].find(')').ok_or_else(|| {
+            ParseError::new(
+                Day10::DAY,
+                line,
+                &line[line.len()..],
+                "`)` closing the button",
+            )
+        })?;
+        contents.push(&line[start..start + end]);
+        pos = start + end + 1;
+    }
+
+    Ok(contents)
+}
+
+/// Comma-separated indices within `content`, each paired with its source text.
+fn button_indices<'a>(line: &str, content: &'a str) -> Result<Vec<(usize, &'a str)>, ParseError> {
+    content
+        .split(',')
+        .map(|s| {
+            let s = s.trim();
+            s.parse()
+                .map(|i| (i, s))
+                .map_err(|_| ParseError::new(Day10::DAY, line, s, "button index"))
+        })
+        .collect()
+}
+
+/// The text between the first `open` and the following `close` in `line`.
+fn delimited<'a>(
+    line: &'a str,
+    open: char,
+    close: char,
+    what: &str,
+) -> Result<&'a str, ParseError> {
+    let end_of_line = &line[line.len()..];
+    let start = line.find(open).ok_or_else(|| {
+        ParseError::new(
+            Day10::DAY,
+            line,
+            end_of_line,
+            format!("`{open}` opening the {what}"),
+        )
+    })? + 1;
+    let end = line[start..].find(close).ok_or_else(|| {
+        ParseError::new(
+            Day10::DAY,
+            line,
+            end_of_line,
+            format!("`{close}` closing the {what}"),
+        )
+    })?;
+    Ok(&line[start..start + end])
+}
+
+pub fn min_presses(machine: &Machine) -> u64 {
+    let num_buttons = machine.buttons.len();
+
+    // Try all 2^n subsets of buttons
+    (0u64..(1 << num_buttons))
+        .filter_map(|mask| {
+            let result = apply_buttons(mask, &machine.buttons);
+            if result == machine.target {
+                Some(mask.count_ones() as u64)
+            } else {
+                None
+            }
+        })
+        .min()
+        .unwrap_or(0)
+}
+
+pub fn apply_buttons(mask: u64, buttons: &[u64]) -> u64 {
+    buttons
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| (mask & (1 << i)) != 0)
+        .fold(0u64, |acc, (_, button)| acc ^ button)
+}
+
+// Part 2: Addition-based counter system using Gaussian elimination
+
+pub fn solve_part2(input: &str) -> Result<u64, ParseError> {
+    let input = input::normalize(input);
+    Ok(parse_joltage_machines(&input)?
+        .iter()
+        .map(|m| min_presses_gauss(&m.buttons, &m.targets))
+        .sum())
+}
+
+pub fn parse_buttons_indices(line: &str) -> Result<Vec<Vec<usize>>, ParseError> {
+    button_contents(line)?
+        .into_iter()
+        .map(|content| {
+            let indices = button_indices(line, content)?;
+            Ok(indices.into_iter().map(|(i, _)| i).collect())
+        })
+        .collect()
+}
+
+pub fn parse_joltage(line: &str) -> Result<Vec<u64>, ParseError> {
+    let content = delimited(line, '{', '}', "joltage requirements")?;
+
+    content
+        .split(',')
+        .map(|s| {
+            let s = s.trim();
+            s.parse()
+                .map_err(|_| ParseError::new(Day10::DAY, line, s, "joltage target"))
+        })
+        .collect()
+}
+
+/// Solve using Gaussian elimination over rationals
+/// Problem: Ax = b, minimize sum(x) where x >= 0 and x is integer
+pub fn min_presses_gauss(buttons: &[Vec<usize>], targets: &[u64]) -> u64 {
+    if targets.iter().all(|&t| t == 0) {
+        return 0;
+    }
+
+    let n_buttons = buttons.len();
+    let n_counters = targets.len();
+
+    // Build augmented matrix [A | b] using rationals (as f64 for simplicity)
+    // A[i][j] = 1 if button j affects counter i
+    let mut matrix: Vec<Vec<f64>> = vec![vec![0.0; n_buttons + 1]; n_counters];
+
+    for (j, button) in buttons.iter().enumerate() {
+        for &counter in button {
+            if counter < n_counters {
+                matrix[counter][j] = 1.0;
+            }
+        }
+    }
+
+    // Set target column
+    for (i, &target) in targets.iter().enumerate() {
+        matrix[i][n_buttons] = target as f64;
+    }
+
+    // Gaussian elimination to get RREF
+    let (rref, pivot_cols) = gaussian_elimination(&mut matrix, n_buttons);
+
+    // Identify free variables (columns without pivots)
+    let free_vars: Vec<usize> = (0..n_buttons)
+        .filter(|col| !pivot_cols.contains(col))
+        .collect();
+
+    // Upper bound for free variables is max target value
+    let max_target = *targets.iter().max().unwrap_or(&0) as i64;
+
+    // Brute force over free variables
+    let mut best = u64::MAX;
+    brute_force_free_vars(
+        &rref,
+        &pivot_cols,
+        &free_vars,
+        n_buttons,
+        max_target,
+        &mut best,
+    );
+
+    best
+}
+
+pub fn gaussian_elimination(matrix: &mut [Vec<f64>], n_cols: usize) -> (Vec<Vec<f64>>, Vec<usize>) {
+    let n_rows = matrix.len();
+    let mut pivot_cols = Vec::new();
+    let mut pivot_row = 0;
+
+    for col in 0..n_cols {
+        // Find pivot in this column (row with max absolute value)
+        let (max_row, max_val) = matrix
+            .iter()
+            .enumerate()
+            .skip(pivot_row)
+            .map(|(i, row)| (i, row[col].abs()))
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .unwrap_or((pivot_row, 0.0));
+
+        if max_val < 1e-10 {
+            continue; // No pivot in this column
+        }
+
+        // Swap rows
+        matrix.swap(pivot_row, max_row);
+
+        // Scale pivot row
+        let pivot_val = matrix[pivot_row][col];
+        matrix[pivot_row]
+            .iter_mut()
+            .take(n_cols + 1)
+            .for_each(|v| *v /= pivot_val);
+
+        // Eliminate other rows
+        for row in 0..n_rows {
+            if row != pivot_row && matrix[row][col].abs() > 1e-10 {
+                let factor = matrix[row][col];
+                let pivot_row_copy: Vec<f64> =
+                    matrix[pivot_row].iter().take(n_cols + 1).copied().collect();
+                matrix[row]
+                    .iter_mut()
+                    .zip(pivot_row_copy.iter())
+                    .for_each(|(v, &p)| *v -= factor * p);
+            }
+        }
+
+        pivot_cols.push(col);
+        pivot_row += 1;
+
+        if pivot_row >= n_rows {
+            break;
+        }
+    }
+
+    (matrix.to_vec(), pivot_cols)
+}
+
+pub fn brute_force_free_vars(
+    rref: &[Vec<f64>],
+    pivot_cols: &[usize],
+    free_vars: &[usize],
+    n_buttons: usize,
+    max_val: i64,
+    best: &mut u64,
+) {
+    let n_free = free_vars.len();
+    let total_combinations = (max_val + 1).pow(n_free as u32);
+
+    for combo in 0..total_combinations {
+        let free_vals: Vec<i64> = (0..n_free)
+            .scan(combo, |temp, _| {
+                let val = *temp % (max_val + 1);
+                *temp /= max_val + 1;
+                Some(val)
+            })
+            .collect();
+
+        // Compute all button values from RREF
+        if let Some(solution) = compute_solution(rref, pivot_cols, free_vars, &free_vals, n_buttons)
+        {
+            let total: u64 = solution.iter().sum();
+            if total < *best {
+                *best = total;
+            }
+        }
+    }
+}
+
+pub fn compute_solution(
+    rref: &[Vec<f64>],
+    pivot_cols: &[usize],
+    free_vars: &[usize],
+    free_vals: &[i64],
+    n_buttons: usize,
+) -> Option<Vec<u64>> {
+    let mut solution = vec![0.0; n_buttons];
+
+    // Set free variables
+    for (i, &col) in free_vars.iter().enumerate() {
+        solution[col] = free_vals[i] as f64;
+    }
+
+    // Back-substitute to find pivot variables
+    for (row_idx, &pivot_col) in pivot_cols.iter().enumerate() {
+        if row_idx >= rref.len() {
+            break;
+        }
+
+        let mut val = rref[row_idx][n_buttons]; // RHS
+
+        // Subtract contributions from free variables
+        for &free_col in free_vars {
+            val -= rref[row_idx][free_col] * solution[free_col];
+        }
+
+        // Subtract contributions from other pivot variables (already computed)
+        for (other_row, &other_pivot) in pivot_cols.iter().enumerate() {
+            if other_row > row_idx && other_pivot < n_buttons {
+                val -= rref[row_idx][other_pivot] * solution[other_pivot];
+            }
+        }
+
+        solution[pivot_col] = val;
+    }
+
+    // Check if solution is valid (non-negative integers)
+    let mut result = Vec::with_capacity(n_buttons);
+    for &val in &solution {
+        let rounded = val.round();
+        if (val - rounded).abs() > 1e-6 || rounded < 0.0 {
+            return None;
+        }
+        result.push(rounded as u64);
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prop;
+    use crate::registry;
+    use crate::rng::Rng;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_part1(EXAMPLE), Ok(7));
+    }
+
+    #[test]
+    fn test_parse_target_simple() {
+        assert_eq!(parse_target("[.##.] (1) {1}"), Ok(0b0110));
+    }
+
+    #[test]
+    fn test_parse_target_first_on() {
+        assert_eq!(parse_target("[#...] (1) {1}"), Ok(0b0001));
+    }
+
+    #[test]
+    fn test_parse_target_all_on() {
+        assert_eq!(parse_target("[####] (1) {1}"), Ok(0b1111));
+    }
+
+    #[test]
+    fn test_parse_target_all_off() {
+        assert_eq!(parse_target("[....] (1) {1}"), Ok(0));
+    }
+
+    #[test]
+    fn test_parse_target_mixed() {
+        assert_eq!(parse_target("[...#.] (1) {1}"), Ok(0b01000));
+    }
+
+    #[test]
+    fn test_parse_target_six_lights() {
+        assert_eq!(parse_target("[.###.#] (1) {1}"), Ok(0b101110));
+    }
+
+    #[test]
+    fn test_parse_buttons_single_index() {
+        assert_eq!(parse_buttons("[.] (3) {1}"), Ok(vec![0b1000]));
+    }
+
+    #[test]
+    fn test_parse_buttons_multiple_indices() {
+        assert_eq!(parse_buttons("[.] (1,3) {1}"), Ok(vec![0b1010]));
+    }
+
+    #[test]
+    fn test_parse_buttons_multiple_buttons() {
+        assert_eq!(
+            parse_buttons("[.] (3) (1,3) (2) {1}"),
+            Ok(vec![0b1000, 0b1010, 0b0100])
+        );
+    }
+
+    #[test]
+    fn test_parse_button_content() {
+        assert_eq!(parse_button("0,2"), Ok(0b0101));
+        assert_eq!(parse_button("1,3,4"), Ok(0b11010));
+        assert_eq!(parse_button("0"), Ok(0b1));
+    }
+
+    #[test]
+    fn test_apply_buttons_none() {
+        let buttons = vec![0b0001, 0b0010, 0b0100];
+        assert_eq!(apply_buttons(0b000, &buttons), 0);
+    }
+
+    #[test]
+    fn test_apply_buttons_single() {
+        let buttons = vec![0b0001, 0b0010, 0b0100];
+        assert_eq!(apply_buttons(0b001, &buttons), 0b0001);
+        assert_eq!(apply_buttons(0b010, &buttons), 0b0010);
+        assert_eq!(apply_buttons(0b100, &buttons), 0b0100);
+    }
+
+    #[test]
+    fn test_apply_buttons_multiple_xor() {
+        let buttons = vec![0b0011, 0b0110];
+        assert_eq!(apply_buttons(0b11, &buttons), 0b0101);
+    }
+
+    #[test]
+    fn test_min_presses_example1() {
+        let machine = parse_machine("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}").unwrap();
+        assert_eq!(min_presses(&machine), 2);
+    }
+
+    #[test]
+    fn test_min_presses_example2() {
+        let machine =
+            parse_machine("[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}").unwrap();
+        assert_eq!(min_presses(&machine), 3);
+    }
+
+    #[test]
+    fn test_min_presses_example3() {
+        let machine =
+            parse_machine("[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}")
+                .unwrap();
+        assert_eq!(min_presses(&machine), 2);
+    }
+
+    #[test]
+    fn test_min_presses_single_button() {
+        let machine = Machine {
+            target: 0b0110,
+            buttons: vec![0b0110],
+        };
+        assert_eq!(min_presses(&machine), 1);
+    }
+
+    #[test]
+    fn test_min_presses_all_off_target() {
+        let machine = Machine {
+            target: 0,
+            buttons: vec![0b0001, 0b0010],
+        };
+        assert_eq!(min_presses(&machine), 0);
+    }
+
+    #[test]
+    fn test_parse_machine_complete() {
+        let machine = parse_machine("[.##.] (3) (1,3) {5}").unwrap();
+        assert_eq!(machine.target, 0b0110);
+        assert_eq!(machine.buttons, vec![0b1000, 0b1010]);
+    }
+
+    #[test]
+    fn test_solve_empty() {
+        assert_eq!(solve_part1(""), Ok(0));
+    }
+
+    #[test]
+    fn test_solve_single_machine() {
+        assert_eq!(
+            solve_part1("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"),
+            Ok(2)
+        );
+    }
+
+    // Part 2 tests
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(solve_part2(EXAMPLE), Ok(33));
+    }
+
+    #[test]
+    fn test_parse_joltage() {
+        assert_eq!(parse_joltage("[.] (1) {3,5,4,7}"), Ok(vec![3, 5, 4, 7]));
+        assert_eq!(
+            parse_joltage("[.] (1) {7,5,12,7,2}"),
+            Ok(vec![7, 5, 12, 7, 2])
+        );
+    }
+
+    #[test]
+    fn test_parse_buttons_indices() {
+        let buttons = parse_buttons_indices("[.] (3) (1,3) (0,2) {1}").unwrap();
+        assert_eq!(buttons, vec![vec![3], vec![1, 3], vec![0, 2]]);
+    }
+
+    #[test]
+    fn test_min_presses_part2_example1() {
+        let buttons =
+            parse_buttons_indices("[.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}").unwrap();
+        let targets = parse_joltage("[.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}").unwrap();
+        assert_eq!(min_presses_gauss(&buttons, &targets), 10);
+    }
+
+    #[test]
+    fn test_min_presses_part2_example2() {
+        let line = "[.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}";
+        let buttons = parse_buttons_indices(line).unwrap();
+        let targets = parse_joltage(line).unwrap();
+        assert_eq!(min_presses_gauss(&buttons, &targets), 12);
+    }
+
+    #[test]
+    fn test_min_presses_part2_example3() {
+        let line = "[.] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+        let buttons = parse_buttons_indices(line).unwrap();
+        let targets = parse_joltage(line).unwrap();
+        assert_eq!(min_presses_gauss(&buttons, &targets), 11);
+    }
+
+    #[test]
+    fn test_min_presses_part2_single_button() {
+        let buttons = vec![vec![0, 1]];
+        let targets = vec![5, 5];
+        assert_eq!(min_presses_gauss(&buttons, &targets), 5);
+    }
+
+    #[test]
+    fn test_min_presses_part2_all_zeros() {
+        let buttons = vec![vec![0], vec![1]];
+        let targets = vec![0, 0];
+        assert_eq!(min_presses_gauss(&buttons, &targets), 0);
+    }
+
+    #[test]
+    fn test_solve_part2_empty() {
+        assert_eq!(solve_part2(""), Ok(0));
+    }
+
+    #[test]
+    fn test_solve_part2_single_machine() {
+        assert_eq!(
+            solve_part2("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"),
+            Ok(10)
+        );
+    }
+
+    #[test]
+    fn test_parse_target_missing_brackets() {
+        let error = parse_target("(0) {1}").unwrap_err();
+        assert_eq!((error.day, error.column), (10, 8));
+        assert_eq!(error.expected, "`[` opening the light diagram");
+
+        let error = parse_target("[.# (0) {1}").unwrap_err();
+        assert_eq!(error.expected, "`]` closing the light diagram");
+    }
+
+    #[test]
+    fn test_parse_target_invalid_light() {
+        let error = parse_target("[.#x] (0) {1}").unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (4, "x"));
+        assert_eq!(error.expected, "`.` or `#`");
+    }
+
+    #[test]
+    fn test_parse_target_too_many_lights() {
+        let line = format!("[{}] (0) {{1}}", ".".repeat(65));
+        assert_eq!(
+            parse_target(&line).unwrap_err().expected,
+            "at most 64 indicator lights"
+        );
+    }
+
+    #[test]
+    fn test_parse_buttons_invalid_index() {
+        let error = parse_buttons("[.#] (0,a) {1}").unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (9, "a"));
+        assert_eq!(error.expected, "button index");
+    }
+
+    #[test]
+    fn test_parse_buttons_unclosed() {
+        let error = parse_buttons("[.#] (0,1 {1}").unwrap_err();
+        assert_eq!(error.expected, "`)` closing the button");
+    }
+
+    #[test]
+    fn test_parse_button_index_too_large() {
+        let error = parse_button("1,64").unwrap_err();
+        assert_eq!(
+            (error.column, error.expected.as_str()),
+            (3, "light index below 64")
+        );
+    }
+
+    #[test]
+    fn test_parse_joltage_invalid() {
+        let error = parse_joltage("[.#] (0) {1,-2}").unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (13, "-2"));
+        assert!(parse_joltage("[.#] (0)").is_err());
+    }
+
+    #[test]
+    fn test_parse_machines_reports_input_line() {
+        let input = "[.#] (1) {1}\n\n[#.] (q) {1}";
+        let error = parse_machines(input).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 7));
+        assert_eq!(error.line_text, "[#.] (q) {1}");
+        assert_eq!(parse_joltage_machines(input).unwrap_err().line, 3);
+    }
+
+    /// Smallest total over every press count up to the largest target.
+    fn naive_min_presses(buttons: &[Vec<usize>], targets: &[u64]) -> u64 {
+        let limit = targets.iter().copied().max().unwrap_or(0);
+        let mut presses = vec![0u64; buttons.len()];
+        let mut best = u64::MAX;
+        loop {
+            let mut counters = vec![0u64; targets.len()];
+            for (button, &count) in buttons.iter().zip(&presses) {
+                for &counter in button {
+                    counters[counter] += count;
+                }
+            }
+            if counters == targets {
+                best = best.min(presses.iter().sum());
+            }
+            // Next combination, odometer style.
+            let Some(i) = presses.iter().position(|&count| count < limit) else {
+                return best;
+            };
+            presses[i] += 1;
+            presses[..i].fill(0);
+        }
+    }
+
+    /// Up to 4 buttons over up to 3 counters, with targets some press counts reach.
+    fn random_machine(rng: &mut Rng) -> (Vec<Vec<usize>>, Vec<u64>) {
+        let counters = rng.range(1..=3) as usize;
+        let buttons: Vec<Vec<usize>> = (0..rng.range(1..=4))
+            .map(|_| (0..counters).filter(|_| rng.bool()).collect())
+            .collect();
+        let mut targets = vec![0u64; counters];
+        for button in &buttons {
+            let count = rng.below(4);
+            for &counter in button {
+                targets[counter] += count;
+            }
+        }
+        (buttons, targets)
+    }
+
+    #[test]
+    fn test_min_presses_gauss_matches_naive() {
+        prop::check(
+            "day10 min_presses_gauss",
+            random_machine,
+            |(buttons, targets)| {
+                prop::agree(
+                    min_presses_gauss(buttons, targets),
+                    naive_min_presses(buttons, targets),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        registry::assert_windows_agnostic(Day10::DAY, EXAMPLE);
+        let windows = input::windows(EXAMPLE);
+        assert_eq!(solve_part1(&windows), solve_part1(EXAMPLE));
+        assert_eq!(solve_part2(&windows), solve_part2(EXAMPLE));
+    }
+}
@@ -0,0 +1,457 @@
+//! Day 11: Reactor
+
+use std::collections::HashMap;
+
+use crate::error::ParseError;
+use crate::input;
+use crate::params::{Param, Params};
+use crate::solution::{Answer, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Reactor";
+    type Input<'a> = HashMap<&'a str, Vec<&'a str>>;
+    const PARAMS: &'static [Param] = &[
+        Param::text("from", "you", "device part 1 counts paths from"),
+        Param::text("part2_from", "svr", "device part 2 counts paths from"),
+        Param::text("to", "out", "device every path ends at"),
+        Param::text(
+            "via",
+            "dac fft",
+            "space-separated devices every part 2 path visits",
+        ),
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_graph(input)
+    }
+
+    fn part1(graph: &Self::Input<'_>) -> Answer {
+        Self::part1_with(graph, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part2(graph: &Self::Input<'_>) -> Answer {
+        Self::part2_with(graph, &Params::defaults(Self::PARAMS))
+    }
+
+    fn part1_with(graph: &Self::Input<'_>, params: &Params) -> Answer {
+        let (from, to) = (params.text("from"), params.text("to"));
+        count_paths_via(graph, from, to, &[], 0, &mut HashMap::new()).into()
+    }
+
+    fn part2_with(graph: &Self::Input<'_>, params: &Params) -> Answer {
+        let (from, to) = (params.text("part2_from"), params.text("to"));
+        let via: Vec<&str> = params.text("via").split_whitespace().collect();
+        count_paths_via(graph, from, to, &via, 0, &mut HashMap::new()).into()
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<u64, ParseError> {
+    let input = input::normalize(input);
+    let graph = parse_graph(&input)?;
+    Ok(count_paths(&graph, "you", &mut HashMap::new()))
+}
+
+pub fn solve_part2(input: &str) -> Result<u64, ParseError> {
+    let input = input::normalize(input);
+    let graph = parse_graph(&input)?;
+    Ok(count_paths_constrained(
+        &graph,
+        "svr",
+        false,
+        false,
+        &mut HashMap::new(),
+    ))
+}
+
+/// Memo entry for a node whose paths are still being counted; meeting it again means the
+/// graph has a cycle, which would otherwise recurse until the stack overflows.
+const IN_PROGRESS: u64 = u64::MAX;
+
+pub fn count_paths_constrained<'a>(
+    graph: &HashMap<&'a str, Vec<&'a str>>,
+    node: &'a str,
+    has_dac: bool,
+    has_fft: bool,
+    memo: &mut HashMap<(&'a str, bool, bool), u64>,
+) -> u64 {
+    let has_dac = has_dac || node == "dac";
+    let has_fft = has_fft || node == "fft";
+
+    if node == "out" {
+        return if has_dac && has_fft { 1 } else { 0 };
+    }
+
+    let state = (node, has_dac, has_fft);
+    match memo.get(&state) {
+        Some(&IN_PROGRESS) => panic!("device graph has a cycle through `{node}`"),
+        Some(&count) => return count,
+        None => memo.insert(state, IN_PROGRESS),
+    };
+
+    let count = graph
+        .get(node)
+        .map(|children| {
+            children
+                .iter()
+                .map(|child| count_paths_constrained(graph, child, has_dac, has_fft, memo))
+                .sum()
+        })
+        .unwrap_or(0);
+
+    memo.insert(state, count);
+    count
+}
+
+/// Count paths from `node` to `to` that visit every device in `via`, at most 64 of them.
+/// `seen` has bit `i` set once `via[i]` has been visited.
+pub fn count_paths_via<'a>(
+    graph: &HashMap<&'a str, Vec<&'a str>>,
+    node: &'a str,
+    to: &str,
+    via: &[&str],
+    seen: u64,
+    memo: &mut HashMap<(&'a str, u64), u64>,
+) -> u64 {
+    assert!(via.len() <= 64, "at most 64 devices can be required");
+    let seen = via
+        .iter()
+        .enumerate()
+        .filter(|&(_, &device)| device == node)
+        .fold(seen, |seen, (i, _)| seen | 1 << i);
+
+    if node == to {
+        let all = 1u64
+            .checked_shl(via.len() as u32)
+            .map_or(u64::MAX, |bit| bit - 1);
+        return u64::from(seen == all);
+    }
+
+    let state = (node, seen);
+    match memo.get(&state) {
+        Some(&IN_PROGRESS) => panic!("device graph has a cycle through `{node}`"),
+        Some(&count) => return count,
+        None => memo.insert(state, IN_PROGRESS),
+    };
+
+    let count = graph
+        .get(node)
+        .map(|children| {
+            children
+                .iter()
+                .map(|child| count_paths_via(graph, child, to, via, seen, memo))
+                .sum()
+        })
+        .unwrap_or(0);
+
+    memo.insert(state, count);
+    count
+}
+
+pub fn parse_graph(input: &str) -> Result<HashMap<&str, Vec<&str>>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (node, children) = line.split_once(':').ok_or_else(|| {
+                ParseError::new(Day11::DAY, input, line, "`device: outputs` line")
+            })?;
+            let node = node.trim();
+            if node.is_empty() {
+                return Err(ParseError::new(Day11::DAY, input, node, "device name"));
+            }
+            Ok((node, children.split_whitespace().collect()))
+        })
+        .collect()
+}
+
+pub fn count_paths<'a>(
+    graph: &HashMap<&'a str, Vec<&'a str>>,
+    node: &'a str,
+    memo: &mut HashMap<&'a str, u64>,
+) -> u64 {
+    if node == "out" {
+        return 1;
+    }
+
+    match memo.get(node) {
+        Some(&IN_PROGRESS) => panic!("device graph has a cycle through `{node}`"),
+        Some(&count) => return count,
+        None => memo.insert(node, IN_PROGRESS),
+    };
+
+    let count = graph
+        .get(node)
+        .map(|children| {
+            children
+                .iter()
+                .map(|child| count_paths(graph, child, memo))
+                .sum()
+        })
+        .unwrap_or(0);
+
+    memo.insert(node, count);
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+
+    use super::*;
+
+    const EXAMPLE: &str = "\
+aaa: you hhh
+you: bbb ccc
+bbb: ddd eee
+ccc: ddd eee fff
+ddd: ggg
+eee: out
+fff: out
+ggg: out
+hhh: ccc fff iii
+iii: out";
+
+    #[test]
+    fn test_part1_example() {
+        assert_eq!(solve_part1(EXAMPLE), Ok(5));
+    }
+
+    #[test]
+    fn test_parse_graph() {
+        let graph = parse_graph("a: b c\nd: e").unwrap();
+        assert_eq!(graph.get("a"), Some(&vec!["b", "c"]));
+        assert_eq!(graph.get("d"), Some(&vec!["e"]));
+    }
+
+    #[test]
+    fn test_parse_graph_empty() {
+        let graph = parse_graph("").unwrap();
+        assert!(graph.is_empty());
+    }
+
+    #[test]
+    fn test_parse_graph_with_empty_lines() {
+        let graph = parse_graph("a: b\n\nc: d").unwrap();
+        assert_eq!(graph.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_graph_without_outputs() {
+        let graph = parse_graph("a:\nb: c").unwrap();
+        assert_eq!(graph.get("a"), Some(&vec![]));
+    }
+
+    #[test]
+    fn test_parse_graph_missing_colon() {
+        let error = parse_graph("a: b\nc d").unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (11, 2, 1));
+        assert_eq!(error.found, "c d");
+        assert_eq!(error.expected, "`device: outputs` line");
+    }
+
+    #[test]
+    fn test_parse_graph_missing_name() {
+        let error = parse_graph("a: b\n: c").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.expected, "device name");
+    }
+
+    #[test]
+    fn test_count_paths_direct() {
+        let graph = parse_graph("you: out").unwrap();
+        assert_eq!(count_paths(&graph, "you", &mut HashMap::new()), 1);
+    }
+
+    #[test]
+    fn test_count_paths_two_paths() {
+        let graph = parse_graph("you: a b\na: out\nb: out").unwrap();
+        assert_eq!(count_paths(&graph, "you", &mut HashMap::new()), 2);
+    }
+
+    #[test]
+    fn test_count_paths_no_path() {
+        let graph = parse_graph("you: a\na: b").unwrap();
+        assert_eq!(count_paths(&graph, "you", &mut HashMap::new()), 0);
+    }
+
+    #[test]
+    fn test_count_paths_shared_node() {
+        // you -> a -> c -> out
+        // you -> b -> c -> out
+        let graph = parse_graph("you: a b\na: c\nb: c\nc: out").unwrap();
+        assert_eq!(count_paths(&graph, "you", &mut HashMap::new()), 2);
+    }
+
+    #[test]
+    fn test_count_paths_at_out() {
+        let graph = parse_graph("").unwrap();
+        assert_eq!(count_paths(&graph, "out", &mut HashMap::new()), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "device graph has a cycle through `bbb`")]
+    fn test_count_paths_cycle() {
+        let graph = parse_graph("you: bbb\nbbb: ccc out\nccc: bbb").unwrap();
+        count_paths(&graph, "you", &mut HashMap::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "device graph has a cycle through `dac`")]
+    fn test_count_paths_constrained_cycle() {
+        let graph = parse_graph("svr: fft\nfft: dac\ndac: fft out").unwrap();
+        count_paths_constrained(&graph, "svr", false, false, &mut HashMap::new());
+    }
+
+    #[test]
+    fn test_count_paths_unknown_node() {
+        let graph = parse_graph("a: b").unwrap();
+        assert_eq!(count_paths(&graph, "unknown", &mut HashMap::new()), 0);
+    }
+
+    #[test]
+    fn test_solve_empty() {
+        assert_eq!(solve_part1(""), Ok(0));
+    }
+
+    // Part 2 tests
+
+    const EXAMPLE_PART2: &str = "\
+svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out";
+
+    #[test]
+    fn test_part2_example() {
+        assert_eq!(solve_part2(EXAMPLE_PART2), Ok(2));
+    }
+
+    #[test]
+    fn test_part2_empty() {
+        assert_eq!(solve_part2(""), Ok(0));
+    }
+
+    #[test]
+    fn test_part2_direct_with_both() {
+        // svr -> dac -> fft -> out
+        let input = "svr: dac\ndac: fft\nfft: out";
+        assert_eq!(solve_part2(input), Ok(1));
+    }
+
+    #[test]
+    fn test_part2_direct_missing_dac() {
+        // svr -> fft -> out (no dac)
+        let input = "svr: fft\nfft: out";
+        assert_eq!(solve_part2(input), Ok(0));
+    }
+
+    #[test]
+    fn test_part2_direct_missing_fft() {
+        // svr -> dac -> out (no fft)
+        let input = "svr: dac\ndac: out";
+        assert_eq!(solve_part2(input), Ok(0));
+    }
+
+    #[test]
+    fn test_part2_two_paths_one_valid() {
+        // Path 1: svr -> a -> out (invalid - missing both)
+        // Path 2: svr -> dac -> fft -> out (valid)
+        let input = "svr: a dac\na: out\ndac: fft\nfft: out";
+        assert_eq!(solve_part2(input), Ok(1));
+    }
+
+    #[test]
+    fn test_part2_fft_before_dac() {
+        // svr -> fft -> dac -> out
+        let input = "svr: fft\nfft: dac\ndac: out";
+        assert_eq!(solve_part2(input), Ok(1));
+    }
+
+    #[test]
+    fn test_count_paths_constrained_at_out_with_both() {
+        let graph = parse_graph("").unwrap();
+        assert_eq!(
+            count_paths_constrained(&graph, "out", true, true, &mut HashMap::new()),
+            1
+        );
+    }
+
+    #[test]
+    fn test_count_paths_constrained_at_out_missing_one() {
+        let graph = parse_graph("").unwrap();
+        assert_eq!(
+            count_paths_constrained(&graph, "out", true, false, &mut HashMap::new()),
+            0
+        );
+        assert_eq!(
+            count_paths_constrained(&graph, "out", false, true, &mut HashMap::new()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_count_paths_constrained_unknown_node() {
+        let graph = parse_graph("a: b").unwrap();
+        assert_eq!(
+            count_paths_constrained(&graph, "unknown", true, true, &mut HashMap::new()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_node_params() {
+        let graph = parse_graph(EXAMPLE_PART2).unwrap();
+        let params = |pairs: &[(&str, &str)]| {
+            let pairs: Vec<_> = pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            Params::resolve(Day11::PARAMS, &pairs).unwrap()
+        };
+        assert_eq!(Day11::part2(&graph), Answer::from(2u64));
+        assert_eq!(
+            Day11::part2_with(&graph, &params(&[("via", "fft")])),
+            Answer::from(4u64)
+        );
+        assert_eq!(
+            Day11::part2_with(&graph, &params(&[("via", "dac")])),
+            Answer::from(4u64)
+        );
+        assert_eq!(
+            Day11::part1_with(&graph, &params(&[("from", "ccc"), ("to", "fff")])),
+            Answer::from(2u64)
+        );
+        assert_eq!(
+            Day11::part2_with(&graph, &params(&[("part2_from", "ccc"), ("via", "hub")])),
+            Answer::from(2u64)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "device graph has a cycle through `b`")]
+    fn test_count_paths_via_cycle() {
+        let graph = parse_graph("a: b\nb: a out").unwrap();
+        count_paths_via(&graph, "a", "out", &["b"], 0, &mut HashMap::new());
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        registry::assert_windows_agnostic(Day11::DAY, EXAMPLE);
+        let windows = input::windows(EXAMPLE);
+        assert_eq!(solve_part1(&windows), solve_part1(EXAMPLE));
+        assert_eq!(solve_part2(&windows), solve_part2(EXAMPLE));
+        registry::assert_windows_agnostic(Day11::DAY, EXAMPLE_PART2);
+    }
+}
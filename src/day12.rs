@@ -0,0 +1,350 @@
+//! Day 12: Christmas Tree Farm
+
+use crate::error::ParseError;
+use crate::input;
+use crate::solution::{Answer, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Christmas Tree Farm";
+    type Input<'a> = (Vec<usize>, Vec<Region>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((shape_sizes, regions): &Self::Input<'_>) -> Answer {
+        count_fitting_regions(shape_sizes, regions).into()
+    }
+}
+
+pub fn solve_part1(input: &str) -> Result<usize, ParseError> {
+    let input = input::normalize(input);
+    let (shape_sizes, regions) = parse_input(&input)?;
+    Ok(count_fitting_regions(&shape_sizes, &regions))
+}
+
+/// Count regions with strictly more area than their presents occupy.
+pub fn count_fitting_regions(shape_sizes: &[usize], regions: &[Region]) -> usize {
+    regions
+        .iter()
+        .filter(|region| {
+            let total_cells: usize = region
+                .counts
+                .iter()
+                .enumerate()
+                .map(|(i, &count)| count * shape_sizes.get(i).unwrap_or(&0))
+                .sum();
+            let area = (region.width * region.height) as usize;
+            total_cells < area
+        })
+        .count()
+}
+
+#[derive(Debug)]
+pub struct Region {
+    pub width: i32,
+    pub height: i32,
+    pub counts: Vec<usize>,
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<usize>, Vec<Region>), ParseError> {
+    let parts: Vec<&str> = input.split("\n\n").collect();
+
+    let mut shape_sizes = Vec::new();
+    let mut regions = Vec::new();
+
+    for part in parts {
+        let trimmed = part.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if let Some(first_line) = trimmed.lines().next() {
+            if first_line.ends_with(':')
+                && first_line[..first_line.len() - 1].parse::<usize>().is_ok()
+            {
+                let parsed = parse_shapes_in(input, part)?;
+                for (i, size) in parsed.into_iter().enumerate() {
+                    while shape_sizes.len() <= i {
+                        shape_sizes.push(0);
+                    }
+                    if size > 0 {
+                        shape_sizes[i] = size;
+                    }
+                }
+            } else if first_line.contains('x') && first_line.contains(':') {
+                regions.extend(parse_regions_in(input, part)?);
+            } else {
+                return Err(ParseError::new(
+                    Day12::DAY,
+                    input,
+                    first_line,
+                    "shape header `N:` or region `WxH: counts`",
+                ));
+            }
+        }
+    }
+
+    Ok((shape_sizes, regions))
+}
+
+pub fn parse_shapes(input: &str) -> Result<Vec<usize>, ParseError> {
+    parse_shapes_in(input, input)
+}
+
+/// Parse the shapes in `block`, locating errors within the enclosing `source`.
+fn parse_shapes_in(source: &str, block: &str) -> Result<Vec<usize>, ParseError> {
+    let mut shapes = Vec::new();
+    let mut current_shape: Option<(usize, usize)> = None;
+
+    for line in block.lines() {
+        if let Some(idx_str) = line.strip_suffix(':') {
+            if let Some((idx, count)) = current_shape.take() {
+                while shapes.len() <= idx {
+                    shapes.push(0);
+                }
+                shapes[idx] = count;
+            }
+            // A region refers to shape `idx` with `idx + 1` counts, which the input would have
+            // to hold; larger indices are errors rather than huge allocations.
+            let idx = idx_str
+                .parse::<usize>()
+                .ok()
+                .filter(|&idx| idx < source.len())
+                .ok_or_else(|| ParseError::new(Day12::DAY, source, idx_str, "shape index"))?;
+            current_shape = Some((idx, 0));
+        } else if !line.is_empty() {
+            let Some((_, ref mut count)) = current_shape else {
+                return Err(ParseError::new(
+                    Day12::DAY,
+                    source,
+                    line,
+                    "shape header `N:`",
+                ));
+            };
+            if let Some(i) = line.find(|c| c != '#' && c != '.') {
+                let c = &line[i..i + line[i..].chars().next().map_or(1, char::len_utf8)];
+                return Err(ParseError::new(Day12::DAY, source, c, "`#` or `.`"));
+            }
+            *count += line.chars().filter(|&c| c == '#').count();
+        }
+    }
+
+    if let Some((idx, count)) = current_shape {
+        while shapes.len() <= idx {
+            shapes.push(0);
+        }
+        shapes[idx] = count;
+    }
+
+    Ok(shapes)
+}
+
+pub fn parse_regions(input: &str) -> Result<Vec<Region>, ParseError> {
+    parse_regions_in(input, input)
+}
+
+/// Parse the regions in `block`, locating errors within the enclosing `source`.
+fn parse_regions_in(source: &str, block: &str) -> Result<Vec<Region>, ParseError> {
+    block
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| parse_region_in(source, line))
+        .collect()
+}
+
+pub fn parse_region_line(line: &str) -> Result<Region, ParseError> {
+    parse_region_in(line, line)
+}
+
+fn parse_region_in(source: &str, line: &str) -> Result<Region, ParseError> {
+    let line = line.trim();
+    let error = |fragment, expected| ParseError::new(Day12::DAY, source, fragment, expected);
+
+    let (size, counts) = line
+        .split_once(':')
+        .ok_or_else(|| error(line, "region `WxH: counts`"))?;
+
+    let size = size.trim();
+    let (width, height) = size
+        .split_once('x')
+        .ok_or_else(|| error(size, "region size `WxH`"))?;
+
+    let width = width.parse().map_err(|_| error(width, "region width"))?;
+    let height = height.parse().map_err(|_| error(height, "region height"))?;
+
+    let counts = counts
+        .split_whitespace()
+        .map(|s| s.parse().map_err(|_| error(s, "present count")))
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    Ok(Region {
+        width,
+        height,
+        counts,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_shapes() {
+        let input = "0:\n###\n##.\n##.";
+        let shapes = parse_shapes(input).unwrap();
+        assert_eq!(shapes[0], 7);
+    }
+
+    #[test]
+    fn test_parse_shapes_multiple() {
+        let input = "0:\n###\n##.\n##.\n\n1:\n###\n##.\n.##";
+        let shapes = parse_shapes(input).unwrap();
+        assert_eq!(shapes.len(), 2);
+        assert_eq!(shapes[0], 7);
+        assert_eq!(shapes[1], 7);
+    }
+
+    #[test]
+    fn test_parse_region_line() {
+        let region = parse_region_line("4x4: 0 0 0 0 2 0").unwrap();
+        assert_eq!(region.width, 4);
+        assert_eq!(region.height, 4);
+        assert_eq!(region.counts, vec![0, 0, 0, 0, 2, 0]);
+    }
+
+    #[test]
+    fn test_parse_region_line_larger() {
+        let region = parse_region_line("12x5: 1 0 1 0 2 2").unwrap();
+        assert_eq!(region.width, 12);
+        assert_eq!(region.height, 5);
+        assert_eq!(region.counts, vec![1, 0, 1, 0, 2, 2]);
+    }
+
+    #[test]
+    fn test_parse_region_line_empty() {
+        assert!(parse_region_line("").is_err());
+    }
+
+    #[test]
+    fn test_parse_region_line_invalid() {
+        assert!(parse_region_line("invalid").is_err());
+        assert!(parse_region_line("4x4").is_err());
+    }
+
+    #[test]
+    fn test_parse_region_line_bad_size() {
+        let error = parse_region_line("4y4: 1").unwrap_err();
+        assert_eq!(error.expected, "region size `WxH`");
+        let error = parse_region_line("4xZ: 1").unwrap_err();
+        assert_eq!(
+            (error.column, error.expected.as_str()),
+            (3, "region height")
+        );
+    }
+
+    #[test]
+    fn test_parse_region_line_bad_count() {
+        let error = parse_region_line("4x4: 1 two").unwrap_err();
+        assert_eq!((error.day, error.column), (12, 8));
+        assert_eq!(error.found, "two");
+    }
+
+    #[test]
+    fn test_parse_shapes_invalid_cell() {
+        let error = parse_shapes("0:\n##\n#o").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 2));
+        assert_eq!(error.expected, "`#` or `.`");
+    }
+
+    #[test]
+    fn test_parse_shapes_row_without_header() {
+        let error = parse_shapes("##\n0:").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.expected, "shape header `N:`");
+    }
+
+    #[test]
+    fn test_parse_shapes_invalid_index() {
+        let error = parse_shapes("0:\n#\na:\n#").unwrap_err();
+        assert_eq!((error.line, error.found.as_str()), (3, "a"));
+    }
+
+    #[test]
+    fn test_parse_shapes_index_too_large() {
+        let error = parse_input("0:\n#\n\n0999999999999999:\n##.").unwrap_err();
+        assert_eq!((error.line, error.found.as_str()), (4, "0999999999999999"));
+        assert_eq!(error.expected, "shape index");
+        assert_eq!(parse_shapes("3:\n#"), Ok(vec![0, 0, 0, 1]));
+        assert!(parse_shapes("4:\n#").is_err());
+    }
+
+    #[test]
+    fn test_parse_input_unknown_block() {
+        let error = parse_input("0:\n#\n\nhello\n\n1x1: 0").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(error.found, "hello");
+    }
+
+    #[test]
+    fn test_parse_input_error_located_in_full_input() {
+        let error = parse_input("0:\n#\n\n2x2: 1\n3x3: x").unwrap_err();
+        assert_eq!((error.line, error.column), (5, 6));
+    }
+
+    #[test]
+    fn test_solve_empty() {
+        assert_eq!(solve_part1(""), Ok(0));
+    }
+
+    #[test]
+    fn test_area_check_fits() {
+        // 4x4 = 16 cells, need 2*7 = 14 cells, 14 < 16 = true
+        let input = "\
+4:
+###
+#..
+###
+
+4x4: 0 0 0 0 2 0";
+        assert_eq!(solve_part1(input), Ok(1));
+    }
+
+    #[test]
+    fn test_area_check_exact() {
+        // 4x4 = 16 cells, need 16 cells exactly, 16 < 16 = false
+        let input = "\
+0:
+####
+####
+
+4x4: 2";
+        assert_eq!(solve_part1(input), Ok(0));
+    }
+
+    #[test]
+    fn test_area_check_too_many() {
+        // 4x4 = 16 cells, need 3*7 = 21 cells, 21 < 16 = false
+        let input = "\
+4:
+###
+#..
+###
+
+4x4: 0 0 0 0 3 0";
+        assert_eq!(solve_part1(input), Ok(0));
+    }
+
+    #[test]
+    fn test_windows_line_endings() {
+        let input = "0:\n###\n##.\n##.\n\n1:\n###\n##.\n.##\n\n4x4: 0 0 0 0 2 0\n12x5: 1 0 1 0 2 2";
+        registry::assert_windows_agnostic(Day12::DAY, input);
+        let windows = input::windows(input);
+        assert_eq!(solve_part1(&windows), solve_part1(input));
+    }
+}
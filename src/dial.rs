@@ -0,0 +1,556 @@
+//! A rotary dial with any number of positions and any set of marked positions, counting
+//! how often a rotation lands on or clicks past a marker in constant time per move, and a
+//! [`DialHistory`] of a list of moves for range queries.
+
+/// Dials with at most this many positions keep a rank table for O(1) counting; larger ones
+/// binary-search their markers instead.
+pub const RANK_TABLE_LIMIT: u64 = 1 << 20;
+
+/// A dial of `size` positions, `0` to `size - 1`, pointing at one of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    size: u64,
+    position: u64,
+    /// Sorted and deduplicated.
+    markers: Vec<u64>,
+    /// `ranks[x]` is the number of markers below `x`, for `x` in `0..=size`.
+    ranks: Option<Vec<u32>>,
+}
+
+/// Where a rotation left the dial and which markers it met on the way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    pub position: u64,
+    /// Whether the dial stopped on a marker.
+    pub landed: bool,
+    /// Clicks that pointed the dial at a marker, including the one it stopped on.
+    pub hits: u64,
+}
+
+impl Dial {
+    /// A dial of `size` positions pointing at `start`, taken modulo `size`, with `markers`
+    /// in any order.
+    pub fn new(
+        size: u64,
+        start: u64,
+        markers: impl IntoIterator<Item = u64>,
+    ) -> Result<Self, String> {
+        if size == 0 {
+            return Err("a dial needs at least one position".to_string());
+        }
+        let mut markers: Vec<u64> = markers.into_iter().collect();
+        if let Some(&marker) = markers.iter().find(|&&marker| marker >= size) {
+            return Err(format!(
+                "marker {marker} is not on a dial of {size} positions"
+            ));
+        }
+        markers.sort_unstable();
+        markers.dedup();
+
+        let ranks = (size <= RANK_TABLE_LIMIT).then(|| {
+            let mut ranks = Vec::with_capacity(size as usize + 1);
+            let mut below = 0;
+            let mut next = markers.iter().peekable();
+            for x in 0..=size {
+                ranks.push(below);
+                if next.next_if(|&&marker| marker == x).is_some() {
+                    below += 1;
+                }
+            }
+            ranks
+        });
+
+        Ok(Self {
+            size,
+            position: start % size,
+            markers,
+            ranks,
+        })
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn markers(&self) -> &[u64] {
+        &self.markers
+    }
+
+    pub fn is_marked(&self, position: u64) -> bool {
+        self.markers.binary_search(&position).is_ok()
+    }
+
+    /// Rotate `distance` clicks, towards lower numbers when `is_left`.
+    pub fn turn(&mut self, is_left: bool, distance: u64) -> Turn {
+        let (first, position) = self.span(self.position, is_left, distance % self.size);
+        self.position = position;
+        Turn {
+            position,
+            landed: self.is_marked(position),
+            hits: self.markers_in(first, distance),
+        }
+    }
+
+    /// Landings on a marker and clicks at one over `moves` turned `repeats` times in a row
+    /// from where the dial points, without turning it.
+    ///
+    /// Takes O(moves × markers × log size) however large `repeats` is, or simulates the
+    /// passes when there are no more of them than markers.
+    pub fn count_repeated(&self, moves: &[(bool, u64)], repeats: u64) -> (u128, u128) {
+        if repeats <= self.markers.len() as u64 {
+            self.simulate_repeated(moves, repeats)
+        } else {
+            self.solve_repeated(moves, repeats)
+        }
+    }
+
+    fn simulate_repeated(&self, moves: &[(bool, u64)], repeats: u64) -> (u128, u128) {
+        let mut dial = self.clone();
+        let (mut landings, mut hits) = (0, 0);
+        for _ in 0..repeats {
+            for &(is_left, distance) in moves {
+                let turn = dial.turn(is_left, distance);
+                landings += turn.landed as u128;
+                hits += turn.hits as u128;
+            }
+        }
+        (landings, hits)
+    }
+
+    /// Every pass turns the dial by the same net amount, so in pass `j` each move covers
+    /// the positions it covered in the first pass, moved on by `j` times that amount.
+    /// Whether a marker falls among them is then a linear congruence in `j`, and
+    /// [`count_below`] counts its solutions over all passes at once.
+    fn solve_repeated(&self, moves: &[(bool, u64)], repeats: u64) -> (u128, u128) {
+        let (size, repeats) = (self.size as u128, repeats as u128);
+        // Where each move's partial lap starts and how long it is, and where the move ends,
+        // in the first pass.
+        let mut windows = Vec::with_capacity(moves.len());
+        let mut ends = Vec::with_capacity(moves.len());
+        let mut hits = 0;
+        let mut position = self.position;
+        for &(is_left, distance) in moves {
+            let step = distance % self.size;
+            let (first, end) = self.span(position, is_left, step);
+            hits += (distance / self.size) as u128 * self.markers.len() as u128 * repeats;
+            if step > 0 {
+                windows.push((first, step as u128));
+            }
+            ends.push(end);
+            position = end;
+        }
+        // How far each pass moves a position back, making the marker's offset grow by it.
+        let back = (self.position as u128 + size - position as u128) % size;
+
+        let mut landings = 0;
+        for &marker in &self.markers {
+            let offset = |from: u64| (marker as u128 + size - from as u128) % size;
+            for &(first, len) in &windows {
+                hits += count_below(offset(first), back, size, len, repeats);
+            }
+            for &end in &ends {
+                landings += count_below(offset(end), back, size, 1, repeats);
+            }
+        }
+        (landings, hits)
+    }
+
+    /// Markers among the `len` consecutive positions from `first`, wrapping past the top
+    /// and counting a position once per visit.
+    pub fn markers_in(&self, first: u64, len: u64) -> u64 {
+        let laps = len / self.size * self.markers.len() as u64;
+        let (first, rest) = (first % self.size, len % self.size);
+        let partial = if rest <= self.size - first {
+            self.rank(first + rest) - self.rank(first)
+        } else {
+            self.markers.len() as u64 - self.rank(first) + self.rank(rest - (self.size - first))
+        };
+        laps + partial
+    }
+
+    /// The first position that `step` clicks from `position` visit, and where they end.
+    /// Turning right visits the positions after `position`, and turning left the ones
+    /// ending just before it.
+    fn span(&self, position: u64, is_left: bool, step: u64) -> (u64, u64) {
+        if is_left {
+            let end = self.back(position, step);
+            (end, end)
+        } else {
+            (
+                self.forward(position, 1 % self.size),
+                self.forward(position, step),
+            )
+        }
+    }
+
+    /// `position + step` around the dial, for `position` and `step` below the size.
+    fn forward(&self, position: u64, step: u64) -> u64 {
+        match step.checked_sub(self.size - position) {
+            Some(wrapped) => wrapped,
+            None => position + step,
+        }
+    }
+
+    /// `position - step` around the dial, for `position` and `step` below the size.
+    fn back(&self, position: u64, step: u64) -> u64 {
+        match position.checked_sub(step) {
+            Some(position) => position,
+            None => self.size - (step - position),
+        }
+    }
+
+    /// The number of markers below `x`.
+    fn rank(&self, x: u64) -> u64 {
+        match &self.ranks {
+            Some(ranks) => ranks[x as usize] as u64,
+            None => self.markers.partition_point(|&marker| marker < x) as u64,
+        }
+    }
+}
+
+/// The `j` in `0..n` with `(offset + j * step) % size < below`, for `offset` and `step`
+/// below `size` and `below` at most `size`.
+///
+/// `x % size < below` is `1 - ⌊(x + size - below) / size⌋ + ⌊x / size⌋`, so the count is
+/// `n` less the difference of two floor sums.
+fn count_below(offset: u128, step: u128, size: u128, below: u128, n: u128) -> u128 {
+    let crossings = floor_sum(n, size, step, offset + size - below)
+        .wrapping_sub(floor_sum(n, size, step, offset));
+    n - crossings
+}
+
+/// `Σ ⌊(a * j + b) / m⌋` over `j` in `0..n`, in O(log m) steps by the Euclid-like reduction.
+///
+/// The sum wraps, which leaves differences of two sums exact as long as they fit; the other
+/// products stay below 2^128 for `n` and `m` below 2^64 and `b` below `2 * m`.
+fn floor_sum(mut n: u128, mut m: u128, mut a: u128, mut b: u128) -> u128 {
+    let mut sum: u128 = 0;
+    loop {
+        if a >= m {
+            let pairs = if n.is_multiple_of(2) {
+                n / 2 * n.saturating_sub(1)
+            } else {
+                n.saturating_sub(1) / 2 * n
+            };
+            sum = sum.wrapping_add(pairs.wrapping_mul(a / m));
+            a %= m;
+        }
+        if b >= m {
+            sum = sum.wrapping_add(n * (b / m));
+            b %= m;
+        }
+        let top = a * n + b;
+        if top < m {
+            return sum;
+        }
+        (n, b) = (top / m, top % m);
+        (m, a) = (a, m);
+    }
+}
+
+/// The positions a dial took over a list of moves and running totals of its landings and
+/// hits, so range queries take O(1) after a linear build.
+///
+/// Moves are numbered from 1; move 0 is the starting position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialHistory {
+    /// `positions[k]` is where the dial pointed after `k` moves.
+    positions: Vec<u64>,
+    /// `landings[k]` and `hits[k]` are the totals over the first `k` moves.
+    landings: Vec<u64>,
+    hits: Vec<u64>,
+}
+
+impl DialHistory {
+    /// Turn `dial` through `moves`, given as `(is_left, distance)`, recording each step.
+    pub fn new(mut dial: Dial, moves: impl IntoIterator<Item = (bool, u64)>) -> Self {
+        let mut history = Self {
+            positions: vec![dial.position()],
+            landings: vec![0],
+            hits: vec![0],
+        };
+        for (is_left, distance) in moves {
+            let turn = dial.turn(is_left, distance);
+            let k = history.positions.len() - 1;
+            history.positions.push(turn.position);
+            history
+                .landings
+                .push(history.landings[k] + turn.landed as u64);
+            history.hits.push(history.hits[k] + turn.hits);
+        }
+        history
+    }
+
+    /// The number of moves.
+    pub fn len(&self) -> usize {
+        self.positions.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Where the dial pointed after move `k`, or `None` past the last move.
+    pub fn position_after(&self, k: usize) -> Option<u64> {
+        self.positions.get(k).copied()
+    }
+
+    /// Moves `first` to `last`, both included, that ended on a marker.
+    pub fn landings_between(&self, first: usize, last: usize) -> Option<u64> {
+        self.between(&self.landings, first, last)
+    }
+
+    /// Clicks during moves `first` to `last`, both included, that pointed at a marker.
+    pub fn hits_between(&self, first: usize, last: usize) -> Option<u64> {
+        self.between(&self.hits, first, last)
+    }
+
+    /// `None` unless `1 <= first <= last <= len`.
+    fn between(&self, totals: &[u64], first: usize, last: usize) -> Option<u64> {
+        (1 <= first && first <= last && last <= self.len())
+            .then(|| totals[last] - totals[first - 1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prop;
+
+    use super::*;
+
+    /// Click by click, checking every position against the markers.
+    fn naive_turn(dial: &Dial, is_left: bool, distance: u64) -> Turn {
+        let mut position = dial.position();
+        let mut hits = 0;
+        for _ in 0..distance {
+            position = if is_left {
+                (position + dial.size() - 1) % dial.size()
+            } else {
+                (position + 1) % dial.size()
+            };
+            hits += dial.is_marked(position) as u64;
+        }
+        Turn {
+            position,
+            landed: dial.is_marked(position),
+            hits,
+        }
+    }
+
+    #[test]
+    fn test_turn_single_marker() {
+        let mut dial = Dial::new(100, 50, [0]).unwrap();
+        assert_eq!(
+            dial.turn(true, 68),
+            Turn {
+                position: 82,
+                landed: false,
+                hits: 1
+            }
+        );
+        assert_eq!(
+            dial.turn(true, 82),
+            Turn {
+                position: 0,
+                landed: true,
+                hits: 1
+            }
+        );
+        assert_eq!(dial.turn(false, 1000).hits, 10);
+        assert_eq!(dial.turn(true, 0).hits, 0);
+        assert_eq!(dial.position(), 0);
+    }
+
+    #[test]
+    fn test_turn_several_markers() {
+        let mut dial = Dial::new(10, 0, [7, 2, 2, 5]).unwrap();
+        assert_eq!(dial.markers(), &[2, 5, 7]);
+        assert_eq!(dial.turn(false, 5).hits, 2);
+        assert!(!dial.turn(true, 2).landed);
+        assert!(dial.turn(false, 4).landed);
+        assert_eq!(dial.turn(true, 25).hits, 8);
+        assert_eq!(dial.position(), 2);
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Dial::new(10, 13, []).unwrap().position(), 3);
+        assert_eq!(Dial::new(1, 0, [0]).unwrap().turn(true, 5).hits, 5);
+        assert_eq!(
+            Dial::new(0, 0, []),
+            Err("a dial needs at least one position".to_string())
+        );
+        assert_eq!(
+            Dial::new(100, 0, [0, 100]),
+            Err("marker 100 is not on a dial of 100 positions".to_string())
+        );
+    }
+
+    #[test]
+    fn test_markers_in_without_rank_table() {
+        let size = RANK_TABLE_LIMIT * 4;
+        let mut dial = Dial::new(size, 0, [0, size - 1]).unwrap();
+        assert_eq!(dial.markers_in(size - 1, 2), 2);
+        assert_eq!(dial.turn(false, size * 3 + 1).hits, 6);
+        assert_eq!(
+            dial.turn(true, u64::MAX).position,
+            (1 + size - u64::MAX % size) % size
+        );
+
+        let mut dial = Dial::new(u64::MAX, u64::MAX - 2, [1]).unwrap();
+        assert_eq!(dial.turn(false, 4).hits, 1);
+        assert_eq!(dial.position(), 2);
+        assert_eq!(dial.turn(true, 3).position, u64::MAX - 1);
+    }
+
+    #[test]
+    fn test_history() {
+        // Day 1's example on the puzzle's dial.
+        let moves = [
+            (true, 68),
+            (true, 30),
+            (false, 48),
+            (true, 5),
+            (false, 60),
+            (true, 55),
+            (true, 1),
+            (true, 99),
+            (false, 14),
+            (true, 82),
+        ];
+        let history = DialHistory::new(Dial::new(100, 50, [0]).unwrap(), moves);
+        assert_eq!(history.len(), 10);
+        assert_eq!(history.position_after(0), Some(50));
+        assert_eq!(history.position_after(1), Some(82));
+        assert_eq!(history.position_after(10), Some(32));
+        assert_eq!(history.position_after(11), None);
+        assert_eq!(history.hits_between(1, 10), Some(6));
+        assert_eq!(history.landings_between(1, 10), Some(3));
+        assert_eq!(history.hits_between(3, 3), Some(1));
+        assert_eq!(history.hits_between(4, 5), Some(1));
+        assert_eq!(history.landings_between(4, 5), Some(0));
+        for (first, last) in [(0, 3), (4, 3), (1, 11)] {
+            assert_eq!(history.hits_between(first, last), None);
+        }
+    }
+
+    #[test]
+    fn test_history_empty() {
+        let history = DialHistory::new(Dial::new(10, 3, []).unwrap(), []);
+        assert!(history.is_empty());
+        assert_eq!(history.position_after(0), Some(3));
+        assert_eq!(history.hits_between(1, 1), None);
+    }
+
+    #[test]
+    fn test_history_matches_naive() {
+        prop::check(
+            "dial history",
+            |rng| {
+                let size = rng.range(1..=30) as u64;
+                let markers: Vec<u64> = (0..rng.range(0..=3)).map(|_| rng.below(size)).collect();
+                let moves: Vec<(bool, u64)> = (0..rng.range(1..=12))
+                    .map(|_| (rng.bool(), rng.range(0..=80) as u64))
+                    .collect();
+                let first = rng.range(1..=moves.len() as i64) as usize;
+                let last = rng.range(first as i64..=moves.len() as i64) as usize;
+                (size, markers, moves, first, last)
+            },
+            |(size, markers, moves, first, last)| {
+                let dial = Dial::new(*size, 0, markers.iter().copied()).unwrap();
+                let history = DialHistory::new(dial.clone(), moves.iter().copied());
+
+                let mut replay = dial;
+                let turns: Vec<Turn> = moves.iter().map(|&(l, d)| replay.turn(l, d)).collect();
+                let range = &turns[first - 1..*last];
+                prop::agree(
+                    (
+                        history.hits_between(*first, *last),
+                        history.landings_between(*first, *last),
+                        history.position_after(*last),
+                    ),
+                    (
+                        Some(range.iter().map(|turn| turn.hits).sum()),
+                        Some(range.iter().filter(|turn| turn.landed).count() as u64),
+                        Some(turns[last - 1].position),
+                    ),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_turn_matches_naive() {
+        prop::check(
+            "dial turn",
+            |rng| {
+                let size = rng.range(1..=30) as u64;
+                let markers: Vec<u64> = (0..rng.range(0..=4)).map(|_| rng.below(size)).collect();
+                let start = rng.below(size);
+                let moves: Vec<(bool, u64)> = (0..5)
+                    .map(|_| (rng.bool(), rng.range(0..=100) as u64))
+                    .collect();
+                (size, start, markers, moves)
+            },
+            |(size, start, markers, moves)| {
+                let mut dial = Dial::new(*size, *start, markers.iter().copied()).unwrap();
+                for &(is_left, distance) in moves {
+                    let expected = naive_turn(&dial, is_left, distance);
+                    prop::agree(dial.turn(is_left, distance), expected)?;
+                }
+                Ok(())
+            },
+        );
+    }
+
+    #[test]
+    fn test_count_repeated_matches_naive() {
+        prop::check(
+            "dial count_repeated",
+            |rng| {
+                let size = rng.range(1..=30) as u64;
+                let markers: Vec<u64> = (0..rng.range(0..=4)).map(|_| rng.below(size)).collect();
+                let start = rng.below(size);
+                let moves: Vec<(bool, u64)> = (0..rng.range(0..=5))
+                    .map(|_| (rng.bool(), rng.range(0..=100) as u64))
+                    .collect();
+                (size, start, markers, moves, rng.range(0..=70) as u64)
+            },
+            |(size, start, markers, moves, repeats)| {
+                let dial = Dial::new(*size, *start, markers.iter().copied()).unwrap();
+                prop::agree(
+                    dial.solve_repeated(moves, *repeats),
+                    dial.simulate_repeated(moves, *repeats),
+                )?;
+                prop::agree(
+                    dial.count_repeated(moves, *repeats),
+                    dial.simulate_repeated(moves, *repeats),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_count_repeated_long_period() {
+        // On a prime-sized dial, `R3` only comes back to its start after `size` passes.
+        let size = 1_000_000_007;
+        let repeats = 1_000_000_000_000_000_000;
+        let dial = Dial::new(size, 0, [0]).unwrap();
+        assert_eq!(
+            dial.count_repeated(&[(false, 3)], repeats),
+            (
+                (repeats / size) as u128,
+                (3 * repeats as u128) / size as u128
+            )
+        );
+        // The largest dials and repeats: one click a pass reaches 0 after the last pass.
+        let dial = Dial::new(u64::MAX, 0, [0]).unwrap();
+        assert_eq!(dial.count_repeated(&[(false, 1)], u64::MAX), (1, 1));
+        assert_eq!(dial.count_repeated(&[(true, 1)], u64::MAX), (1, 1));
+        assert_eq!(dial.count_repeated(&[(true, 1)], u64::MAX - 1), (0, 0));
+    }
+}
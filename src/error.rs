@@ -0,0 +1,175 @@
+//! `ParseError`, the structured error returned by every day's parser.
+
+use std::fmt;
+
+/// A located problem in a puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending text.
+    pub found: String,
+    /// What the parser expected instead.
+    pub expected: String,
+    /// The full line containing the error, used when rendering.
+    pub line_text: String,
+}
+
+impl ParseError {
+    /// Build an error for `fragment`, which must be a subslice of `source`.
+    ///
+    /// Line and column are derived from the fragment's position, so parsers only need to keep
+    /// hold of the slice that failed. Fragments outside `source` are reported at line 1, column 1.
+    pub fn new(day: u8, source: &str, fragment: &str, expected: impl Into<String>) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(source.as_ptr() as usize)
+            .filter(|&offset| offset + fragment.len() <= source.len())
+            .unwrap_or(0);
+
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+
+        Self {
+            day,
+            line: source[..offset].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            found: fragment.to_string(),
+            expected: expected.into(),
+            line_text: source[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// Move an error located within a single line to `line` of the full input.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Multi-line rendering with the offending line and a caret marker.
+    pub fn render(&self) -> String {
+        let gutter = self.line.to_string().len();
+        let width = self.found.chars().count().max(1);
+        format!(
+            "{self}\n{:gutter$} |\n{} | {}\n{:gutter$} | {}{}",
+            "",
+            self.line,
+            self.line_text,
+            "",
+            " ".repeat(self.column - 1),
+            "^".repeat(width),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {:02}, line {}, column {}: expected {}",
+            self.day, self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, ", found end of input")
+        } else {
+            write!(f, ", found `{}`", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_locates_fragment() {
+        let source = "L68\nX30\nR48";
+        let error = ParseError::new(1, source, &source[4..5], "direction");
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 1);
+        assert_eq!(error.found, "X");
+        assert_eq!(error.line_text, "X30");
+    }
+
+    #[test]
+    fn test_new_counts_columns_in_chars() {
+        let source = "ab\né-xyz";
+        let error = ParseError::new(2, source, &source[6..9], "number");
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.found, "xyz");
+    }
+
+    #[test]
+    fn test_new_at_end_of_input() {
+        let source = "1-2\n";
+        let error = ParseError::new(5, source, &source[source.len()..], "IDs section");
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.found, "");
+        assert_eq!(error.line_text, "");
+    }
+
+    #[test]
+    fn test_new_strips_carriage_return() {
+        let source = "1,2\r\nx,3\r\n";
+        let error = ParseError::new(9, source, &source[5..6], "number");
+        assert_eq!(error.line_text, "x,3");
+    }
+
+    #[test]
+    fn test_new_fragment_outside_source() {
+        let error = ParseError::new(1, "abc", "zzz", "something");
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.found, "zzz");
+    }
+
+    #[test]
+    fn test_on_line() {
+        let line = "[.#] (x) {1}";
+        let error = ParseError::new(10, line, &line[6..7], "button index").on_line(12);
+        assert_eq!((error.line, error.column), (12, 7));
+    }
+
+    #[test]
+    fn test_display() {
+        let source = "1-2,3-x";
+        let error = ParseError::new(2, source, &source[6..], "end as a valid number");
+        assert_eq!(
+            error.to_string(),
+            "day 02, line 1, column 7: expected end as a valid number, found `x`"
+        );
+    }
+
+    #[test]
+    fn test_display_end_of_input() {
+        let error = ParseError::new(5, "", "", "ranges section");
+        assert_eq!(
+            error.to_string(),
+            "day 05, line 1, column 1: expected ranges section, found end of input"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let source = "L68\nL30\nX48";
+        let error = ParseError::new(1, source, &source[8..9], "direction `L` or `R`");
+        assert_eq!(
+            error.render(),
+            "day 01, line 3, column 1: expected direction `L` or `R`, found `X`\n  |\n3 | X48\n  | ^"
+        );
+    }
+
+    #[test]
+    fn test_render_wide_fragment() {
+        let source = "12-abc";
+        let error = ParseError::new(2, source, &source[3..], "end as a valid number");
+        assert!(error.render().ends_with("1 | 12-abc\n  |    ^^^"));
+    }
+}
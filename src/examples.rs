@@ -0,0 +1,379 @@
+//! Tagged examples in the day feature docs, run against the solvers so docs and code agree.
+//!
+//! A fenced block whose info string starts with `example` is an example input; the words
+//! after it are the expected answers, e.g. ```` ```example part1=3 part2=6 ````, and any
+//! parameters the example is solved with, e.g. ```` ```example connections=10 part1=40 ````.
+
+use std::path::Path;
+
+use crate::params::Params;
+use crate::registry;
+use crate::solution::Part;
+
+/// One tagged block from a feature doc.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// 1-based line of the opening fence.
+    pub line: usize,
+    pub input: String,
+    pub expected: Vec<(Part, String)>,
+    /// Parameter overrides, e.g. `connections=10`.
+    pub params: Vec<(String, String)>,
+}
+
+/// Pull every tagged example out of `markdown`, reporting bad tags as `line N: ...`.
+pub fn extract(markdown: &str) -> Result<Vec<Example>, String> {
+    let mut examples = Vec::new();
+    let mut lines = markdown.lines().enumerate();
+
+    while let Some((i, line)) = lines.next() {
+        let Some(info) = line.strip_prefix("```") else {
+            continue;
+        };
+        let mut words = info.split_whitespace();
+        let tagged = words.next() == Some("example");
+
+        let mut body = Vec::new();
+        let mut closed = false;
+        for (_, line) in lines.by_ref() {
+            if line.trim_end() == "```" {
+                closed = true;
+                break;
+            }
+            body.push(line);
+        }
+        if !closed {
+            return Err(format!("line {}: unterminated code block", i + 1));
+        }
+        if !tagged {
+            continue;
+        }
+
+        let mut expected = Vec::new();
+        let mut params = Vec::new();
+        for word in words {
+            let (key, value) = word
+                .split_once('=')
+                .filter(|(_, value)| !value.is_empty())
+                .ok_or_else(|| {
+                    format!("line {}: expected `partN=answer`, found `{word}`", i + 1)
+                })?;
+            let is_part = key
+                .strip_prefix("part")
+                .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()));
+            match key {
+                "part1" => expected.push((Part::One, value.to_string())),
+                "part2" => expected.push((Part::Two, value.to_string())),
+                _ if is_part => return Err(format!("line {}: unknown part `{key}`", i + 1)),
+                _ => params.push((key.to_string(), value.to_string())),
+            }
+        }
+
+        examples.push(Example {
+            line: i + 1,
+            input: body.join("\n"),
+            expected,
+            params,
+        });
+    }
+
+    Ok(examples)
+}
+
+/// The day in a `YYYY-MM-DD-HHMM_DAYXX_IMPLEMENTATION.md` file name.
+pub fn day_of(path: &Path) -> Option<u8> {
+    path.file_name()?
+        .to_str()?
+        .strip_suffix("_IMPLEMENTATION.md")?
+        .rsplit_once("_DAY")?
+        .1
+        .parse()
+        .ok()
+}
+
+/// Run `example` through the registered solver for `day`, with its parameters.
+///
+/// Only the parts with an expected answer are solved; an example without any still has to
+/// parse.
+pub fn check(day: u8, example: &Example) -> Result<(), String> {
+    let solver = registry::find(day).ok_or_else(|| format!("day {day} is not registered"))?;
+    let params = Params::resolve(solver.params, &example.params)?;
+    let parts: Vec<Part> = example.expected.iter().map(|(part, _)| *part).collect();
+    let run = solver
+        .run_with(&example.input, &parts, &params)
+        .map_err(|error| error.render())?;
+
+    let wrong: Vec<String> = example
+        .expected
+        .iter()
+        .zip(run.parts)
+        .filter(|((_, expected), solved)| solved.answer.to_string() != *expected)
+        .map(|((part, expected), solved)| {
+            let answer = &solved.answer;
+            format!("part {part}: got {answer}, expected {expected}")
+        })
+        .collect();
+    if wrong.is_empty() {
+        Ok(())
+    } else {
+        Err(wrong.join(", "))
+    }
+}
+
+/// Check every tagged example in the `*_IMPLEMENTATION.md` files of `dir`, and that every
+/// registered day has at least one with an expected answer.
+///
+/// Returns how many examples were checked, or one `file:line: problem` message per failure.
+pub fn check_docs(dir: &Path) -> Result<usize, Vec<String>> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|error| vec![format!("failed to read {}: {error}", dir.display())])?;
+    let mut paths: Vec<_> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter_map(|path| day_of(&path).map(|day| (path, day)))
+        .collect();
+    paths.sort();
+
+    let mut checked = 0;
+    let mut answered = Vec::new();
+    let mut failures = Vec::new();
+    for (path, day) in paths {
+        let name = path.display();
+        let examples = std::fs::read_to_string(&path)
+            .map_err(|error| format!("failed to read {name}: {error}"))
+            .and_then(|markdown| extract(&markdown).map_err(|error| format!("{name}:{error}")));
+        let examples = match examples {
+            Ok(examples) => examples,
+            Err(error) => {
+                failures.push(error);
+                continue;
+            }
+        };
+        for example in examples {
+            checked += 1;
+            if !example.expected.is_empty() {
+                answered.push(day);
+            }
+            if let Err(error) = check(day, &example) {
+                failures.push(format!("{name}:{}: {error}", example.line));
+            }
+        }
+    }
+
+    for day in registry::DAYS {
+        if !answered.contains(&day.number) {
+            failures.push(format!(
+                "day {} has no example with an expected answer",
+                day.number
+            ));
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(checked)
+    } else {
+        Err(failures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::temp::Temp;
+
+    use super::*;
+
+    fn example(input: &str, expected: &[(Part, &str)]) -> Example {
+        Example {
+            line: 1,
+            input: input.to_string(),
+            expected: expected
+                .iter()
+                .map(|(part, answer)| (*part, answer.to_string()))
+                .collect(),
+            params: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_feature_docs() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("docs/features");
+        match check_docs(&dir) {
+            Ok(checked) => assert!(checked >= registry::DAYS.len(), "only {checked} examples"),
+            Err(failures) => panic!("{}", failures.join("\n")),
+        }
+    }
+
+    #[test]
+    fn test_extract() {
+        let markdown = "\
+# Day 01
+
+```
+not an example
+```
+
+```example part1=3 part2=6
+L68
+L30
+```
+
+```rust
+fn main() {}
+```
+```example
+```
+";
+        assert_eq!(
+            extract(markdown),
+            Ok(vec![
+                Example {
+                    line: 7,
+                    ..example("L68\nL30", &[(Part::One, "3"), (Part::Two, "6")])
+                },
+                Example {
+                    line: 15,
+                    ..example("", &[])
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_extract_keeps_whitespace() {
+        let examples = extract("```example part1=1\n 45 64 \n\n*   +  \n```\n").unwrap();
+        assert_eq!(examples[0].input, " 45 64 \n\n*   +  ");
+    }
+
+    #[test]
+    fn test_extract_params() {
+        let examples =
+            extract("```example connections=10 part1=40 part2_from=svr\nx\n```").unwrap();
+        assert_eq!(examples[0].expected, vec![(Part::One, "40".to_string())]);
+        assert_eq!(
+            examples[0].params,
+            vec![
+                ("connections".to_string(), "10".to_string()),
+                ("part2_from".to_string(), "svr".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_errors() {
+        assert_eq!(
+            extract("text\n```example part3=1\nx\n```"),
+            Err("line 2: unknown part `part3`".to_string())
+        );
+        assert_eq!(
+            extract("```example part1\nx\n```"),
+            Err("line 1: expected `partN=answer`, found `part1`".to_string())
+        );
+        assert_eq!(
+            extract("```example part1=\nx\n```"),
+            Err("line 1: expected `partN=answer`, found `part1=`".to_string())
+        );
+        assert_eq!(
+            extract("a\n\n```\nx\n"),
+            Err("line 3: unterminated code block".to_string())
+        );
+    }
+
+    #[test]
+    fn test_day_of() {
+        assert_eq!(
+            day_of(Path::new(
+                "docs/features/2025-12-05-1250_DAY01_IMPLEMENTATION.md"
+            )),
+            Some(1)
+        );
+        assert_eq!(
+            day_of(Path::new("2025-12-12-1207_DAY12_IMPLEMENTATION.md")),
+            Some(12)
+        );
+        assert_eq!(day_of(Path::new("2026-10-18-1130_BENCHMARKS.md")), None);
+        assert_eq!(day_of(Path::new("x_DAYXX_IMPLEMENTATION.md")), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        assert_eq!(
+            check(1, &example(input, &[(Part::One, "3"), (Part::Two, "6")])),
+            Ok(())
+        );
+        assert_eq!(check(1, &example(input, &[(Part::Two, "6")])), Ok(()));
+        assert_eq!(check(1, &example(input, &[])), Ok(()));
+        assert_eq!(
+            check(1, &example(input, &[(Part::One, "4"), (Part::Two, "7")])),
+            Err("part 1: got 3, expected 4, part 2: got 6, expected 7".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check_params() {
+        let input = "0,0,0\n1,0,0\n5,0,0\n20,0,0";
+        let with = |params: &[(&str, &str)], answer: &str| Example {
+            params: params
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..example(input, &[(Part::One, answer)])
+        };
+        assert_eq!(check(8, &with(&[("connections", "1")], "2")), Ok(()));
+        assert_eq!(
+            check(8, &with(&[("connections", "2"), ("circuits", "1")], "3")),
+            Ok(())
+        );
+        assert_eq!(
+            check(8, &with(&[("links", "1")], "2")),
+            Err("unknown parameter `links`, expected one of: connections, circuits".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check_errors() {
+        assert_eq!(
+            check(30, &example("", &[])),
+            Err("day 30 is not registered".to_string())
+        );
+        let error = check(1, &example("L68\nX5", &[])).unwrap_err();
+        assert!(error.starts_with("day 01, line 2, column 1: "), "{error}");
+    }
+
+    #[test]
+    fn test_check_docs_reports_failures() {
+        let dir = Temp::path("examples");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("x_DAY01_IMPLEMENTATION.md"),
+            "```example part1=4\nL68\n```\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("x_DAY02_IMPLEMENTATION.md"),
+            "```example part9=1\n```\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("NOTES.md"), "```example part1=4\n```\n").unwrap();
+
+        let name = |file: &str| dir.join(file).display().to_string();
+        let mut expected = vec![
+            format!(
+                "{}:1: part 1: got 0, expected 4",
+                name("x_DAY01_IMPLEMENTATION.md")
+            ),
+            format!(
+                "{}:line 1: unknown part `part9`",
+                name("x_DAY02_IMPLEMENTATION.md")
+            ),
+        ];
+        // Day 1's example has an answer, even a wrong one; no other day has one.
+        expected.extend(
+            (2..=12).map(|day| format!("day {day} has no example with an expected answer")),
+        );
+        assert_eq!(check_docs(&dir), Err(expected));
+        assert!(check_docs(&PathBuf::from("does/not/exist")).is_err());
+    }
+}
@@ -0,0 +1,227 @@
+//! Mutation fuzzing of the parsers: malformed input must be parsed or rejected with an
+//! error, never panic or hang.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::examples;
+use crate::input;
+use crate::parallel;
+use crate::registry::Day;
+use crate::rng::Rng;
+use crate::shrink;
+
+/// How long a parse may take before it counts as a hang.
+pub const HANG_LIMIT: Duration = Duration::from_secs(5);
+
+/// Snippets that tend to break parsers: separators of every day, signs, overflow, blank
+/// lines, CRLF, tabs, multi-byte characters and a byte order mark.
+const TOKENS: [&str; 30] = [
+    "\n",
+    "\n\n",
+    "\r\n",
+    " ",
+    "\t",
+    ",",
+    "-",
+    ":",
+    "x",
+    "[",
+    "]",
+    "(",
+    ")",
+    "{",
+    "}",
+    "#",
+    ".",
+    "^",
+    "S",
+    "L",
+    "R",
+    "+",
+    "*",
+    "0",
+    "9",
+    "-1",
+    "99999999999999999999",
+    "é",
+    "☃",
+    "\u{feff}",
+];
+
+/// What parsing an input did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Parsed,
+    Rejected,
+    Panicked(String),
+    Hung,
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Outcome::Panicked(_) | Outcome::Hung)
+    }
+}
+
+/// Parse `input` with `day` on a separate thread, giving up after `limit`.
+///
+/// A hung parse cannot be stopped and keeps its thread busy. The fuzz test therefore stops
+/// at the first hang, which it minimizes at the cost of a thread per hanging candidate.
+pub fn parse(day: &'static Day, input: &str, limit: Duration) -> Outcome {
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_string();
+    thread::spawn(move || {
+        let parsed = panic::catch_unwind(AssertUnwindSafe(|| day.run(&input, &[]).is_ok()));
+        let _ = sender.send(parsed);
+    });
+    match receiver.recv_timeout(limit) {
+        Ok(Ok(true)) => Outcome::Parsed,
+        Ok(Ok(false)) => Outcome::Rejected,
+        Ok(Err(payload)) => Outcome::Panicked(parallel::panic_message(payload.as_ref())),
+        Err(_) => Outcome::Hung,
+    }
+}
+
+/// Seeds for `day`: the tagged examples from its feature doc and the head of its puzzle
+/// input (40 lines of at most 120 characters), when they exist.
+pub fn corpus(day: u8) -> Vec<String> {
+    let docs = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("docs/features");
+    let mut seeds: Vec<String> = std::fs::read_dir(docs)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| examples::day_of(path) == Some(day))
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .filter_map(|markdown| examples::extract(&markdown).ok())
+        .flatten()
+        .map(|example| example.input)
+        .collect();
+    if let Ok(input) = std::fs::read_to_string(input::default_path(day)) {
+        let head: Vec<String> = input
+            .lines()
+            .take(40)
+            .map(|line| line.chars().take(120).collect())
+            .collect();
+        seeds.push(head.join("\n") + "\n");
+    }
+    seeds
+}
+
+/// One to four random edits of `seed`: deleting, inserting or replacing characters or
+/// whole lines, or truncating.
+pub fn mutate(rng: &mut Rng, seed: &str) -> String {
+    let mut chars: Vec<char> = seed.chars().collect();
+    for _ in 0..rng.range(1..=4) {
+        let at = rng.below(chars.len() as u64 + 1) as usize;
+        match rng.below(7) {
+            0 => {
+                let end = (at + rng.range(1..=8) as usize).min(chars.len());
+                chars.drain(at..end);
+            }
+            1 => {
+                let token = rng.pick(&TOKENS);
+                chars.splice(at..at, token.chars());
+            }
+            2 if at < chars.len() => {
+                let mut token = rng.pick(&TOKENS).chars();
+                chars[at] = token.next().unwrap_or(' ');
+            }
+            3 => chars.truncate(at),
+            operation => {
+                let text: String = chars.iter().collect();
+                let mut lines: Vec<&str> = text.split('\n').collect();
+                let i = rng.below(lines.len() as u64) as usize;
+                let j = rng.below(lines.len() as u64) as usize;
+                match operation {
+                    4 => lines.insert(j, lines[i]),
+                    5 => lines.swap(i, j),
+                    _ => {
+                        lines.remove(i);
+                    }
+                }
+                chars = lines.join("\n").chars().collect();
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// Cut a failing `input` down to the characters needed to keep it failing the same way.
+pub fn minimize(day: &'static Day, input: &str, limit: Duration) -> String {
+    let expected = std::mem::discriminant(&parse(day, input, limit));
+    let chars = shrink::ddmin(input.chars().collect(), |chars: &[char]| {
+        let candidate: String = chars.iter().collect();
+        std::mem::discriminant(&parse(day, &candidate, limit)) == expected
+    });
+    chars.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prop::{self, Config};
+    use crate::registry::{self, DAYS};
+
+    #[test]
+    fn test_parsers_never_panic_or_hang() {
+        for day in DAYS {
+            let seeds = corpus(day.number);
+            assert!(!seeds.is_empty(), "no seeds for day {}", day.number);
+            let generate = |rng: &mut Rng| {
+                let seed = rng.pick(&seeds);
+                mutate(rng, seed)
+            };
+            let result = prop::run(Config::from_env(), generate, |input| {
+                match parse(day, input, HANG_LIMIT) {
+                    outcome if outcome.is_failure() => Err(format!(
+                        "day {:02} parser: {outcome:?} on minimal input {:?}",
+                        day.number,
+                        minimize(day, input, HANG_LIMIT)
+                    )),
+                    _ => Ok(()),
+                }
+            });
+            if let Err(failure) = result {
+                panic!("{failure}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_outcomes() {
+        let day = registry::find(1).unwrap();
+        assert_eq!(parse(day, "L1\nR2", HANG_LIMIT), Outcome::Parsed);
+        assert_eq!(parse(day, "L1\nX2", HANG_LIMIT), Outcome::Rejected);
+        assert!(!Outcome::Rejected.is_failure());
+        assert!(Outcome::Panicked(String::new()).is_failure());
+        assert!(Outcome::Hung.is_failure());
+    }
+
+    #[test]
+    fn test_mutate() {
+        let mut rng = Rng::new(1);
+        let seed = "L68\nL30\nR48\n";
+        let mutants: Vec<String> = (0..200).map(|_| mutate(&mut rng, seed)).collect();
+        assert!(mutants.iter().any(|m| m != seed));
+        assert!(mutants.iter().any(|m| m.len() < seed.len()));
+        assert!(mutants.iter().any(|m| m.len() > seed.len()));
+        assert!(mutants.iter().any(|m| !m.is_ascii()));
+        assert_eq!(mutate(&mut Rng::new(5), ""), mutate(&mut Rng::new(5), ""));
+    }
+
+    #[test]
+    fn test_corpus() {
+        for day in DAYS {
+            // The doc examples, plus the input head when the input exists.
+            assert!(!corpus(day.number).is_empty(), "day {}", day.number);
+        }
+        assert!(corpus(30).is_empty());
+        assert!(corpus(6)
+            .iter()
+            .all(|seed| seed.lines().all(|l| l.chars().count() <= 120)));
+    }
+}
@@ -0,0 +1,633 @@
+//! Seeded random puzzle inputs at any scale, with the answers the construction guarantees.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::rng::Rng;
+use crate::solution::{Answer, Part};
+
+/// Days with a generator.
+pub const DAYS: [u8; 8] = [1, 2, 6, 7, 9, 10, 11, 12];
+
+/// Path counts (day 11) and timelines (day 7) are kept below this so no solver overflows.
+const PATH_LIMIT: u64 = 1_000_000_000_000_000;
+
+/// A generated input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    /// Answers known from the construction; parts without one are left out.
+    pub answers: Vec<(Part, Answer)>,
+}
+
+/// What `size` counts for `day`, and its default, roughly the scale of a real input.
+pub fn size_of(day: u8) -> Option<(&'static str, usize)> {
+    match day {
+        1 => Some(("moves", 4000)),
+        2 => Some(("ranges", 35)),
+        6 => Some(("problems", 1000)),
+        7 => Some(("splitter rows", 70)),
+        9 => Some(("columns", 125)),
+        10 => Some(("machines", 180)),
+        11 => Some(("devices", 600)),
+        12 => Some(("regions", 1000)),
+        _ => None,
+    }
+}
+
+/// Generate an input for `day` from `seed`, `size` being what [`size_of`] describes.
+pub fn generate(day: u8, seed: u64, size: usize) -> Result<Generated, String> {
+    if size == 0 {
+        return Err("size must be positive".to_string());
+    }
+    let rng = &mut Rng::new(seed);
+    let (input, answers) = match day {
+        1 => dial_moves(rng, size),
+        2 => id_ranges(rng, size),
+        6 => worksheet(rng, size),
+        7 => manifold(rng, size),
+        9 => {
+            let tiles = rectilinear_loop(rng, size, 800, 100_000);
+            let input = tiles.iter().map(|(x, y)| format!("{x},{y}\n")).collect();
+            (input, vec![])
+        }
+        10 => machines(rng, size),
+        11 => devices(rng, size),
+        12 => regions(rng, size),
+        _ => return Err(format!("no generator for day {day}")),
+    };
+    Ok(Generated { input, answers })
+}
+
+fn both(part1: impl Into<Answer>, part2: impl Into<Answer>) -> Vec<(Part, Answer)> {
+    vec![(Part::One, part1.into()), (Part::Two, part2.into())]
+}
+
+/// Day 1: moves on the 100-position dial, about one in eight landing exactly on 0.
+fn dial_moves(rng: &mut Rng, size: usize) -> (String, Vec<(Part, Answer)>) {
+    let mut input = String::new();
+    let mut position: i64 = 50;
+    let (mut landings, mut passes) = (0u64, 0i64);
+
+    for _ in 0..size {
+        let left = rng.bool();
+        let laps = if rng.below(4) == 0 {
+            rng.range(1..=9)
+        } else {
+            0
+        };
+        let distance = if rng.below(8) == 0 {
+            let to_zero = if left {
+                position
+            } else {
+                (100 - position) % 100
+            };
+            Some(to_zero + 100 * laps).filter(|&d| d > 0).unwrap_or(100)
+        } else {
+            rng.range(1..=99) + 100 * laps
+        };
+
+        // Multiples of 100 strictly after `position`, up to and including where it stops.
+        passes += if left {
+            (position - 1).div_euclid(100) - (position - distance - 1).div_euclid(100)
+        } else {
+            (position + distance).div_euclid(100)
+        };
+        position = (position + if left { -distance } else { distance }).rem_euclid(100);
+        landings += u64::from(position == 0);
+        let _ = writeln!(input, "{}{distance}", if left { 'L' } else { 'R' });
+    }
+
+    (input, both(landings as usize, passes))
+}
+
+/// Day 2: comma-separated ranges of up to 100 000 IDs each, of 1 to 10 digits.
+///
+/// The answers enumerate the repeated-pattern IDs directly: for a pattern of `p` digits
+/// repeated to `len` digits they are the multiples of `(10^len - 1) / (10^p - 1)`.
+fn id_ranges(rng: &mut Rng, size: usize) -> (String, Vec<(Part, Answer)>) {
+    let mut ranges = Vec::new();
+    let (mut doubled, mut repeated) = (0u64, 0u64);
+
+    for _ in 0..size {
+        let len = rng.range(1..=10) as u32;
+        let low = if len == 1 { 1 } else { 10u64.pow(len - 1) };
+        let start = low + rng.below(10u64.pow(len) - low);
+        let end = start + rng.below(100_000);
+        ranges.push(format!("{start}-{end}"));
+
+        let (range_doubled, range_repeated) = repeated_ids(start, end);
+        doubled += range_doubled;
+        repeated += range_repeated;
+    }
+
+    (format!("{}\n", ranges.join(",")), both(doubled, repeated))
+}
+
+/// Sums of the IDs in `start..=end` made of a pattern repeated exactly twice, and at least
+/// twice.
+fn repeated_ids(start: u64, end: u64) -> (u64, u64) {
+    let mut doubled = 0;
+    let mut ids = Vec::new();
+    for len in digits(start)..=digits(end) {
+        for p in (1..len).filter(|&p| len.is_multiple_of(p)) {
+            let unit = (10u64.pow(len) - 1) / (10u64.pow(p) - 1);
+            let first = start.div_ceil(unit).max(10u64.pow(p - 1));
+            let last = (end / unit).min(10u64.pow(p) - 1);
+            for pattern in first..=last {
+                if p * 2 == len {
+                    doubled += pattern * unit;
+                }
+                ids.push(pattern * unit);
+            }
+        }
+    }
+    ids.sort_unstable();
+    ids.dedup();
+    (doubled, ids.iter().sum())
+}
+
+fn digits(n: u64) -> u32 {
+    n.checked_ilog10().unwrap_or(0) + 1
+}
+
+/// Day 6: four rows of 1-3 digit numbers over an operator row, each problem aligned left or
+/// right within its columns.
+fn worksheet(rng: &mut Rng, size: usize) -> (String, Vec<(Part, Answer)>) {
+    const ROWS: usize = 4;
+    let mut lines = vec![Vec::new(); ROWS + 1];
+    let (mut rows_total, mut columns_total) = (0u64, 0u64);
+
+    for _ in 0..size {
+        let width = rng.range(1..=3) as usize;
+        let numbers: Vec<String> = (0..ROWS)
+            .map(|row| {
+                let len = if row == 0 {
+                    width
+                } else {
+                    rng.range(1..=width as i64) as usize
+                };
+                let mut number = ((b'1' + rng.below(9) as u8) as char).to_string();
+                for _ in 1..len {
+                    number.push((b'0' + rng.below(10) as u8) as char);
+                }
+                number
+            })
+            .collect();
+        let right = rng.bool();
+        let cells: Vec<String> = numbers
+            .iter()
+            .map(|n| {
+                if right {
+                    format!("{n:>width$}")
+                } else {
+                    format!("{n:<width$}")
+                }
+            })
+            .collect();
+        let multiply = rng.bool();
+        let fold = |values: &mut dyn Iterator<Item = u64>| -> u64 {
+            if multiply {
+                values.product()
+            } else {
+                values.sum()
+            }
+        };
+
+        rows_total += fold(&mut numbers.iter().map(|n| n.parse::<u64>().unwrap()));
+        columns_total += fold(&mut (0..width).map(|col| {
+            let column: String = cells
+                .iter()
+                .map(|cell| cell.as_bytes()[col] as char)
+                .filter(char::is_ascii_digit)
+                .collect();
+            column.parse::<u64>().unwrap()
+        }));
+
+        for (line, cell) in lines.iter_mut().zip(cells) {
+            line.push(cell);
+        }
+        let operator = if multiply { '*' } else { '+' };
+        lines[ROWS].push(format!("{operator:<width$}"));
+    }
+
+    let input = lines.iter().map(|line| line.join(" ") + "\n").collect();
+    (input, both(rows_total, columns_total))
+}
+
+/// Day 7: `size` splitter rows under `S`, each preceded by an empty row, with splitters on the
+/// widening triangle of columns the splits fan out to.
+fn manifold(rng: &mut Rng, size: usize) -> (String, Vec<(Part, Answer)>) {
+    let width = 2 * size + 3;
+    let center = size + 1;
+    let row = |cells: &[u8]| String::from_utf8(cells.to_vec()).unwrap() + "\n";
+
+    let mut start = vec![b'.'; width];
+    start[center] = b'S';
+    let mut input = row(&start);
+    let mut timelines = vec![0u64; width];
+    timelines[center] = 1;
+    let mut splits = 0usize;
+
+    for i in 0..size {
+        input += &row(&vec![b'.'; width]);
+        let mut cells = vec![b'.'; width];
+        let mut next = vec![0u64; width];
+        let mut total: u64 = timelines.iter().sum();
+        for col in 0..width {
+            let in_triangle = col.abs_diff(center) <= i && (col + i - center).is_multiple_of(2);
+            // Splitting `count` timelines adds `count` more.
+            let count = timelines[col];
+            if in_triangle && rng.below(5) < 3 && total + count <= PATH_LIMIT {
+                cells[col] = b'^';
+                total += count;
+                splits += usize::from(count > 0);
+                next[col - 1] += count;
+                next[col + 1] += count;
+            } else {
+                next[col] += count;
+            }
+        }
+        input += &row(&cells);
+        timelines = next;
+    }
+    input += &row(&vec![b'.'; width]);
+
+    (input, both(splits, timelines.iter().sum::<u64>()))
+}
+
+/// A simple rectilinear loop of red tiles: `columns` columns at most `max_step` apart, whose
+/// vertical extents within `0..=2 * max_height` overlap their neighbours', traced along the
+/// tops and back along the bottoms, then maybe transposed, reversed and rotated.
+pub fn rectilinear_loop(
+    rng: &mut Rng,
+    columns: usize,
+    max_step: i64,
+    max_height: i64,
+) -> Vec<(i64, i64)> {
+    let mut xs = vec![rng.range(0..=max_step - 1)];
+    for _ in 0..columns {
+        xs.push(xs.last().unwrap() + rng.range(1..=max_step));
+    }
+    let mut spans: Vec<(i64, i64)> = Vec::new();
+    for _ in 0..columns {
+        let (bottom, top) = loop {
+            let bottom = rng.range(0..=max_height);
+            let top = bottom + rng.range(1..=max_height);
+            match spans.last() {
+                Some(&(b, t)) if bottom >= t || top <= b => continue,
+                _ => break (bottom, top),
+            }
+        };
+        spans.push((bottom, top));
+    }
+
+    let mut tiles = vec![(xs[0], spans[0].0)];
+    for (i, &(_, top)) in spans.iter().enumerate() {
+        tiles.extend([(xs[i], top), (xs[i + 1], top)]);
+    }
+    for (i, &(bottom, _)) in spans.iter().enumerate().rev() {
+        tiles.extend([(xs[i + 1], bottom), (xs[i], bottom)]);
+    }
+    tiles.dedup();
+    if tiles.first() == tiles.last() {
+        tiles.pop();
+    }
+
+    if rng.bool() {
+        tiles.iter_mut().for_each(|p| *p = (p.1, p.0));
+    }
+    if rng.bool() {
+        tiles.reverse();
+    }
+    let start = rng.below(tiles.len() as u64) as usize;
+    tiles.rotate_left(start);
+    tiles
+}
+
+/// Day 10: machines of 3-10 lights whose light pattern and joltage targets are reachable by
+/// construction; the minimum presses are left to the solver.
+fn machines(rng: &mut Rng, size: usize) -> (String, Vec<(Part, Answer)>) {
+    let mut input = String::new();
+    for _ in 0..size {
+        let lights = rng.range(3..=10) as usize;
+        let buttons: Vec<Vec<usize>> = (0..rng.range(lights as i64 - 2..=lights as i64 + 1))
+            .map(|_| {
+                let mut wiring: Vec<usize> = (0..rng.range(1..=5))
+                    .map(|_| rng.below(lights as u64) as usize)
+                    .collect();
+                wiring.sort_unstable();
+                wiring.dedup();
+                wiring
+            })
+            .collect();
+
+        let mut pattern = vec![false; lights];
+        let mut joltage = vec![0u64; lights];
+        for wiring in &buttons {
+            let toggled = rng.bool();
+            let presses = rng.below(11);
+            for &light in wiring {
+                pattern[light] ^= toggled;
+                joltage[light] += presses;
+            }
+        }
+
+        let pattern: String = pattern
+            .iter()
+            .map(|&on| if on { '#' } else { '.' })
+            .collect();
+        let buttons: Vec<String> = buttons
+            .iter()
+            .map(|wiring| format!("({})", join(wiring, ",")))
+            .collect();
+        let _ = writeln!(
+            input,
+            "[{pattern}] {} {{{}}}",
+            buttons.join(" "),
+            join(&joltage, ",")
+        );
+    }
+    (input, vec![])
+}
+
+fn join<T: ToString>(items: &[T], separator: &str) -> String {
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Day 11: a DAG of `size` devices plus `you`, `svr`, `dac`, `fft` and `out`, in shuffled
+/// lines.
+///
+/// Devices are laid out in topological order and only link forward, so path counts follow
+/// from one backwards pass per target. Links that would push a count from `svr` past
+/// [`PATH_LIMIT`] are dropped, except for a spine `svr` → `dac`/`fft` → `out` that keeps
+/// part 2 above 0 at any size. Names have three letters, or four once three run out.
+fn devices(rng: &mut Rng, size: usize) -> (String, Vec<(Part, Answer)>) {
+    const SPECIAL: [&str; 5] = ["you", "svr", "dac", "fft", "out"];
+    let letters = if size <= 26usize.pow(3) - SPECIAL.len() {
+        3
+    } else {
+        4
+    };
+    let mut seen = HashSet::new();
+    let mut names: Vec<String> = Vec::new();
+    while names.len() < size {
+        let name: String = (0..letters)
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect();
+        if !SPECIAL.contains(&name.as_str()) && seen.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    let (first, second) = if rng.bool() {
+        ("dac", "fft")
+    } else {
+        ("fft", "dac")
+    };
+    let at =
+        |rng: &mut Rng, from: usize, to: usize| from + rng.below((to - from) as u64 + 1) as usize;
+    let third = names.len() / 3;
+    let you = at(rng, 0, third);
+    names.insert(you, "you".to_string());
+    let first_at = at(rng, third + 1, 2 * third + 1);
+    names.insert(first_at, first.to_string());
+    let second_at = at(rng, first_at + 1, names.len());
+    names.insert(second_at, second.to_string());
+    names.insert(0, "svr".to_string());
+    names.push("out".to_string());
+
+    // `paths[i]` counts the paths from device `i` to `out`.
+    let n = names.len();
+    let mut links: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let (first_at, second_at) = (first_at + 1, second_at + 1);
+    links[0].push(first_at);
+    links[first_at].push(second_at);
+    links[second_at].push(n - 1);
+    let mut paths = vec![0u64; n];
+    paths[n - 1] = 1;
+    for i in (0..n - 1).rev() {
+        paths[i] = links[i].iter().map(|&j| paths[j]).sum();
+        for _ in 0..rng.range(1..=3) {
+            let j = (i + 1 + rng.below(8) as usize).min(n - 1);
+            if !links[i].contains(&j) && (links[i].is_empty() || paths[i] + paths[j] <= PATH_LIMIT)
+            {
+                links[i].push(j);
+                paths[i] += paths[j];
+            }
+        }
+    }
+
+    let count_to = |target: usize| {
+        let mut counts = vec![0u64; n];
+        counts[target] = 1;
+        for i in (0..target).rev() {
+            counts[i] = links[i].iter().map(|&j| counts[j]).sum();
+        }
+        counts
+    };
+    let index = |name: &str| names.iter().position(|n| n == name).unwrap();
+    let (svr, dac, fft) = (index("svr"), index("dac"), index("fft"));
+    let (to_dac, to_fft) = (count_to(dac), count_to(fft));
+    let via_both = to_dac[svr] * to_fft[dac] * paths[fft] + to_fft[svr] * to_dac[fft] * paths[dac];
+
+    let mut lines: Vec<String> = (0..n - 1)
+        .map(|i| {
+            let outputs: Vec<&str> = links[i].iter().map(|&j| names[j].as_str()).collect();
+            format!("{}: {}\n", names[i], outputs.join(" "))
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+
+    (lines.concat(), both(paths[index("you")], via_both))
+}
+
+/// Day 12: six shapes of 5-7 cells, then regions that either clearly fit (one present per
+/// 3x3 block) or clearly do not (more cells than area).
+fn regions(rng: &mut Rng, size: usize) -> (String, Vec<(Part, Answer)>) {
+    let mut input = String::new();
+    let mut sizes = Vec::new();
+    for index in 0..6 {
+        let cells = rng.range(5..=7) as usize;
+        let mut grid = [b'.'; 9];
+        let mut slots: Vec<usize> = (0..9).collect();
+        rng.shuffle(&mut slots);
+        for &slot in &slots[..cells] {
+            grid[slot] = b'#';
+        }
+        let _ = writeln!(input, "{index}:");
+        for row in grid.chunks(3) {
+            let _ = writeln!(input, "{}", String::from_utf8_lossy(row));
+        }
+        input.push('\n');
+        sizes.push(cells);
+    }
+
+    let mut fitting = 0usize;
+    for _ in 0..size {
+        let (width, height) = (rng.range(4..=50) as usize, rng.range(4..=50) as usize);
+        let mut counts = [0usize; 6];
+        if rng.bool() {
+            fitting += 1;
+            let blocks = (width / 3) * (height / 3);
+            for _ in 0..rng.range(blocks as i64 / 2..=blocks as i64) {
+                counts[rng.below(6) as usize] += 1;
+            }
+        } else {
+            let mut cells = 0;
+            while cells <= width * height {
+                let shape = rng.below(6) as usize;
+                counts[shape] += 1;
+                cells += sizes[shape];
+            }
+        }
+        let _ = writeln!(input, "{width}x{height}: {}", join(&counts, " "));
+    }
+
+    (input, vec![(Part::One, fitting.into())])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    /// Solve `generated` and compare with every known answer.
+    fn check(day: u8, generated: &Generated) {
+        let parts = [Part::One, Part::Two];
+        let solved = registry::find(day)
+            .unwrap()
+            .run(&generated.input, &parts)
+            .unwrap_or_else(|error| panic!("{}", error.render()));
+        for (part, expected) in &generated.answers {
+            let (_, answer) = solved.iter().find(|(p, _)| p == part).unwrap();
+            assert_eq!(answer, expected, "day {day} part {part}");
+        }
+    }
+
+    #[test]
+    fn test_generated_inputs_match_solvers() {
+        for day in DAYS {
+            for seed in 0..5 {
+                for size in [1, 2, 10] {
+                    check(day, &generate(day, seed, size).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_sizes_match_solvers() {
+        for day in DAYS {
+            let (_, size) = size_of(day).unwrap();
+            check(day, &generate(day, 2025, size).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_known_answers() {
+        let known = |day| generate(day, 1, 10).unwrap().answers.len();
+        assert_eq!(DAYS.map(known), [2, 2, 2, 2, 0, 0, 2, 1]);
+    }
+
+    #[test]
+    fn test_devices_at_scale() {
+        // Part 2 needs a path from `svr` through `dac` and `fft` at any size.
+        for size in [2000, 5000] {
+            let generated = generate(11, 7, size).unwrap();
+            check(11, &generated);
+            let (_, part2) = generated
+                .answers
+                .iter()
+                .find(|(p, _)| *p == Part::Two)
+                .unwrap();
+            assert_ne!(*part2, Answer::from(0u64), "size {size}");
+        }
+        // Past the three-letter names, names get longer instead of running out.
+        let generated = generate(11, 7, 18_000).unwrap();
+        assert_eq!(generated.input.lines().count(), 18_004);
+        assert!(generated
+            .input
+            .lines()
+            .any(|line| line.find(':') == Some(4)));
+    }
+
+    #[test]
+    fn test_deterministic() {
+        assert_eq!(generate(7, 42, 20), generate(7, 42, 20));
+        assert_ne!(generate(7, 42, 20), generate(7, 43, 20));
+    }
+
+    #[test]
+    fn test_sizes() {
+        assert_eq!(generate(1, 0, 25).unwrap().input.lines().count(), 25);
+        assert_eq!(generate(2, 0, 4).unwrap().input.matches(',').count(), 3);
+        assert_eq!(
+            generate(6, 0, 8)
+                .unwrap()
+                .input
+                .lines()
+                .last()
+                .unwrap()
+                .split_whitespace()
+                .count(),
+            8
+        );
+        assert_eq!(generate(7, 0, 3).unwrap().input.lines().count(), 8);
+        assert_eq!(generate(10, 0, 6).unwrap().input.lines().count(), 6);
+        assert_eq!(generate(11, 0, 30).unwrap().input.lines().count(), 34);
+        assert_eq!(
+            generate(12, 0, 9)
+                .unwrap()
+                .input
+                .lines()
+                .filter(|l| l.contains('x'))
+                .count(),
+            9
+        );
+    }
+
+    #[test]
+    fn test_repeated_ids() {
+        // From the puzzle example.
+        assert_eq!(repeated_ids(11, 22), (33, 33));
+        assert_eq!(repeated_ids(95, 115), (99, 99 + 111));
+        assert_eq!(repeated_ids(998, 1012), (1010, 999 + 1010));
+        assert_eq!(repeated_ids(1698522, 1698528), (0, 0));
+        assert_eq!(repeated_ids(1, 9), (0, 0));
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digits(0), 1);
+        assert_eq!(digits(9), 1);
+        assert_eq!(digits(10), 2);
+        assert_eq!(digits(9_999_999_999), 10);
+    }
+
+    #[test]
+    fn test_rectilinear_loop() {
+        let mut rng = Rng::new(3);
+        for columns in 1..20 {
+            let tiles = rectilinear_loop(&mut rng, columns, 5, 10);
+            // Every step is horizontal or vertical, including the closing one.
+            for (i, a) in tiles.iter().enumerate() {
+                let b = tiles[(i + 1) % tiles.len()];
+                assert!((a.0 == b.0) != (a.1 == b.1), "{a:?} -> {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_day() {
+        assert_eq!(
+            generate(3, 0, 10),
+            Err("no generator for day 3".to_string())
+        );
+        assert_eq!(size_of(3), None);
+        assert_eq!(generate(1, 0, 0), Err("size must be positive".to_string()));
+    }
+}
@@ -0,0 +1,332 @@
+//! Local record of submitted answers, used to refuse submissions the site already ruled out.
+
+use std::path::{Path, PathBuf};
+
+use crate::client::{self, Outcome};
+use crate::json::{self, Value};
+use crate::solution::Part;
+
+/// `.aoc/history.json`.
+pub fn default_path() -> PathBuf {
+    client::state_dir().join("history.json")
+}
+
+/// One submission and the site's verdict.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Read `path`; a missing file is an empty history.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::from_json(&text)
+                .map_err(|error| format!("invalid history {}: {error}", path.display())),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("failed to read {}: {error}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let error = |e: std::io::Error| format!("failed to write {}: {e}", path.display());
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(error)?;
+        }
+        std::fs::write(path, self.to_json()).map_err(error)
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    /// Explain why submitting `answer` at `now` would be pointless, if it would be.
+    ///
+    /// Refused: a part already solved, a pending wait, a repeated answer, and integers on
+    /// the wrong side of an earlier "too high" or "too low".
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Result<(), String> {
+        let value = answer.parse::<i128>().ok();
+        for attempt in self
+            .attempts
+            .iter()
+            .filter(|a| a.day == day && a.part == part)
+        {
+            let bound = attempt.answer.parse::<i128>().ok();
+            match attempt.outcome {
+                Outcome::Correct => {
+                    return Err(format!(
+                        "day {day:02} part {part} was already solved with {}",
+                        attempt.answer
+                    ))
+                }
+                Outcome::AlreadySolved => {
+                    return Err(format!("day {day:02} part {part} was already solved"))
+                }
+                Outcome::Wait { seconds } => {
+                    let until = attempt.at + seconds;
+                    if now < until {
+                        return Err(format!("submitted too recently, wait {}s", until - now));
+                    }
+                }
+                outcome if attempt.answer == answer => {
+                    return Err(format!("{answer} was already submitted: {outcome}"))
+                }
+                Outcome::TooHigh if value.zip(bound).is_some_and(|(v, b)| v >= b) => {
+                    return Err(format!(
+                        "{answer} is not below {}, which was too high",
+                        attempt.answer
+                    ))
+                }
+                Outcome::TooLow if value.zip(bound).is_some_and(|(v, b)| v <= b) => {
+                    return Err(format!(
+                        "{answer} is not above {}, which was too low",
+                        attempt.answer
+                    ))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// A JSON array of `{"day","part","answer","outcome","at"}` objects, plus
+    /// `"wait_seconds"` for waits.
+    pub fn to_json(&self) -> String {
+        let attempts = self
+            .attempts
+            .iter()
+            .map(|attempt| {
+                let mut fields = vec![
+                    ("day".to_string(), Value::from(attempt.day as u64)),
+                    (
+                        "part".to_string(),
+                        Value::from(attempt.part.number() as u64),
+                    ),
+                    ("answer".to_string(), Value::from(attempt.answer.as_str())),
+                    ("outcome".to_string(), Value::from(attempt.outcome.name())),
+                    ("at".to_string(), Value::from(attempt.at)),
+                ];
+                if let Outcome::Wait { seconds } = attempt.outcome {
+                    fields.push(("wait_seconds".to_string(), Value::from(seconds)));
+                }
+                Value::Object(fields)
+            })
+            .collect();
+        format!("{}\n", Value::Array(attempts))
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, String> {
+        let document = json::parse(text)?;
+        let entries = document.as_array().ok_or("expected an array of attempts")?;
+
+        let attempts = entries
+            .iter()
+            .map(|entry| {
+                let number = |key: &str| {
+                    entry
+                        .get(key)
+                        .and_then(Value::as_f64)
+                        .ok_or_else(|| format!("attempt is missing `{key}`"))
+                };
+                let text = |key: &str| {
+                    entry
+                        .get(key)
+                        .and_then(Value::as_str)
+                        .ok_or_else(|| format!("attempt is missing `{key}`"))
+                };
+
+                let part = Part::from_number(number("part")? as u8)
+                    .ok_or("attempt has an invalid `part`")?;
+                let wait = entry.get("wait_seconds").and_then(Value::as_f64);
+                let outcome = text("outcome")?;
+                let outcome = Outcome::from_name(outcome, wait.unwrap_or(0.0) as u64)
+                    .ok_or_else(|| format!("unknown outcome `{outcome}`"))?;
+
+                Ok(Attempt {
+                    day: number("day")? as u8,
+                    part,
+                    answer: text("answer")?.to_string(),
+                    outcome,
+                    at: number("at")? as u64,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self { attempts })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::temp::Temp;
+
+    use super::*;
+
+    fn attempt(part: Part, answer: &str, outcome: Outcome, at: u64) -> Attempt {
+        Attempt {
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            at,
+        }
+    }
+
+    fn history(attempts: Vec<Attempt>) -> History {
+        History { attempts }
+    }
+
+    #[test]
+    fn test_check_bounds() {
+        let history = history(vec![
+            attempt(Part::One, "500", Outcome::TooHigh, 0),
+            attempt(Part::One, "100", Outcome::TooLow, 0),
+        ]);
+        assert_eq!(history.check(1, Part::One, "250", 10), Ok(()));
+        assert_eq!(
+            history.check(1, Part::One, "500", 10),
+            Err("500 was already submitted: too high".to_string())
+        );
+        assert_eq!(
+            history.check(1, Part::One, "501", 10),
+            Err("501 is not below 500, which was too high".to_string())
+        );
+        assert_eq!(
+            history.check(1, Part::One, "-3", 10),
+            Err("-3 is not above 100, which was too low".to_string())
+        );
+        // Bounds are per day and part.
+        assert_eq!(history.check(1, Part::Two, "9999", 10), Ok(()));
+        assert_eq!(history.check(2, Part::One, "9999", 10), Ok(()));
+    }
+
+    #[test]
+    fn test_check_text_answers() {
+        let history = history(vec![attempt(Part::One, "abc", Outcome::TooHigh, 0)]);
+        assert_eq!(history.check(1, Part::One, "zzz", 0), Ok(()));
+        assert_eq!(history.check(1, Part::One, "1", 0), Ok(()));
+        assert!(history.check(1, Part::One, "abc", 0).is_err());
+    }
+
+    #[test]
+    fn test_check_wrong_answer_repeated() {
+        let history = history(vec![attempt(Part::Two, "42", Outcome::Wrong, 0)]);
+        assert_eq!(
+            history.check(1, Part::Two, "42", 0),
+            Err("42 was already submitted: wrong".to_string())
+        );
+        assert_eq!(history.check(1, Part::Two, "43", 0), Ok(()));
+    }
+
+    #[test]
+    fn test_check_solved() {
+        let history = history(vec![
+            attempt(Part::One, "7", Outcome::Correct, 0),
+            attempt(Part::Two, "8", Outcome::AlreadySolved, 0),
+        ]);
+        assert_eq!(
+            history.check(1, Part::One, "9", 0),
+            Err("day 01 part 1 was already solved with 7".to_string())
+        );
+        assert_eq!(
+            history.check(1, Part::Two, "9", 0),
+            Err("day 01 part 2 was already solved".to_string())
+        );
+    }
+
+    #[test]
+    fn test_check_wait() {
+        let history = history(vec![attempt(
+            Part::One,
+            "5",
+            Outcome::Wait { seconds: 60 },
+            1000,
+        )]);
+        assert_eq!(
+            history.check(1, Part::One, "6", 1030),
+            Err("submitted too recently, wait 30s".to_string())
+        );
+        assert_eq!(history.check(1, Part::One, "6", 1060), Ok(()));
+        // A wait never checked the answer, so the same one may be sent again.
+        assert_eq!(history.check(1, Part::One, "5", 1060), Ok(()));
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let history = history(vec![
+            attempt(Part::One, "500", Outcome::TooHigh, 1_765_000_000),
+            attempt(
+                Part::Two,
+                "a \"b\"",
+                Outcome::Wait { seconds: 279 },
+                1_765_000_100,
+            ),
+        ]);
+        let text = history.to_json();
+        assert_eq!(
+            text.lines().next().unwrap(),
+            concat!(
+                r#"[{"day":1,"part":1,"answer":"500","outcome":"too_high","at":1765000000},"#,
+                r#"{"day":1,"part":2,"answer":"a \"b\"","outcome":"wait","at":1765000100,"wait_seconds":279}]"#
+            )
+        );
+        assert_eq!(History::from_json(&text), Ok(history));
+    }
+
+    #[test]
+    fn test_from_json_errors() {
+        assert_eq!(
+            History::from_json("{}"),
+            Err("expected an array of attempts".to_string())
+        );
+        assert_eq!(
+            History::from_json(r#"[{"part":1,"answer":"1","outcome":"wrong","at":0}]"#),
+            Err("attempt is missing `day`".to_string())
+        );
+        assert_eq!(
+            History::from_json(r#"[{"day":1,"part":3,"answer":"1","outcome":"wrong","at":0}]"#),
+            Err("attempt has an invalid `part`".to_string())
+        );
+        assert_eq!(
+            History::from_json(r#"[{"day":1,"part":1,"answer":"1","outcome":"meh","at":0}]"#),
+            Err("unknown outcome `meh`".to_string())
+        );
+        assert!(History::from_json("[").is_err());
+    }
+
+    #[test]
+    fn test_load_and_save() {
+        let dir = Temp::path("history");
+        let path = dir.join("history.json");
+        assert_eq!(History::load(&path), Ok(History::default()));
+
+        let mut saved = History::default();
+        saved.record(attempt(Part::One, "3", Outcome::Correct, 5));
+        saved.save(&path).unwrap();
+        assert_eq!(History::load(&path), Ok(saved));
+
+        std::fs::write(&path, "[1]").unwrap();
+        assert!(History::load(&path)
+            .unwrap_err()
+            .starts_with("invalid history "));
+    }
+
+    #[test]
+    fn test_default_path() {
+        assert!(default_path().ends_with(".aoc/history.json"));
+    }
+}
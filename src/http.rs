@@ -0,0 +1,423 @@
+//! Minimal HTTP client: plain `http://` over `TcpStream`, `https://` through the system `curl`.
+//!
+//! Everything goes through the [`Transport`] trait so callers can be pointed at a local
+//! stand-in server or a mock.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: &'static str,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// Form-encoded body for `POST`.
+    pub body: Option<String>,
+}
+
+impl Request {
+    pub fn get(url: impl Into<String>) -> Self {
+        Self {
+            method: "GET",
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub fn post_form(url: impl Into<String>, fields: &[(&str, &str)]) -> Self {
+        let body = fields
+            .iter()
+            .map(|(key, value)| format!("{}={}", form_encode(key), form_encode(value)))
+            .collect::<Vec<_>>()
+            .join("&");
+        Self {
+            method: "POST",
+            url: url.into(),
+            headers: vec![(
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            )],
+            body: Some(body),
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.push((name.to_string(), value.into()));
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends a request and returns the response, or a printable error.
+pub trait Transport {
+    fn send(&self, request: &Request) -> Result<Response, String>;
+}
+
+/// The real network: `http://` over a raw socket, `https://` via `curl`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Http;
+
+impl Transport for Http {
+    fn send(&self, request: &Request) -> Result<Response, String> {
+        if request.url.starts_with("http://") {
+            send_plain(request)
+        } else if request.url.starts_with("https://") {
+            send_curl(request)
+        } else {
+            Err(format!("unsupported URL: {}", request.url))
+        }
+    }
+}
+
+/// Percent-encode a form field.
+fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            b' ' => "+".to_string(),
+            b => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// Split `http://host:port/path` into the `host:port` authority and the path.
+fn split_url(url: &str) -> Result<(&str, &str), String> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| format!("unsupported URL: {url}"))?;
+    Ok(match rest.find('/') {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, "/"),
+    })
+}
+
+fn send_plain(request: &Request) -> Result<Response, String> {
+    let (authority, path) = split_url(&request.url)?;
+    let address = if authority.contains(':') {
+        authority.to_string()
+    } else {
+        format!("{authority}:80")
+    };
+    let error = |e: std::io::Error| format!("request to {} failed: {e}", request.url);
+
+    let mut stream = TcpStream::connect(&address).map_err(error)?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(error)?;
+
+    let mut head = format!(
+        "{} {path} HTTP/1.1\r\nHost: {authority}\r\nConnection: close\r\n",
+        request.method
+    );
+    for (name, value) in &request.headers {
+        head += &format!("{name}: {value}\r\n");
+    }
+    if let Some(body) = &request.body {
+        head += &format!("Content-Length: {}\r\n", body.len());
+    }
+    head += "\r\n";
+    stream.write_all(head.as_bytes()).map_err(error)?;
+    if let Some(body) = &request.body {
+        stream.write_all(body.as_bytes()).map_err(error)?;
+    }
+
+    read_response(BufReader::new(stream)).map_err(|e| format!("{}: {e}", request.url))
+}
+
+/// Parse an HTTP/1.1 response with a `Content-Length`, chunked or connection-delimited body.
+fn read_response(mut reader: impl BufRead) -> Result<Response, String> {
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(|e| e.to_string())?;
+    let status = line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| format!("invalid status line `{}`", line.trim_end()))?;
+
+    let mut length = None;
+    let mut chunked = false;
+    loop {
+        line.clear();
+        reader.read_line(&mut line).map_err(|e| e.to_string())?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.parse::<usize>().ok();
+            } else if name.eq_ignore_ascii_case("transfer-encoding") {
+                chunked = value.eq_ignore_ascii_case("chunked");
+            }
+        }
+    }
+
+    let mut body = Vec::new();
+    if chunked {
+        loop {
+            line.clear();
+            reader.read_line(&mut line).map_err(|e| e.to_string())?;
+            let size = usize::from_str_radix(line.trim(), 16)
+                .map_err(|_| format!("invalid chunk size `{}`", line.trim()))?;
+            if size == 0 {
+                break;
+            }
+            let mut chunk = vec![0; size + 2];
+            reader.read_exact(&mut chunk).map_err(|e| e.to_string())?;
+            body.extend_from_slice(&chunk[..size]);
+        }
+    } else if let Some(length) = length {
+        body.resize(length, 0);
+        reader.read_exact(&mut body).map_err(|e| e.to_string())?;
+    } else {
+        reader.read_to_end(&mut body).map_err(|e| e.to_string())?;
+    }
+
+    let body = String::from_utf8(body).map_err(|_| "response body is not UTF-8".to_string())?;
+    Ok(Response { status, body })
+}
+
+/// Options are passed on stdin so the session cookie never shows up in the process list.
+fn send_curl(request: &Request) -> Result<Response, String> {
+    let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+    let mut config = format!(
+        "url = {}\nrequest = {}\nsilent\nshow-error\nmax-time = {}\nwrite-out = \"\\n%{{http_code}}\"\n",
+        quote(&request.url),
+        request.method,
+        TIMEOUT.as_secs()
+    );
+    for (name, value) in &request.headers {
+        config += &format!("header = {}\n", quote(&format!("{name}: {value}")));
+    }
+    if let Some(body) = &request.body {
+        config += &format!("data-raw = {}\n", quote(body));
+    }
+
+    let mut child = Command::new("curl")
+        .args(["--config", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to run curl (required for https): {e}"))?;
+    child
+        .stdin
+        .take()
+        .expect("piped stdin")
+        .write_all(config.as_bytes())
+        .map_err(|e| format!("failed to configure curl: {e}"))?;
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("curl failed: {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "request to {} failed: {}",
+            request.url,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    let stdout =
+        String::from_utf8(output.stdout).map_err(|_| "response body is not UTF-8".to_string())?;
+    let (body, status) = stdout
+        .rsplit_once('\n')
+        .ok_or("curl did not report a status code")?;
+    let status = status
+        .parse()
+        .map_err(|_| format!("invalid status code `{status}`"))?;
+    Ok(Response {
+        status,
+        body: body.to_string(),
+    })
+}
+
+/// A local stand-in server answering each connection with the next canned response.
+///
+/// Returns the base URL and a handle yielding the raw requests it received.
+#[cfg(test)]
+pub(crate) fn stand_in(
+    responses: Vec<(u16, &'static str)>,
+) -> (String, std::thread::JoinHandle<Vec<String>>) {
+    use std::io::Read;
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        let mut requests = Vec::new();
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap();
+                }
+                request += &line;
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut body_bytes = vec![0; length];
+            reader.read_exact(&mut body_bytes).unwrap();
+            request += &String::from_utf8(body_bytes).unwrap();
+            requests.push(request);
+
+            write!(
+                stream,
+                "HTTP/1.1 {status} X\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+        requests
+    });
+    (url, handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_against_stand_in() {
+        let (url, server) = stand_in(vec![(200, "L68\nR10\n")]);
+        let response = Http
+            .send(&Request::get(format!("{url}/2025/day/1/input")).header("Cookie", "session=abc"))
+            .unwrap();
+        assert_eq!(
+            response,
+            Response {
+                status: 200,
+                body: "L68\nR10\n".to_string()
+            }
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2025/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=abc\r\n"));
+    }
+
+    #[test]
+    fn test_post_form_against_stand_in() {
+        let (url, server) = stand_in(vec![(200, "<p>That's the right answer!</p>")]);
+        let request = Request::post_form(
+            format!("{url}/2025/day/1/answer"),
+            &[("level", "1"), ("answer", "a b&c")],
+        );
+        assert_eq!(
+            Http.send(&request).unwrap().body,
+            "<p>That's the right answer!</p>"
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2025/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Content-Type: application/x-www-form-urlencoded\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=1&answer=a+b%26c"));
+    }
+
+    #[test]
+    fn test_send_unsupported_url() {
+        assert_eq!(
+            Http.send(&Request::get("ftp://example.com")),
+            Err("unsupported URL: ftp://example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_send_connection_refused() {
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let error = Http
+            .send(&Request::get(format!("http://127.0.0.1:{port}/x")))
+            .unwrap_err();
+        assert!(error.starts_with("request to http://127.0.0.1:"));
+    }
+
+    #[test]
+    fn test_split_url() {
+        assert_eq!(split_url("http://a:1/b/c"), Ok(("a:1", "/b/c")));
+        assert_eq!(split_url("http://a"), Ok(("a", "/")));
+        assert!(split_url("https://a").is_err());
+    }
+
+    #[test]
+    fn test_form_encode() {
+        assert_eq!(form_encode("Az09-_.~"), "Az09-_.~");
+        assert_eq!(form_encode("a b"), "a+b");
+        assert_eq!(form_encode("=&é"), "%3D%26%C3%A9");
+    }
+
+    #[test]
+    fn test_read_response_content_length() {
+        let raw = "HTTP/1.1 404 Not Found\r\nContent-Length: 3\r\n\r\nabcdef";
+        assert_eq!(
+            read_response(raw.as_bytes()),
+            Ok(Response {
+                status: 404,
+                body: "abc".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_read_response_chunked() {
+        let raw =
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\nde\r\n0\r\n\r\n";
+        assert_eq!(read_response(raw.as_bytes()).unwrap().body, "abcde");
+    }
+
+    #[test]
+    fn test_read_response_until_close() {
+        let raw = "HTTP/1.1 200 OK\r\nServer: x\r\n\r\nrest of body";
+        assert_eq!(read_response(raw.as_bytes()).unwrap().body, "rest of body");
+    }
+
+    #[test]
+    fn test_read_response_errors() {
+        assert_eq!(
+            read_response("garbage\r\n".as_bytes()),
+            Err("invalid status line `garbage`".to_string())
+        );
+        assert!(read_response(
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\nzz\r\n".as_bytes()
+        )
+        .is_err());
+        assert!(
+            read_response("HTTP/1.1 200 OK\r\nContent-Length: 9\r\n\r\nabc".as_bytes()).is_err()
+        );
+        assert_eq!(
+            read_response(&b"HTTP/1.1 200 OK\r\n\r\n\xff"[..]),
+            Err("response body is not UTF-8".to_string())
+        );
+    }
+
+    #[test]
+    fn test_request_builders() {
+        let request = Request::get("http://x/y").header("User-Agent", "me");
+        assert_eq!(request.method, "GET");
+        assert_eq!(
+            request.headers,
+            vec![("User-Agent".to_string(), "me".to_string())]
+        );
+        assert_eq!(request.body, None);
+    }
+}
@@ -0,0 +1,254 @@
+//! Runtime puzzle input loading from the co-located default file, a given path or stdin.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// `src/bin/dayXX/input.txt` inside the project.
+    #[default]
+    Default,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interpret a `--input` argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(PathBuf::from(arg))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound { day: u8, path: PathBuf },
+    Read { origin: String, error: io::Error },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { day, path } => write!(
+                f,
+                "input for day {day:02} not found at {} (use --input <path> or --input - for stdin)",
+                path.display()
+            ),
+            InputError::Read { origin, error } => write!(f, "failed to read {origin}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The co-located input file for `day`, resolved against the project root.
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/bin/day{day:02}/input.txt"))
+}
+
+pub fn load(day: u8, source: &InputSource) -> Result<String, InputError> {
+    match source {
+        InputSource::Default => load_file(day, &default_path(day)),
+        InputSource::Path(path) => load_file(day, path),
+        InputSource::Stdin => read_from(io::stdin().lock(), "stdin"),
+    }
+}
+
+/// Columns between tab stops when expanding tabs.
+pub const TAB_WIDTH: usize = 8;
+
+/// Canonical form of a puzzle input, so every day parses the same text however it was
+/// saved: no byte order mark, `\n` line endings, tabs expanded to [`TAB_WIDTH`] stops,
+/// no trailing whitespace on any line and a single final newline.
+///
+/// Borrows `input` when it is already canonical.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let body = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let is_canonical = body.len() == input.len()
+        && !body.contains(['\r', '\t'])
+        && body.split('\n').all(|line| line.trim_end() == line)
+        && (body.is_empty() || body.ends_with('\n') && !body.ends_with("\n\n"));
+    if is_canonical {
+        return Cow::Borrowed(input);
+    }
+
+    let unified = body.replace("\r\n", "\n").replace('\r', "\n");
+    let mut normalized = String::with_capacity(unified.len() + 1);
+    for line in unified.trim_end().lines() {
+        let start = normalized.len();
+        for c in line.chars() {
+            if c == '\t' {
+                let column = normalized[start..].chars().count();
+                normalized.extend(std::iter::repeat_n(' ', TAB_WIDTH - column % TAB_WIDTH));
+            } else {
+                normalized.push(c);
+            }
+        }
+        normalized.truncate(start + normalized[start..].trim_end().len());
+        normalized.push('\n');
+    }
+    Cow::Owned(normalized)
+}
+
+/// FNV-1a 64-bit hash of `input` as 16 hex digits, to tell inputs apart across machines.
+pub fn checksum(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+fn load_file(day: u8, path: &Path) -> Result<String, InputError> {
+    if !path.is_file() {
+        return Err(InputError::NotFound {
+            day,
+            path: path.to_path_buf(),
+        });
+    }
+    let file = std::fs::File::open(path).map_err(|error| InputError::Read {
+        origin: path.display().to_string(),
+        error,
+    })?;
+    read_from(file, &path.display().to_string())
+}
+
+fn read_from(mut reader: impl Read, origin: &str) -> Result<String, InputError> {
+    let mut input = String::new();
+    reader
+        .read_to_string(&mut input)
+        .map_err(|error| InputError::Read {
+            origin: origin.to_string(),
+            error,
+        })?;
+    match normalize(&input) {
+        Cow::Borrowed(_) => Ok(input),
+        Cow::Owned(normalized) => Ok(normalized),
+    }
+}
+
+/// `input` as saved by a Windows editor: a byte order mark, CRLF line endings and
+/// trailing blank lines.
+#[cfg(test)]
+pub(crate) fn windows(input: &str) -> String {
+    format!("\u{feff}{}\r\n\r\n", input.replace('\n', "\r\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::temp::Temp;
+
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("my/input.txt"),
+            InputSource::Path(PathBuf::from("my/input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_default_path() {
+        let path = default_path(7);
+        assert!(path.ends_with("src/bin/day07/input.txt"));
+        assert!(path.is_absolute());
+    }
+
+    #[test]
+    fn test_load_default() {
+        let input = load(1, &InputSource::default()).unwrap();
+        assert!(input.starts_with('L') || input.starts_with('R'));
+    }
+
+    #[test]
+    fn test_load_path() {
+        let path = Temp::file("input-path.txt", "L68\nR10\n");
+        assert_eq!(
+            load(1, &InputSource::Path(path.to_path_buf())).unwrap(),
+            "L68\nR10\n"
+        );
+    }
+
+    #[test]
+    fn test_load_missing_path() {
+        let error = load(3, &InputSource::from_arg("does/not/exist.txt")).unwrap_err();
+        assert!(matches!(error, InputError::NotFound { day: 3, .. }));
+        assert_eq!(
+            error.to_string(),
+            "input for day 03 not found at does/not/exist.txt (use --input <path> or --input - for stdin)"
+        );
+    }
+
+    #[test]
+    fn test_load_invalid_utf8() {
+        let path = Temp::file("input-binary.txt", [0xff, 0xfe, 0x00]);
+        let error = load(1, &InputSource::Path(path.to_path_buf())).unwrap_err();
+        assert!(matches!(error, InputError::Read { .. }));
+        assert!(error.to_string().starts_with("failed to read "));
+    }
+
+    #[test]
+    fn test_read_from() {
+        assert_eq!(read_from("1,2,3".as_bytes(), "stdin").unwrap(), "1,2,3\n");
+        assert_eq!(
+            read_from("\u{feff}L68\r\nR10\r\n".as_bytes(), "stdin").unwrap(),
+            "L68\nR10\n"
+        );
+    }
+
+    #[test]
+    fn test_normalize_borrows_canonical_input() {
+        assert!(matches!(normalize("L68\nR10\n"), Cow::Borrowed(_)));
+        assert!(matches!(normalize("1 2\n\n3\n"), Cow::Borrowed(_)));
+        assert!(matches!(normalize(""), Cow::Borrowed(_)));
+        assert!(matches!(normalize("L68"), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn test_normalize_line_endings() {
+        assert_eq!(normalize("a\r\nb\r\n"), "a\nb\n");
+        assert_eq!(normalize("a\rb"), "a\nb\n");
+        assert_eq!(normalize("a\r\n\r\nb"), "a\n\nb\n");
+    }
+
+    #[test]
+    fn test_normalize_bom() {
+        assert_eq!(normalize("\u{feff}3-5\n"), "3-5\n");
+        assert_eq!(normalize("\u{feff}"), "");
+    }
+
+    #[test]
+    fn test_normalize_trailing_whitespace() {
+        assert_eq!(normalize("1 \n2\t\n\n\n \n"), "1\n2\n");
+        assert_eq!(normalize("\n\n"), "");
+        assert_eq!(normalize("\n\nx"), "\n\nx\n");
+    }
+
+    #[test]
+    fn test_normalize_tabs() {
+        assert_eq!(normalize("\tx\n"), "        x\n");
+        assert_eq!(normalize("ab\tc\td\n"), "ab      c       d\n");
+        assert_eq!(normalize("é\t1\n"), "é       1\n");
+    }
+
+    #[test]
+    fn test_normalize_idempotent() {
+        let input = windows("123 328\t51\n 45\t64 \n*   +");
+        let once = normalize(&input).into_owned();
+        assert!(matches!(normalize(&once), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(""), "cbf29ce484222325");
+        assert_eq!(checksum("a"), "af63dc4c8601ec8c");
+        assert_ne!(checksum("L68\n"), checksum("L68\r\n"));
+    }
+}
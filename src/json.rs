@@ -0,0 +1,400 @@
+//! Minimal JSON values with a serializer and parser, for baselines and machine-readable output.
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    /// Keys keep their insertion order.
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Look up `key` in an object.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::String(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(n: f64) -> Self {
+        Value::Number(n)
+    }
+}
+
+impl From<u64> for Value {
+    fn from(n: u64) -> Self {
+        Value::Number(n as f64)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Self {
+        Value::Bool(b)
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Number(n) if n.is_finite() => write!(f, "{n}"),
+            Value::Number(_) => write!(f, "null"),
+            Value::String(s) => write_string(f, s),
+            Value::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+            Value::Object(entries) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{c}")?,
+        }
+    }
+    write!(f, "\"")
+}
+
+/// Parse a complete JSON document.
+pub fn parse(text: &str) -> Result<Value, String> {
+    let mut parser = Parser { text, pos: 0 };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.pos < text.len() {
+        return Err(parser.error("end of document"));
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, expected: &str) -> String {
+        match self.text[self.pos..].chars().next() {
+            Some(c) => format!("expected {expected} at byte {}, found `{c}`", self.pos),
+            None => format!(
+                "expected {expected} at byte {}, found end of input",
+                self.pos
+            ),
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\n' | b'\r' | b'\t')) {
+            self.pos += 1;
+        }
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Value) -> Result<Value, String> {
+        if self.text[self.pos..].starts_with(word) {
+            self.pos += word.len();
+            Ok(value)
+        } else {
+            Err(self.error(&format!("`{word}`")))
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'n') => self.literal("null", Value::Null),
+            Some(b't') => self.literal("true", Value::Bool(true)),
+            Some(b'f') => self.literal("false", Value::Bool(false)),
+            Some(b'"') => self.string().map(Value::String),
+            Some(b'[') => self.array(),
+            Some(b'{') => self.object(),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => Err(self.error("a value")),
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let start = self.pos;
+        while matches!(
+            self.peek(),
+            Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+        ) {
+            self.pos += 1;
+        }
+        self.text[start..self.pos]
+            .parse()
+            .map(Value::Number)
+            .map_err(|_| {
+                self.pos = start;
+                self.error("a number")
+            })
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        if !self.eat(b'"') {
+            return Err(self.error("a string"));
+        }
+        let mut out = String::new();
+        loop {
+            let rest = &self.text[self.pos..];
+            let Some(c) = rest.chars().next() else {
+                return Err(self.error("closing `\"`"));
+            };
+            self.pos += c.len_utf8();
+            match c {
+                '"' => return Ok(out),
+                '\\' => out.push(self.escape()?),
+                c => out.push(c),
+            }
+        }
+    }
+
+    fn escape(&mut self) -> Result<char, String> {
+        let c = match self.peek() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'u') => {
+                let hex = self.text.get(self.pos + 1..self.pos + 5);
+                let code = hex.and_then(|h| u32::from_str_radix(h, 16).ok());
+                let c = code
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| self.error("four hex digits"))?;
+                self.pos += 5;
+                return Ok(c);
+            }
+            _ => return Err(self.error("an escape sequence")),
+        };
+        self.pos += 1;
+        Ok(c)
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        self.eat(b'[');
+        let mut items = Vec::new();
+        if self.eat(b']') {
+            return Ok(Value::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            if self.eat(b']') {
+                return Ok(Value::Array(items));
+            }
+            if !self.eat(b',') {
+                return Err(self.error("`,` or `]`"));
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, String> {
+        self.eat(b'{');
+        let mut entries = Vec::new();
+        if self.eat(b'}') {
+            return Ok(Value::Object(entries));
+        }
+        loop {
+            let key = self.string()?;
+            if !self.eat(b':') {
+                return Err(self.error("`:`"));
+            }
+            entries.push((key, self.value()?));
+            if self.eat(b'}') {
+                return Ok(Value::Object(entries));
+            }
+            if !self.eat(b',') {
+                return Err(self.error("`,` or `}`"));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_scalars() {
+        assert_eq!(Value::Null.to_string(), "null");
+        assert_eq!(Value::from(true).to_string(), "true");
+        assert_eq!(Value::from(42u64).to_string(), "42");
+        assert_eq!(Value::from(1.5).to_string(), "1.5");
+        assert_eq!(Value::from(f64::NAN).to_string(), "null");
+    }
+
+    #[test]
+    fn test_display_escapes_strings() {
+        assert_eq!(
+            Value::from("a\"b\\c\nd\u{1}").to_string(),
+            r#""a\"b\\c\nd\u0001""#
+        );
+    }
+
+    #[test]
+    fn test_display_nested() {
+        let value = Value::Object(vec![
+            ("day".to_string(), Value::from(1u64)),
+            (
+                "parts".to_string(),
+                Value::Array(vec![Value::from("a"), Value::Null]),
+            ),
+        ]);
+        assert_eq!(value.to_string(), r#"{"day":1,"parts":["a",null]}"#);
+    }
+
+    #[test]
+    fn test_parse_scalars() {
+        assert_eq!(parse("null"), Ok(Value::Null));
+        assert_eq!(parse(" true "), Ok(Value::Bool(true)));
+        assert_eq!(parse("false"), Ok(Value::Bool(false)));
+        assert_eq!(parse("-12.5e1"), Ok(Value::Number(-125.0)));
+        assert_eq!(parse(r#""hi""#), Ok(Value::from("hi")));
+    }
+
+    #[test]
+    fn test_parse_string_escapes() {
+        assert_eq!(
+            parse(r#""a\"\\\/\n\r\t\b\fé""#),
+            Ok(Value::from("a\"\\/\n\r\t\u{8}\u{c}é"))
+        );
+    }
+
+    #[test]
+    fn test_parse_nested() {
+        let value = parse(r#"{ "a": [1, {"b": null}], "c": "d" }"#).unwrap();
+        assert_eq!(value.get("c").and_then(Value::as_str), Some("d"));
+        let items = value.get("a").and_then(Value::as_array).unwrap();
+        assert_eq!(items[0].as_f64(), Some(1.0));
+        assert_eq!(items[1].get("b"), Some(&Value::Null));
+        assert_eq!(value.get("missing"), None);
+    }
+
+    #[test]
+    fn test_parse_empty_containers() {
+        assert_eq!(parse("[]"), Ok(Value::Array(vec![])));
+        assert_eq!(parse("{ }"), Ok(Value::Object(vec![])));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let text = r#"{"name":"x\ty","values":[1,2.5,-3],"ok":true,"none":null}"#;
+        assert_eq!(parse(text).unwrap().to_string(), text);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse("[1 2]"),
+            Err("expected `,` or `]` at byte 3, found `2`".to_string())
+        );
+        assert_eq!(
+            parse(r#"{"a" 1}"#),
+            Err("expected `:` at byte 5, found `1`".to_string())
+        );
+        assert_eq!(
+            parse(r#""open"#),
+            Err("expected closing `\"` at byte 5, found end of input".to_string())
+        );
+        assert!(parse("tru").is_err());
+        assert!(parse("-").is_err());
+        assert!(parse(r#""\x""#).is_err());
+        assert!(parse(r#""\u12""#).is_err());
+        assert!(parse("{1: 2}").is_err());
+        assert!(parse("{\"a\": 1,}").is_err());
+        assert!(parse("1 2").is_err());
+        assert!(parse("").is_err());
+    }
+
+    #[test]
+    fn test_accessors_on_wrong_types() {
+        assert_eq!(Value::Null.as_f64(), None);
+        assert_eq!(Value::Null.as_str(), None);
+        assert_eq!(Value::Null.as_array(), None);
+        assert_eq!(Value::Null.get("a"), None);
+        assert_eq!(Value::from(String::from("s")), Value::from("s"));
+    }
+}
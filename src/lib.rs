@@ -0,0 +1,43 @@
+//! Advent of Code 2025 solutions.
+
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod dial;
+pub mod error;
+pub mod examples;
+pub mod fuzz;
+pub mod generator;
+pub mod history;
+pub mod http;
+pub mod input;
+pub mod json;
+pub mod limits;
+pub mod lock;
+pub mod memory;
+pub mod output;
+pub mod parallel;
+pub mod params;
+pub mod prop;
+pub mod query;
+pub mod registry;
+pub mod rng;
+pub mod scaffold;
+pub mod shrink;
+pub mod solution;
+#[cfg(test)]
+mod temp;
+pub mod toml;
+pub mod trace;
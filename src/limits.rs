@@ -0,0 +1,189 @@
+//! Per-day and per-part time and memory limits from `limits.toml`, enforced by the runner.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::parallel::Limit;
+use crate::solution::Part;
+use crate::toml::{self, Value};
+
+/// `limits.toml` at the project root.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("limits.toml")
+}
+
+/// A time and/or memory limit given for a whole day or one part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct Override {
+    time: Option<Duration>,
+    memory: Option<usize>,
+}
+
+/// Limits keyed by day, and by part for limits that apply to a single part.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Limits {
+    overrides: BTreeMap<(u8, Option<Part>), Override>,
+}
+
+impl Limits {
+    /// Parse `[dayXX]` sections holding `time` (seconds) and `memory` (MiB) for the whole
+    /// day, or `part1_time`, `part2_memory` and so on for one part.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let document = toml::parse(text)?;
+        let mut overrides: BTreeMap<_, Override> = BTreeMap::new();
+
+        for section in &document.sections {
+            if section.name.is_empty() && section.entries.is_empty() {
+                continue;
+            }
+            let day = section
+                .name
+                .strip_prefix("day")
+                .and_then(|n| n.parse::<u8>().ok())
+                .ok_or_else(|| format!("invalid section `{}`, expected `dayXX`", section.name))?;
+
+            for (key, value) in &section.entries {
+                let (part, name) = match key.split_once('_') {
+                    Some(("part1", name)) => (Some(Part::One), name),
+                    Some(("part2", name)) => (Some(Part::Two), name),
+                    _ => (None, key.as_str()),
+                };
+                let entry = overrides.entry((day, part)).or_default();
+                match name {
+                    "time" => {
+                        let seconds = match value {
+                            Value::Integer(n) => Some(*n as f64),
+                            Value::Float(n) => Some(*n),
+                            _ => None,
+                        };
+                        entry.time = Some(
+                            seconds
+                                .filter(|&seconds| seconds > 0.0)
+                                .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                                .ok_or_else(|| {
+                                    format!("day{day:02}.{key}: expected positive seconds")
+                                })?,
+                        );
+                    }
+                    "memory" => {
+                        let mib = value
+                            .as_integer()
+                            .filter(|&mib| mib > 0)
+                            .and_then(|mib| (mib as usize).checked_mul(1 << 20))
+                            .ok_or_else(|| format!("day{day:02}.{key}: expected positive MiB"))?;
+                        entry.memory = Some(mib);
+                    }
+                    _ => return Err(format!("day{day:02}: unknown key `{key}`")),
+                }
+            }
+        }
+
+        Ok(Self { overrides })
+    }
+
+    /// Load `path`, treating a missing file as no limits.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|error| format!("{}: {error}", path.display())),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("failed to read {}: {error}", path.display())),
+        }
+    }
+
+    pub fn load_default() -> Result<Self, String> {
+        Self::load(&default_path())
+    }
+
+    /// The limit for `part` of `day`: each of time and memory comes from the part's entry,
+    /// else the day's, else `default`.
+    pub fn get(&self, day: u8, part: Part, default: Limit) -> Limit {
+        let find = |key| self.overrides.get(&key).copied().unwrap_or_default();
+        let (part, day) = (find((day, Some(part))), find((day, None)));
+        Limit {
+            time: part.time.or(day.time).unwrap_or(default.time),
+            memory: part.memory.or(day.memory).unwrap_or(default.memory),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::temp::Temp;
+
+    use super::*;
+
+    const DEFAULT: Limit = Limit {
+        time: Duration::from_secs(5),
+        memory: 100 << 20,
+    };
+
+    #[test]
+    fn test_get() {
+        let limits = Limits::parse(
+            "[day10]\ntime = 20\npart2_time = 2.5\npart2_memory = 64\n\n[day11]\nmemory = 8\n",
+        )
+        .unwrap();
+        assert_eq!(
+            limits.get(10, Part::One, DEFAULT),
+            Limit {
+                time: Duration::from_secs(20),
+                memory: 100 << 20
+            }
+        );
+        assert_eq!(
+            limits.get(10, Part::Two, DEFAULT),
+            Limit {
+                time: Duration::from_millis(2500),
+                memory: 64 << 20
+            }
+        );
+        assert_eq!(
+            limits.get(11, Part::Two, DEFAULT),
+            Limit {
+                time: Duration::from_secs(5),
+                memory: 8 << 20
+            }
+        );
+        assert_eq!(limits.get(1, Part::One, DEFAULT), DEFAULT);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for (text, error) in [
+            (
+                "[tenth]\ntime = 1",
+                "invalid section `tenth`, expected `dayXX`",
+            ),
+            ("[day10]\nspeed = 1", "day10: unknown key `speed`"),
+            ("[day10]\npart3_time = 1", "day10: unknown key `part3_time`"),
+            ("[day10]\ntime = 0", "day10.time: expected positive seconds"),
+            (
+                "[day10]\ntime = \"1\"",
+                "day10.time: expected positive seconds",
+            ),
+            (
+                "[day10]\npart1_memory = 1.5",
+                "day10.part1_memory: expected positive MiB",
+            ),
+            (
+                "[day10]\nmemory = -1",
+                "day10.memory: expected positive MiB",
+            ),
+        ] {
+            assert_eq!(Limits::parse(text), Err(error.to_string()), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_load() {
+        assert!(Limits::load_default().is_ok());
+        assert_eq!(
+            Limits::load(Path::new("does/not/exist.toml")),
+            Ok(Limits::default())
+        );
+        let path = Temp::file("limits.toml", "[day01]\ntime = nope\n");
+        let error = Limits::load(&path).unwrap_err();
+        assert!(error.starts_with(&path.display().to_string()));
+    }
+}
@@ -0,0 +1,250 @@
+//! A combination lock of several coupled [`Dial`] wheels that carry like an odometer: each
+//! time a wheel wraps between its top position and 0, the next wheel turns one click the
+//! same way.
+
+use crate::day01;
+use crate::dial::Dial;
+use crate::input::InputSource;
+
+/// The most wheels a lock can have; each is a copy of the dial, rank table included.
+pub const MAX_WHEELS: usize = 64;
+
+/// Wheels in order, each turned by hand or by carries out of the wheel before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lock {
+    wheels: Vec<Dial>,
+    /// Clicks that pointed each wheel at a marker, whether turned by hand or by a carry.
+    zeros: Vec<u64>,
+}
+
+impl Lock {
+    pub fn new(wheels: Vec<Dial>) -> Result<Self, String> {
+        if wheels.is_empty() {
+            return Err("a lock needs at least one wheel".to_string());
+        }
+        let zeros = vec![0; wheels.len()];
+        Ok(Self { wheels, zeros })
+    }
+
+    /// The number of wheels.
+    pub fn len(&self) -> usize {
+        self.wheels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.wheels.is_empty()
+    }
+
+    /// Turn wheel `wheel`, counting from 0, by `distance` clicks, carrying into the wheels
+    /// after it. A carry out of the last wheel is lost.
+    ///
+    /// Panics when the lock has no such wheel.
+    pub fn turn(&mut self, wheel: usize, is_left: bool, distance: u64) {
+        assert!(
+            wheel < self.wheels.len(),
+            "wheel {} is not on a lock of {} wheels",
+            wheel + 1,
+            self.wheels.len()
+        );
+        let mut distance = distance;
+        for (dial, zeros) in self.wheels[wheel..]
+            .iter_mut()
+            .zip(&mut self.zeros[wheel..])
+        {
+            if distance == 0 {
+                break;
+            }
+            let carries = wraps(dial, is_left, distance);
+            *zeros += dial.turn(is_left, distance).hits;
+            // Each wrap is one click of the next wheel.
+            distance = carries;
+        }
+    }
+
+    /// Where each wheel points.
+    pub fn combination(&self) -> Vec<u64> {
+        self.wheels.iter().map(Dial::position).collect()
+    }
+
+    /// Clicks that pointed each wheel at a marker.
+    pub fn zeros(&self) -> &[u64] {
+        &self.zeros
+    }
+}
+
+/// Turn a lock of `wheels` day 1 dials, as set up by `aoc.toml` and `overrides`, through
+/// the moves in `source`; by default it has as many wheels as the moves turn.
+pub fn open(
+    wheels: Option<usize>,
+    source: &InputSource,
+    overrides: &[(String, String)],
+) -> Result<Lock, String> {
+    let (dial, moves) = day01::load_with(source, overrides, day01::parse_lock_moves)?;
+    let wheels = wheels.unwrap_or_else(|| day01::wheels_turned(&moves));
+    day01::run_lock(&moves, &dial, wheels)
+}
+
+/// A line per wheel with its zeros, then the combination.
+pub fn describe(lock: &Lock) -> String {
+    let mut report = String::new();
+    for (wheel, zeros) in lock.zeros().iter().enumerate() {
+        report += &format!("Wheel {}: {zeros} zeros\n", wheel + 1);
+    }
+    let combination: Vec<String> = lock.combination().iter().map(u64::to_string).collect();
+    report += &format!("Combination: {}\n", combination.join("-"));
+    report
+}
+
+/// How often turning `dial` by `distance` clicks crosses between its top position and 0:
+/// arriving at 0 when turning right, leaving it when turning left.
+fn wraps(dial: &Dial, is_left: bool, distance: u64) -> u64 {
+    let (size, position) = (dial.size(), dial.position());
+    // The click that first crosses, counting from 1; every `size` clicks after it cross too.
+    let first = match (is_left, position) {
+        (true, _) => position + 1,
+        (false, 0) => size,
+        (false, _) => size - position,
+    };
+    match distance.checked_sub(first) {
+        Some(rest) => rest / size + 1,
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prop;
+    use crate::temp::Temp;
+
+    use super::*;
+
+    fn lock(wheels: usize, size: u64, start: u64) -> Lock {
+        Lock::new(vec![Dial::new(size, start, [0]).unwrap(); wheels]).unwrap()
+    }
+
+    /// One click, carrying when a wheel goes from its top position to 0 or back.
+    fn naive_turn(
+        positions: &mut [u64],
+        zeros: &mut [u64],
+        size: u64,
+        wheel: usize,
+        is_left: bool,
+    ) {
+        let Some(&before) = positions.get(wheel) else {
+            return;
+        };
+        let after = if is_left {
+            (before + size - 1) % size
+        } else {
+            (before + 1) % size
+        };
+        positions[wheel] = after;
+        zeros[wheel] += (after == 0) as u64;
+        if (is_left && before == 0) || (!is_left && after == 0) {
+            naive_turn(positions, zeros, size, wheel + 1, is_left);
+        }
+    }
+
+    #[test]
+    fn test_odometer() {
+        let mut lock = lock(3, 10, 0);
+        lock.turn(0, false, 1234);
+        assert_eq!(lock.combination(), vec![4, 3, 2]);
+        assert_eq!(lock.zeros(), &[123, 12, 1]);
+        lock.turn(0, true, 5);
+        assert_eq!(lock.combination(), vec![9, 2, 2]);
+        assert_eq!(lock.zeros(), &[124, 12, 1]);
+        // Turning back undoes the borrow.
+        lock.turn(0, false, 5);
+        assert_eq!(lock.combination(), vec![4, 3, 2]);
+        // The first wheel is the lowest digit: 234 + 766 rolls over to 000, losing the
+        // carry out of the last wheel.
+        lock.turn(0, false, 766);
+        assert_eq!(lock.combination(), vec![0, 0, 0]);
+        lock.turn(0, true, 802);
+        assert_eq!(lock.combination(), vec![8, 9, 1]);
+    }
+
+    #[test]
+    fn test_turn_later_wheel() {
+        let mut lock = lock(2, 100, 50);
+        lock.turn(1, true, 50);
+        assert_eq!(lock.combination(), vec![50, 0]);
+        assert_eq!(lock.zeros(), &[0, 1]);
+        // The carry out of the last wheel is lost.
+        lock.turn(1, true, 250);
+        assert_eq!(lock.combination(), vec![50, 50]);
+        assert_eq!(lock.zeros(), &[0, 3]);
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            Lock::new(vec![]),
+            Err("a lock needs at least one wheel".to_string())
+        );
+        let lock = lock(4, 100, 50);
+        assert_eq!(lock.len(), 4);
+        assert!(!lock.is_empty());
+        assert_eq!(lock.combination(), vec![50; 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "wheel 3 is not on a lock of 2 wheels")]
+    fn test_turn_missing_wheel() {
+        lock(2, 100, 50).turn(2, true, 1);
+    }
+
+    #[test]
+    fn test_open() {
+        let input = Temp::file("lock-open.txt", "2:L50\nR150\n3:R10\n");
+        let source = InputSource::Path(input.to_path_buf());
+        let lock = open(None, &source, &[]).unwrap();
+        assert_eq!(
+            describe(&lock),
+            "Wheel 1: 2 zeros\nWheel 2: 1 zeros\nWheel 3: 0 zeros\nCombination: 0-2-60\n"
+        );
+        assert_eq!(open(Some(4), &source, &[]).unwrap().len(), 4);
+        assert_eq!(
+            open(Some(2), &source, &[]),
+            Err("a move turns wheel 3, but the lock has 2 wheels".to_string())
+        );
+        assert_eq!(
+            open(None, &source, &[("markers".to_string(), "x".to_string())]),
+            Err("parameter `markers`: `x` is not a number".to_string())
+        );
+    }
+
+    #[test]
+    fn test_turn_matches_naive() {
+        prop::check(
+            "lock turn",
+            |rng| {
+                let size = rng.range(1..=12) as u64;
+                let wheels = rng.range(1..=4) as usize;
+                let moves: Vec<(usize, bool, u64)> = (0..rng.range(0..=6))
+                    .map(|_| {
+                        let wheel = rng.below(wheels as u64) as usize;
+                        (wheel, rng.bool(), rng.range(0..=200) as u64)
+                    })
+                    .collect();
+                (size, wheels, rng.below(size), moves)
+            },
+            |&(size, wheels, start, ref moves)| {
+                let mut lock = lock(wheels, size, start);
+                let mut positions = vec![start; wheels];
+                let mut zeros = vec![0; wheels];
+                for &(wheel, is_left, distance) in moves {
+                    lock.turn(wheel, is_left, distance);
+                    for _ in 0..distance {
+                        naive_turn(&mut positions, &mut zeros, size, wheel, is_left);
+                    }
+                }
+                prop::agree(
+                    (lock.combination(), lock.zeros().to_vec()),
+                    (positions, zeros),
+                )
+            },
+        );
+    }
+}
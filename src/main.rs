@@ -1,13 +1,2290 @@
-fn main() {
-    println!("Advent of Code 2025");
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use aoc_2025::answers::{self, Answers, Verdict};
+use aoc_2025::bench::{self, DayBench};
+use aoc_2025::client::{self, Client, Fetched, Outcome, RateLimiter, Settings};
+use aoc_2025::generator;
+use aoc_2025::history;
+use aoc_2025::http::Http;
+use aoc_2025::input::{self, InputSource};
+use aoc_2025::limits::Limits;
+use aoc_2025::lock;
+use aoc_2025::memory;
+use aoc_2025::output::{self, DayResult, Format, Status, SummaryRow};
+use aoc_2025::parallel::{self, Limit};
+use aoc_2025::params::{self, Params};
+use aoc_2025::query::{self, Query};
+use aoc_2025::registry::{self, Day, DAYS};
+use aoc_2025::scaffold;
+use aoc_2025::shrink::{self, Predicate};
+use aoc_2025::solution::{Answer, Part};
+use aoc_2025::trace;
+
+#[cfg(test)]
+mod temp;
+
+const USAGE: &str = "\
+Usage:
+  aoc-2025 run <day|all> [--part <1|2>] [--input <path|->] [--format <text|json|csv>] [--param <key=value>]... [--jobs <n>] [--timeout <seconds>] [--memory <MiB>]
+  aoc-2025 run 1 --trace [--animate] [--speed <moves/s>] [--input <path|->] [--param <key=value>]...
+  aoc-2025 bench [day|all] [--iterations <n>] [--param <key=value>]... [--save <path>] [--compare <path>] [--threshold <percent>]
+  aoc-2025 verify [day|all] [--update-readme]
+  aoc-2025 fetch <day>
+  aoc-2025 submit <day> <1|2>
+  aoc-2025 new <day> [--title <title>] [--dir <path>]
+  aoc-2025 gen <day> [--seed <n>] [--size <n>]
+  aoc-2025 shrink <day> --input <path> (--panics | --reference <command>) [--part <1|2>] [--output <path>]
+  aoc-2025 query [<k|i-j>...] [--input <path|->] [--param <key=value>]...
+  aoc-2025 lock [--wheels <n>] [--input <path|->] [--param <key=value>]...
+  aoc-2025 list";
+
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+const DEFAULT_ITERATIONS: usize = 10;
+const DEFAULT_THRESHOLD: f64 = 10.0;
+const DEFAULT_SEED: u64 = 2025;
+/// Moves per second of `run 1 --trace --animate` without `--speed`.
+const DEFAULT_SPEED: f64 = 10.0;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Command {
+    Run {
+        day: Option<u8>,
+        part: Option<Part>,
+        input: InputSource,
+        format: Format,
+        /// `--param` overrides applied on top of `aoc.toml`, in order.
+        params: Vec<(String, String)>,
+        /// Days solved at once; `None` for one per core.
+        jobs: Option<usize>,
+        /// Limits for parts that `limits.toml` does not list.
+        limit: Limit,
+    },
+    Bench {
+        day: Option<u8>,
+        iterations: usize,
+        params: Vec<(String, String)>,
+        save: Option<PathBuf>,
+        compare: Option<PathBuf>,
+        /// Percentage slowdown of a median that counts as a regression.
+        threshold: f64,
+    },
+    Verify {
+        day: Option<u8>,
+        update_readme: bool,
+    },
+    Fetch {
+        day: u8,
+    },
+    Submit {
+        day: u8,
+        part: Part,
+    },
+    New {
+        day: u8,
+        title: String,
+        /// `None` for the current directory.
+        dir: Option<PathBuf>,
+    },
+    Gen {
+        day: u8,
+        seed: u64,
+        /// `None` for the day's default size.
+        size: Option<usize>,
+    },
+    Shrink {
+        day: u8,
+        input: PathBuf,
+        predicate: Predicate,
+        part: Option<Part>,
+        /// `None` for the input path with `.min` appended.
+        output: Option<PathBuf>,
+    },
+    Query {
+        /// Read from stdin, one per line, when empty.
+        queries: Vec<Query>,
+        input: InputSource,
+        params: Vec<(String, String)>,
+    },
+    /// `run 1 --trace`.
+    Trace {
+        input: InputSource,
+        params: Vec<(String, String)>,
+        /// Time between frames of an animated playback; `None` to print the trace.
+        delay: Option<Duration>,
+    },
+    Lock {
+        /// `None` for as many as the moves turn.
+        wheels: Option<usize>,
+        input: InputSource,
+        params: Vec<(String, String)>,
+    },
+    List,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match parse_args(&args) {
+        Ok(command) => {
+            if execute(&command) {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(message) => {
+            eprintln!("error: {message}\n\n{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter().map(String::as_str).peekable();
+
+    match args.next() {
+        Some("run") => {
+            let day = match args.next() {
+                Some("all") => None,
+                Some(day) => Some(parse_day(day)?),
+                None => return Err("missing day".to_string()),
+            };
+            let mut part = None;
+            let mut input = InputSource::Default;
+            let mut format = Format::Text;
+            let mut params = Vec::new();
+            let mut jobs = None;
+            let mut limit = Limit::default();
+            let mut trace = false;
+            let mut animate = false;
+            let mut speed = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--part" | "-p" => {
+                        let value = args.next().ok_or("missing value for --part")?;
+                        part = Some(parse_part(value)?);
+                    }
+                    "--input" | "-i" => {
+                        let value = args.next().ok_or("missing value for --input")?;
+                        input = InputSource::from_arg(value);
+                    }
+                    "--format" | "-f" => {
+                        let value = args.next().ok_or("missing value for --format")?;
+                        format = Format::from_arg(value)
+                            .ok_or_else(|| format!("invalid format: {value}"))?;
+                    }
+                    "--param" | "-P" => {
+                        let value = args.next().ok_or("missing value for --param")?;
+                        params.push(params::parse_override(value)?);
+                    }
+                    "--jobs" | "-j" => {
+                        let value = args.next().ok_or("missing value for --jobs")?;
+                        jobs = Some(
+                            value
+                                .parse()
+                                .ok()
+                                .filter(|&n| n > 0)
+                                .ok_or_else(|| format!("invalid jobs: {value}"))?,
+                        );
+                    }
+                    "--timeout" | "-t" => {
+                        let value = args.next().ok_or("missing value for --timeout")?;
+                        limit.time = value
+                            .parse()
+                            .ok()
+                            .filter(|&seconds: &f64| seconds > 0.0)
+                            .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+                            .ok_or_else(|| format!("invalid timeout: {value}"))?;
+                    }
+                    "--memory" | "-m" => {
+                        let value = args.next().ok_or("missing value for --memory")?;
+                        limit.memory = value
+                            .parse::<usize>()
+                            .ok()
+                            .filter(|&mib| mib > 0)
+                            .and_then(|mib| mib.checked_mul(1 << 20))
+                            .ok_or_else(|| format!("invalid memory: {value}"))?;
+                    }
+                    "--trace" => trace = true,
+                    "--animate" => animate = true,
+                    "--speed" | "-s" => {
+                        let value = args.next().ok_or("missing value for --speed")?;
+                        speed = Some(
+                            value
+                                .parse()
+                                .ok()
+                                .filter(|&speed: &f64| speed > 0.0)
+                                .and_then(|speed| Duration::try_from_secs_f64(1.0 / speed).ok())
+                                .ok_or_else(|| format!("invalid speed: {value}"))?,
+                        );
+                    }
+                    other => return Err(format!("unexpected argument: {other}")),
+                }
+            }
+            if day.is_none() && input != InputSource::Default {
+                return Err("--input requires a single day".to_string());
+            }
+            if day.is_none() && !params.is_empty() {
+                return Err("--param requires a single day".to_string());
+            }
+            if !trace && (animate || speed.is_some()) {
+                return Err("--animate and --speed require --trace".to_string());
+            }
+            if trace {
+                if day != Some(1) {
+                    return Err("--trace is only available for day 1".to_string());
+                }
+                if part.is_some() || format != Format::Text {
+                    return Err("--trace shows both parts as text".to_string());
+                }
+                let default = Duration::from_secs_f64(1.0 / DEFAULT_SPEED);
+                return Ok(Command::Trace {
+                    input,
+                    params,
+                    delay: speed.or(animate.then_some(default)),
+                });
+            }
+            Ok(Command::Run {
+                day,
+                part,
+                input,
+                format,
+                params,
+                jobs,
+                limit,
+            })
+        }
+        Some("bench") => {
+            let day = match args.next_if(|arg| !arg.starts_with('-')) {
+                None | Some("all") => None,
+                Some(day) => Some(parse_day(day)?),
+            };
+            let mut iterations = DEFAULT_ITERATIONS;
+            let mut params = Vec::new();
+            let mut save = None;
+            let mut compare = None;
+            let mut threshold = DEFAULT_THRESHOLD;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--iterations" | "-n" => {
+                        let value = args.next().ok_or("missing value for --iterations")?;
+                        iterations = value
+                            .parse()
+                            .ok()
+                            .filter(|&n| n > 0)
+                            .ok_or_else(|| format!("invalid iterations: {value}"))?;
+                    }
+                    "--param" | "-P" => {
+                        let value = args.next().ok_or("missing value for --param")?;
+                        params.push(params::parse_override(value)?);
+                    }
+                    "--save" => {
+                        let value = args.next().ok_or("missing value for --save")?;
+                        save = Some(PathBuf::from(value));
+                    }
+                    "--compare" => {
+                        let value = args.next().ok_or("missing value for --compare")?;
+                        compare = Some(PathBuf::from(value));
+                    }
+                    "--threshold" => {
+                        let value = args.next().ok_or("missing value for --threshold")?;
+                        threshold = value
+                            .parse()
+                            .ok()
+                            .filter(|t: &f64| *t >= 0.0)
+                            .ok_or_else(|| format!("invalid threshold: {value}"))?;
+                    }
+                    other => return Err(format!("unexpected argument: {other}")),
+                }
+            }
+            if day.is_none() && !params.is_empty() {
+                return Err("--param requires a single day".to_string());
+            }
+            Ok(Command::Bench {
+                day,
+                iterations,
+                params,
+                save,
+                compare,
+                threshold,
+            })
+        }
+        Some("verify") => {
+            let day = match args.next_if(|arg| !arg.starts_with('-')) {
+                None | Some("all") => None,
+                Some(day) => Some(parse_day(day)?),
+            };
+            let mut update_readme = false;
+            for arg in args {
+                match arg {
+                    "--update-readme" => update_readme = true,
+                    other => return Err(format!("unexpected argument: {other}")),
+                }
+            }
+            Ok(Command::Verify { day, update_readme })
+        }
+        Some("fetch") => {
+            let day = args.next().ok_or("missing day")?;
+            let day = parse_event_day(day)?;
+            match args.next() {
+                Some(other) => Err(format!("unexpected argument: {other}")),
+                None => Ok(Command::Fetch { day }),
+            }
+        }
+        Some("submit") => {
+            let day = parse_day(args.next().ok_or("missing day")?)?;
+            let part = parse_part(args.next().ok_or("missing part")?)?;
+            match args.next() {
+                Some(other) => Err(format!("unexpected argument: {other}")),
+                None => Ok(Command::Submit { day, part }),
+            }
+        }
+        Some("new") => {
+            let day = parse_event_day(args.next().ok_or("missing day")?)?;
+            let mut title = format!("Day {day}");
+            let mut dir = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--title" | "-t" => {
+                        title = args.next().ok_or("missing value for --title")?.to_string();
+                    }
+                    "--dir" | "-d" => {
+                        let value = args.next().ok_or("missing value for --dir")?;
+                        dir = Some(PathBuf::from(value));
+                    }
+                    other => return Err(format!("unexpected argument: {other}")),
+                }
+            }
+            Ok(Command::New { day, title, dir })
+        }
+        Some("gen") => {
+            let value = args.next().ok_or("missing day")?;
+            let day = value
+                .parse()
+                .ok()
+                .filter(|&day| generator::size_of(day).is_some())
+                .ok_or_else(|| format!("no generator for day {value}"))?;
+            let mut seed = DEFAULT_SEED;
+            let mut size = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--seed" | "-s" => {
+                        let value = args.next().ok_or("missing value for --seed")?;
+                        seed = value
+                            .parse()
+                            .map_err(|_| format!("invalid seed: {value}"))?;
+                    }
+                    "--size" | "-n" => {
+                        let value = args.next().ok_or("missing value for --size")?;
+                        size = Some(
+                            value
+                                .parse()
+                                .ok()
+                                .filter(|&n| n > 0)
+                                .ok_or_else(|| format!("invalid size: {value}"))?,
+                        );
+                    }
+                    other => return Err(format!("unexpected argument: {other}")),
+                }
+            }
+            Ok(Command::Gen { day, seed, size })
+        }
+        Some("shrink") => {
+            let day = parse_day(args.next().ok_or("missing day")?)?;
+            let mut input = None;
+            let mut predicate = None;
+            let mut part = None;
+            let mut output = None;
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--input" | "-i" => {
+                        let value = args.next().ok_or("missing value for --input")?;
+                        input = Some(PathBuf::from(value));
+                    }
+                    "--panics" => predicate = Some(Predicate::Panics),
+                    "--reference" | "-r" => {
+                        let value = args.next().ok_or("missing value for --reference")?;
+                        predicate = Some(Predicate::Differs {
+                            reference: value.to_string(),
+                        });
+                    }
+                    "--part" | "-p" => {
+                        let value = args.next().ok_or("missing value for --part")?;
+                        part = Some(parse_part(value)?);
+                    }
+                    "--output" | "-o" => {
+                        let value = args.next().ok_or("missing value for --output")?;
+                        output = Some(PathBuf::from(value));
+                    }
+                    other => return Err(format!("unexpected argument: {other}")),
+                }
+            }
+            Ok(Command::Shrink {
+                day,
+                input: input.ok_or("missing --input")?,
+                predicate: predicate.ok_or("missing --panics or --reference")?,
+                part,
+                output,
+            })
+        }
+        Some("query") => {
+            let mut queries = Vec::new();
+            let mut input = InputSource::Default;
+            let mut params = Vec::new();
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--input" | "-i" => {
+                        let value = args.next().ok_or("missing value for --input")?;
+                        input = InputSource::from_arg(value);
+                    }
+                    "--param" | "-P" => {
+                        let value = args.next().ok_or("missing value for --param")?;
+                        params.push(params::parse_override(value)?);
+                    }
+                    query => queries.push(query::parse(query)?),
+                }
+            }
+            if queries.is_empty() && input == InputSource::Stdin {
+                return Err("queries must be given as arguments with --input -".to_string());
+            }
+            Ok(Command::Query {
+                queries,
+                input,
+                params,
+            })
+        }
+        Some("lock") => {
+            let mut wheels = None;
+            let mut input = InputSource::Default;
+            let mut params = Vec::new();
+            while let Some(arg) = args.next() {
+                match arg {
+                    "--wheels" | "-w" => {
+                        let value = args.next().ok_or("missing value for --wheels")?;
+                        wheels = Some(
+                            value
+                                .parse()
+                                .ok()
+                                .filter(|n| (1..=lock::MAX_WHEELS).contains(n))
+                                .ok_or_else(|| format!("invalid wheels: {value}"))?,
+                        );
+                    }
+                    "--input" | "-i" => {
+                        let value = args.next().ok_or("missing value for --input")?;
+                        input = InputSource::from_arg(value);
+                    }
+                    "--param" | "-P" => {
+                        let value = args.next().ok_or("missing value for --param")?;
+                        params.push(params::parse_override(value)?);
+                    }
+                    other => return Err(format!("unexpected argument: {other}")),
+                }
+            }
+            Ok(Command::Lock {
+                wheels,
+                input,
+                params,
+            })
+        }
+        Some("list") => match args.next() {
+            Some(other) => Err(format!("unexpected argument: {other}")),
+            None => Ok(Command::List),
+        },
+        Some(other) => Err(format!("unknown command: {other}")),
+        None => Err("missing command".to_string()),
+    }
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    let number: u8 = value.parse().map_err(|_| format!("invalid day: {value}"))?;
+    if registry::find(number).is_some() {
+        Ok(number)
+    } else {
+        Err(format!("day {number} is not implemented"))
+    }
+}
+
+/// Any day of the event, implemented or not.
+fn parse_event_day(value: &str) -> Result<u8, String> {
+    value
+        .parse()
+        .ok()
+        .filter(|day| (1..=client::LAST_DAY).contains(day))
+        .ok_or_else(|| format!("invalid day: {value}"))
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    value
+        .parse()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or_else(|| format!("invalid part: {value}"))
+}
+
+/// Execute `command`, returning whether every day succeeded.
+fn execute(command: &Command) -> bool {
+    match command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            params,
+            jobs,
+            limit,
+        } => run_days(
+            *day,
+            *part,
+            input,
+            *format,
+            params,
+            jobs.unwrap_or_else(parallel::default_workers),
+            *limit,
+        ),
+        Command::Bench {
+            day,
+            iterations,
+            params,
+            save,
+            compare,
+            threshold,
+        } => bench_days(
+            *day,
+            *iterations,
+            params,
+            save.as_ref(),
+            compare.as_ref(),
+            *threshold,
+        ),
+        Command::Verify { day, update_readme } => {
+            let readme = Path::new(env!("CARGO_MANIFEST_DIR")).join("docs/README.md");
+            verify_days(*day, update_readme.then_some(readme.as_path()))
+        }
+        Command::Fetch { day } => {
+            let path = input::default_path(*day);
+            let result = Settings::load().and_then(|settings| {
+                let client = Client::new(Http, settings, RateLimiter::site());
+                client::fetch(&client, *day, &path)
+            });
+            match result {
+                Ok(Fetched::Cached) => {
+                    println!("Day {day:02}: already cached at {}", path.display());
+                    true
+                }
+                Ok(Fetched::Downloaded { bytes }) => {
+                    println!("Day {day:02}: saved {bytes} bytes to {}", path.display());
+                    true
+                }
+                Err(error) => {
+                    eprintln!("error: {error}");
+                    false
+                }
+            }
+        }
+        Command::Submit { day, part } => match submit_answer(*day, *part) {
+            Ok(outcome) => outcome == Outcome::Correct,
+            Err(error) => {
+                eprintln!("error: {error}");
+                false
+            }
+        },
+        Command::New { day, title, dir } => {
+            let root = match dir.clone().map_or_else(std::env::current_dir, Ok) {
+                Ok(root) => root,
+                Err(error) => {
+                    eprintln!("error: failed to read the current directory: {error}");
+                    return false;
+                }
+            };
+            match scaffold::generate(&root, *day, title, client::now()) {
+                Ok(actions) => {
+                    for (path, action) in actions {
+                        let path = path.strip_prefix(&root).unwrap_or(&path);
+                        println!("{action:>8}  {}", path.display());
+                    }
+                    true
+                }
+                Err(error) => {
+                    eprintln!("error: {error}");
+                    false
+                }
+            }
+        }
+        Command::Gen { day, seed, size } => {
+            let (_, default_size) = generator::size_of(*day).expect("validated day");
+            match generator::generate(*day, *seed, size.unwrap_or(default_size)) {
+                Ok(generated) => {
+                    // Answers go to stderr so the input can be redirected to a file.
+                    print!("{}", generated.input);
+                    for (part, answer) in generated.answers {
+                        eprintln!("Day {day:02} part {part}: {answer}");
+                    }
+                    true
+                }
+                Err(error) => {
+                    eprintln!("error: {error}");
+                    false
+                }
+            }
+        }
+        Command::Shrink {
+            day,
+            input,
+            predicate,
+            part,
+            output,
+        } => {
+            let output = output.clone().unwrap_or_else(|| {
+                let mut path = input.clone().into_os_string();
+                path.push(".min");
+                PathBuf::from(path)
+            });
+            match shrink_input(*day, input, predicate, *part, &output) {
+                Ok(()) => true,
+                Err(error) => {
+                    eprintln!("error: {error}");
+                    false
+                }
+            }
+        }
+        Command::Query {
+            queries,
+            input,
+            params,
+        } => match query::run(queries, input, params) {
+            Ok(answers) => {
+                let mut success = true;
+                for answer in answers {
+                    match answer {
+                        Ok(answer) => println!("{answer}"),
+                        Err(error) => {
+                            eprintln!("error: {error}");
+                            success = false;
+                        }
+                    }
+                }
+                success
+            }
+            Err(error) => {
+                eprintln!("error: {error}");
+                false
+            }
+        },
+        Command::Trace {
+            input,
+            params,
+            delay,
+        } => match trace::run(&mut std::io::stdout().lock(), input, params, *delay) {
+            Ok(_) => true,
+            Err(error) => {
+                eprintln!("error: {error}");
+                false
+            }
+        },
+        Command::Lock {
+            wheels,
+            input,
+            params,
+        } => match lock::open(*wheels, input, params) {
+            Ok(lock) => {
+                print!("{}", lock::describe(&lock));
+                true
+            }
+            Err(error) => {
+                eprintln!("error: {error}");
+                false
+            }
+        },
+        Command::List => {
+            for entry in DAYS {
+                let params: Vec<String> = entry
+                    .params
+                    .iter()
+                    .map(|param| format!("{}={}", param.name, param.default))
+                    .collect();
+                if params.is_empty() {
+                    println!("Day {:02}: {}", entry.number, entry.title);
+                } else {
+                    println!(
+                        "Day {:02}: {} ({})",
+                        entry.number,
+                        entry.title,
+                        params.join(", ")
+                    );
+                }
+            }
+            true
+        }
+    }
+}
+
+/// Solve every selected part on the thread pool within its limit and with the day's
+/// parameters from `aoc.toml` and `overrides`, then report the days in order, ending
+/// `run all` with a summary table.
+fn run_days(
+    day: Option<u8>,
+    part: Option<Part>,
+    source: &InputSource,
+    format: Format,
+    overrides: &[(String, String)],
+    workers: usize,
+    default: Limit,
+) -> bool {
+    // Known answers only apply to the co-located inputs.
+    let known = if *source == InputSource::Default {
+        Answers::load_default()
+    } else {
+        Ok(Answers::default())
+    };
+    let entries: Vec<&'static Day> = DAYS
+        .iter()
+        .filter(|d| day.is_none_or(|n| n == d.number))
+        .collect();
+    let loaded = known.and_then(|mut known| {
+        let limits = Limits::load_default()?;
+        let config = params::Config::load_default()?;
+        let params = entries
+            .iter()
+            .map(|entry| {
+                let params = entry.resolve_params(&config, overrides)?;
+                // Answers for other parameters are not the puzzle's.
+                if !params.is_default() {
+                    known.forget(entry.number);
+                }
+                Ok(params)
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok((known, limits, params))
+    });
+    let (known, limits, params) = match loaded {
+        Ok(loaded) => loaded,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return false;
+        }
+    };
+
+    let parts = part.map_or(Part::ALL.to_vec(), |p| vec![p]);
+    let limit = |entry: &Day, part| limits.get(entry.number, part, default);
+    // Each day's input is read once and shared by its parts. Every part still parses it
+    // within its own limits, so each reports its own parse time.
+    let inputs: Vec<Result<Arc<str>, String>> = entries
+        .iter()
+        .map(|entry| {
+            input::load(entry.number, source)
+                .map(Arc::from)
+                .map_err(|error| error.to_string())
+        })
+        .collect();
+    let tasks = entries
+        .iter()
+        .zip(params)
+        .zip(inputs)
+        .flat_map(|((&entry, params), input)| {
+            parts.iter().map(move |&part| {
+                let (input, params) = (input.clone(), params.clone());
+                (limit(entry, part), move || {
+                    solve_input(entry, Some(part), &input?, &params)
+                })
+            })
+        })
+        .collect();
+    let start = Instant::now();
+    let mut reports = parallel::run(tasks, workers).into_iter();
+    let wall = start.elapsed();
+
+    let mut success = true;
+    let mut results = Vec::new();
+    let mut rows = Vec::new();
+    let mut contended = 0;
+    for entry in entries {
+        let mut solved: Option<DayResult> = None;
+        let mut errors = Vec::new();
+        let mut row = SummaryRow {
+            day: entry.number,
+            title: entry.title,
+            parts: Vec::new(),
+            wall: Duration::ZERO,
+            peak_memory: 0,
+        };
+        for &part in &parts {
+            let report = reports.next().expect("one report per part");
+            row.wall += report.elapsed;
+            row.peak_memory = row.peak_memory.max(report.peak_memory);
+            contended += (report.abandoned > 0) as usize;
+            let name = format!("day {:02} part {part}", entry.number);
+            let status = match report.outcome {
+                parallel::Outcome::Finished(Ok(result)) => {
+                    let answer = result.run.parts[0].answer.clone();
+                    match &mut solved {
+                        Some(day) => day.run.parts.extend(result.run.parts.iter().cloned()),
+                        None => solved = Some(result.clone()),
+                    }
+                    // Kept per part for JSON and CSV, each with the parse time it measured.
+                    results.push(result);
+                    Status::Solved(answer)
+                }
+                parallel::Outcome::Finished(Err(error)) => {
+                    errors.push(error);
+                    Status::Failed
+                }
+                parallel::Outcome::Panicked(message) => {
+                    errors.push(format!("{name} panicked: {message}"));
+                    Status::Panicked
+                }
+                parallel::Outcome::TimedOut => {
+                    let time = limit(entry, part).time;
+                    errors.push(format!("{name} timed out after {time:.2?}"));
+                    Status::TimedOut
+                }
+                parallel::Outcome::OutOfMemory => {
+                    let memory = memory::format_mib(limit(entry, part).memory);
+                    errors.push(format!("{name} ran out of memory (limit {memory})"));
+                    Status::OutOfMemory
+                }
+            };
+            row.parts.push((part, status));
+        }
+
+        // Both parts report the same missing or malformed input.
+        errors.dedup();
+        for error in &errors {
+            eprintln!("error: {error}");
+        }
+        success &= errors.is_empty();
+        if let Some(result) = solved {
+            if format == Format::Text {
+                print!("{}", output::text(&result, &known));
+            }
+            success &= is_correct(&result, &known);
+        }
+        rows.push(row);
+    }
+
+    match format {
+        Format::Text if day.is_none() => print!("\n{}", output::summary(&rows, &known, wall)),
+        Format::Text => {}
+        Format::Json => print!("{}", output::json(&results)),
+        Format::Csv => print!("{}", output::csv(&results)),
+    }
+    if contended > 0 {
+        eprintln!(
+            "warning: {contended} parts ran alongside timed-out parts that had not stopped, \
+             so their times may be inflated"
+        );
+    }
+    success
+}
+
+/// Solve and time `day` on its loaded `input` with `params`, failing with a printable
+/// message when the input is malformed.
+fn solve_input(
+    day: &Day,
+    part: Option<Part>,
+    input: &str,
+    params: &Params,
+) -> Result<DayResult, String> {
+    let parts = part.map_or(Part::ALL.to_vec(), |p| vec![p]);
+    let run = day
+        .run_with(input, &parts, params)
+        .map_err(|error| error.render())?;
+
+    Ok(DayResult {
+        day: day.number,
+        title: day.title,
+        checksum: input::checksum(input),
+        run,
+    })
+}
+
+/// Whether no answer in `result` contradicts `known`.
+fn is_correct(result: &DayResult, known: &Answers) -> bool {
+    result.run.parts.iter().all(|solved| {
+        !matches!(
+            known.check(result.day, solved.part, &solved.answer),
+            Verdict::Wrong { .. }
+        )
+    })
+}
+
+fn solve_day(
+    day: &Day,
+    parts: &[Part],
+    source: &InputSource,
+) -> Result<Vec<(Part, Answer)>, String> {
+    let input = input::load(day.number, source).map_err(|error| error.to_string())?;
+    day.run(&input, parts).map_err(|error| error.render())
+}
+
+/// Solve `part` of `day` on its default input and submit the answer, unless
+/// `answers.toml` or the attempt history already settle it.
+fn submit_answer(day: u8, part: Part) -> Result<Outcome, String> {
+    if let Some(expected) = Answers::load_default()?.get(day, part) {
+        return Err(format!(
+            "day {day:02} part {part} is already solved ({expected} in answers.toml)"
+        ));
+    }
+    let entry = registry::find(day).expect("validated day");
+    let (_, answer) = solve_day(entry, &[part], &InputSource::Default)?
+        .pop()
+        .expect("one part");
+    if answer == Answer::NotApplicable {
+        return Err(format!("day {day:02} part {part} has no answer to submit"));
+    }
+
+    println!("Day {day:02} part {part}: submitting {answer}");
+    let settings = Settings::load()?;
+    let client = Client::new(Http, settings, RateLimiter::site());
+    let outcome = client::submit(
+        &client,
+        &history::default_path(),
+        day,
+        part,
+        &answer.to_string(),
+    )?;
+    println!("Day {day:02} part {part}: {outcome}");
+    if outcome == Outcome::Correct {
+        println!("Add it to answers.toml: [day{day:02}] part{part} = {answer}");
+    }
+    Ok(outcome)
+}
+
+/// Shrink the input at `path` while `predicate` holds and write the result to `output`.
+fn shrink_input(
+    day: u8,
+    path: &Path,
+    predicate: &Predicate,
+    part: Option<Part>,
+    output: &Path,
+) -> Result<(), String> {
+    let input = std::fs::read_to_string(path)
+        .map_err(|error| format!("failed to read {}: {error}", path.display()))?;
+    let entry = registry::find(day).expect("validated day");
+    let parts = part.map_or(Part::ALL.to_vec(), |p| vec![p]);
+
+    // Every candidate that panics would otherwise print its panic message.
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let shrunk = shrink::shrink(day, &input, |candidate| {
+        shrink::fails(entry, &parts, predicate, candidate)
+    });
+    std::panic::set_hook(hook);
+    let shrunk = shrunk?;
+
+    std::fs::write(output, &shrunk.input)
+        .map_err(|error| format!("failed to write {}: {error}", output.display()))?;
+    println!(
+        "Day {day:02}: shrunk {} lines ({} bytes) to {} lines ({} bytes) in {} tests",
+        input.lines().count(),
+        input.len(),
+        shrunk.input.lines().count(),
+        shrunk.input.len(),
+        shrunk.tests
+    );
+    println!("Wrote {}", output.display());
+    Ok(())
+}
+
+/// Check every part of the selected days against `answers.toml`, optionally rewriting the
+/// Progress table of `readme` from it.
+fn verify_days(day: Option<u8>, readme: Option<&Path>) -> bool {
+    let known = match Answers::load_default() {
+        Ok(known) => known,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return false;
+        }
+    };
+
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    for entry in DAYS.iter().filter(|d| day.is_none_or(|n| n == d.number)) {
+        let results = match solve_day(entry, &Part::ALL, &InputSource::Default) {
+            Ok(results) => results,
+            Err(error) => {
+                eprintln!("error: {error}");
+                failed += Part::ALL.len();
+                continue;
+            }
+        };
+        for (part, answer) in results {
+            let verdict = known.check(entry.number, part, &answer);
+            let note = match &verdict {
+                Verdict::Correct => {
+                    passed += 1;
+                    String::new()
+                }
+                Verdict::Wrong { expected } => {
+                    failed += 1;
+                    format!(" (expected {expected})")
+                }
+                Verdict::Unknown => {
+                    unknown += 1;
+                    " (no known answer)".to_string()
+                }
+            };
+            println!(
+                "Day {:02} part {part}: {verdict}  {answer}{note}",
+                entry.number
+            );
+        }
+    }
+    println!("{passed} passed, {failed} failed, {unknown} unknown");
+
+    if let Some(path) = readme {
+        match update_readme(path, &known) {
+            Ok(()) => println!("Updated Progress table in {}", path.display()),
+            Err(error) => {
+                eprintln!("error: {error}");
+                return false;
+            }
+        }
+    }
+
+    failed == 0
+}
+
+fn update_readme(path: &Path, known: &Answers) -> Result<(), String> {
+    let readme = std::fs::read_to_string(path)
+        .map_err(|error| format!("failed to read {}: {error}", path.display()))?;
+    let table = known.progress_table(DAYS.iter().map(|d| d.number));
+    let updated = answers::replace_progress_table(&readme, &table)?;
+    std::fs::write(path, updated)
+        .map_err(|error| format!("failed to write {}: {error}", path.display()))
+}
+
+/// Benchmark the selected days with their parameters from `aoc.toml` and `overrides`,
+/// then save and/or compare against a baseline.
+fn bench_days(
+    day: Option<u8>,
+    iterations: usize,
+    overrides: &[(String, String)],
+    save: Option<&PathBuf>,
+    compare: Option<&PathBuf>,
+    threshold: f64,
+) -> bool {
+    let loaded = compare
+        .map(load_baseline)
+        .transpose()
+        .and_then(|baseline| Ok((baseline, params::Config::load_default()?)));
+    let (baseline, config) = match loaded {
+        Ok(loaded) => loaded,
+        Err(error) => {
+            eprintln!("error: {error}");
+            return false;
+        }
+    };
+
+    let mut success = true;
+    let mut results = Vec::new();
+    for entry in DAYS.iter().filter(|d| day.is_none_or(|n| n == d.number)) {
+        let result = entry
+            .resolve_params(&config, overrides)
+            .and_then(|params| bench_day(entry, iterations, &params));
+        match result {
+            Ok(result) => {
+                print!("{}", bench::report(&result, entry.title));
+                results.push(result);
+            }
+            Err(error) => {
+                eprintln!("error: {error}");
+                success = false;
+            }
+        }
+    }
+
+    if let Some(path) = save {
+        match std::fs::write(path, bench::to_json(&results)) {
+            Ok(()) => println!("Saved baseline to {}", path.display()),
+            Err(error) => {
+                eprintln!("error: failed to write {}: {error}", path.display());
+                success = false;
+            }
+        }
+    }
+
+    if let Some(baseline) = baseline {
+        println!("\nMedians compared to baseline (threshold {threshold}%):");
+        for comparison in bench::compare(&results, &baseline, threshold) {
+            println!("  {comparison}");
+            success &= !comparison.regressed;
+        }
+    }
+
+    success
+}
+
+fn bench_day(day: &Day, iterations: usize, params: &Params) -> Result<DayBench, String> {
+    let input =
+        input::load(day.number, &InputSource::Default).map_err(|error| error.to_string())?;
+    day.bench_with(&input, iterations, params)
+        .map_err(|error| error.render())
+}
+
+fn load_baseline(path: &PathBuf) -> Result<Vec<DayBench>, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|error| format!("failed to read {}: {error}", path.display()))?;
+    bench::from_json(&text).map_err(|error| format!("invalid baseline {}: {error}", path.display()))
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::temp::Temp;
+
     use super::*;
 
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn find_day(number: u8) -> &'static Day {
+        registry::find(number).unwrap()
+    }
+
+    fn defaults(number: u8) -> Params {
+        Params::defaults(find_day(number).params)
+    }
+
+    /// Load `day`'s input from `source` and solve it as the runner does.
+    fn run_day(
+        day: &Day,
+        part: Option<Part>,
+        source: &InputSource,
+        params: &Params,
+    ) -> Result<DayResult, String> {
+        let input = input::load(day.number, source).map_err(|error| error.to_string())?;
+        solve_input(day, part, &input, params)
+    }
+
+    fn known() -> Answers {
+        Answers::load_default().unwrap()
+    }
+
+    #[test]
+    fn test_parse_run_single_day() {
+        assert_eq!(
+            parse_args(&args(&["run", "7"])),
+            Ok(Command::Run {
+                day: Some(7),
+                part: None,
+                input: InputSource::Default,
+                format: Format::Text,
+                params: vec![],
+                jobs: None,
+                limit: Limit::default(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_run_with_part() {
+        assert_eq!(
+            parse_args(&args(&["run", "7", "--part", "2"])),
+            Ok(Command::Run {
+                day: Some(7),
+                part: Some(Part::Two),
+                input: InputSource::Default,
+                format: Format::Text,
+                params: vec![],
+                jobs: None,
+                limit: Limit::default(),
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "3", "-p", "1"])),
+            Ok(Command::Run {
+                day: Some(3),
+                part: Some(Part::One),
+                input: InputSource::Default,
+                format: Format::Text,
+                params: vec![],
+                jobs: None,
+                limit: Limit::default(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_run_all() {
+        assert_eq!(
+            parse_args(&args(&["run", "all"])),
+            Ok(Command::Run {
+                day: None,
+                part: None,
+                input: InputSource::Default,
+                format: Format::Text,
+                params: vec![],
+                jobs: None,
+                limit: Limit::default(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_run_jobs_and_timeout() {
+        assert_eq!(
+            parse_args(&args(&[
+                "run",
+                "all",
+                "--jobs",
+                "3",
+                "--timeout",
+                "2.5",
+                "--memory",
+                "64"
+            ])),
+            Ok(Command::Run {
+                day: None,
+                part: None,
+                input: InputSource::Default,
+                format: Format::Text,
+                params: vec![],
+                jobs: Some(3),
+                limit: Limit {
+                    time: Duration::from_millis(2500),
+                    memory: 64 << 20,
+                },
+            })
+        );
+        for (flags, error) in [
+            (&["-j", "0"][..], "invalid jobs: 0"),
+            (&["-j"], "missing value for --jobs"),
+            (&["-t", "0"], "invalid timeout: 0"),
+            (&["-t", "soon"], "invalid timeout: soon"),
+            (&["-t", "1e30"], "invalid timeout: 1e30"),
+            (&["-m", "0"], "invalid memory: 0"),
+            (&["-m", "1.5"], "invalid memory: 1.5"),
+            (&["--memory"], "missing value for --memory"),
+        ] {
+            let mut command = vec!["run", "all"];
+            command.extend(flags);
+            assert_eq!(parse_args(&args(&command)), Err(error.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_parse_run_params() {
+        assert_eq!(
+            parse_args(&args(&[
+                "run",
+                "8",
+                "-P",
+                "connections=10",
+                "--param",
+                "circuits=2"
+            ])),
+            Ok(Command::Run {
+                day: Some(8),
+                part: None,
+                input: InputSource::Default,
+                format: Format::Text,
+                params: vec![
+                    ("connections".to_string(), "10".to_string()),
+                    ("circuits".to_string(), "2".to_string()),
+                ],
+                jobs: None,
+                limit: Limit::default(),
+            })
+        );
+        for (command, error) in [
+            (
+                &["run", "8", "-P", "connections"][..],
+                "invalid parameter `connections`, expected `key=value`",
+            ),
+            (&["run", "8", "--param"], "missing value for --param"),
+            (
+                &["run", "all", "-P", "k=2"],
+                "--param requires a single day",
+            ),
+        ] {
+            assert_eq!(parse_args(&args(command)), Err(error.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_execute_run_with_params() {
+        let run = |params: &[(&str, &str)]| {
+            execute(&Command::Run {
+                day: Some(8),
+                part: Some(Part::One),
+                input: InputSource::Default,
+                format: Format::Text,
+                params: params
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+                jobs: None,
+                limit: Limit::default(),
+            })
+        };
+        // Known answers are skipped, not failed, for other parameters.
+        assert!(run(&[("connections", "10")]));
+        assert!(!run(&[("connections", "ten")]));
+        assert!(!run(&[("k", "2")]));
+    }
+
+    #[test]
+    fn test_execute_run_all_in_parallel() {
+        assert!(execute(&Command::Run {
+            day: None,
+            part: Some(Part::One),
+            input: InputSource::Default,
+            format: Format::Text,
+            params: vec![],
+            jobs: Some(4),
+            limit: Limit::default(),
+        }));
+        // Days that run out of time fail the run without stopping the others.
+        assert!(!execute(&Command::Run {
+            day: None,
+            part: None,
+            input: InputSource::Default,
+            format: Format::Csv,
+            params: vec![],
+            jobs: None,
+            limit: Limit {
+                time: Duration::from_nanos(1),
+                ..Limit::default()
+            },
+        }));
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(parse_args(&args(&["list"])), Ok(Command::List));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_args(&[]), Err("missing command".to_string()));
+        assert_eq!(
+            parse_args(&args(&["fly"])),
+            Err("unknown command: fly".to_string())
+        );
+        assert_eq!(parse_args(&args(&["run"])), Err("missing day".to_string()));
+        assert_eq!(
+            parse_args(&args(&["run", "x"])),
+            Err("invalid day: x".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "25"])),
+            Err("day 25 is not implemented".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "1", "--part"])),
+            Err("missing value for --part".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "1", "--part", "3"])),
+            Err("invalid part: 3".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "1", "--fast"])),
+            Err("unexpected argument: --fast".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["list", "all"])),
+            Err("unexpected argument: all".to_string())
+        );
+    }
+
+    #[test]
+    fn test_run_day_both_parts() {
+        assert_eq!(
+            output::text(
+                &run_day(find_day(1), None, &InputSource::Default, &defaults(1)).unwrap(),
+                &known()
+            ),
+            "Day 01: Secret Entrance\n  Part 1: 1105\n  Part 2: 6599\n"
+        );
+    }
+
+    #[test]
+    fn test_run_day_single_part() {
+        assert_eq!(
+            output::text(
+                &run_day(
+                    find_day(7),
+                    Some(Part::Two),
+                    &InputSource::Default,
+                    &defaults(7)
+                )
+                .unwrap(),
+                &known()
+            ),
+            "Day 07: Laboratories\n  Part 2: 221371496188107\n"
+        );
+        assert_eq!(
+            output::text(
+                &run_day(
+                    find_day(8),
+                    Some(Part::One),
+                    &InputSource::Default,
+                    &defaults(8)
+                )
+                .unwrap(),
+                &known()
+            ),
+            "Day 08: Playground\n  Part 1: 115885\n"
+        );
+    }
+
     #[test]
-    fn test_main() {
-        main();
+    fn test_run_day_without_part2() {
+        assert_eq!(
+            output::text(
+                &run_day(
+                    find_day(12),
+                    Some(Part::Two),
+                    &InputSource::Default,
+                    &defaults(12)
+                )
+                .unwrap(),
+                &known()
+            ),
+            "Day 12: Christmas Tree Farm\n  Part 2: N/A\n"
+        );
+    }
+
+    #[test]
+    fn test_execute() {
+        assert!(execute(&Command::List));
+        assert!(execute(&Command::Run {
+            day: Some(2),
+            part: Some(Part::One),
+            input: InputSource::Default,
+            format: Format::Text,
+            params: vec![],
+            jobs: None,
+            limit: Limit::default(),
+        }));
+    }
+
+    #[test]
+    fn test_parse_run_with_input() {
+        assert_eq!(
+            parse_args(&args(&["run", "1", "--input", "-"])),
+            Ok(Command::Run {
+                day: Some(1),
+                part: None,
+                input: InputSource::Stdin,
+                format: Format::Text,
+                params: vec![],
+                jobs: None,
+                limit: Limit::default(),
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "1", "-i", "other.txt", "-p", "2"])),
+            Ok(Command::Run {
+                day: Some(1),
+                part: Some(Part::Two),
+                input: InputSource::from_arg("other.txt"),
+                format: Format::Text,
+                params: vec![],
+                jobs: None,
+                limit: Limit::default(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        assert_eq!(
+            parse_args(&args(&["run", "1", "--input"])),
+            Err("missing value for --input".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "all", "--input", "x.txt"])),
+            Err("--input requires a single day".to_string())
+        );
+    }
+
+    #[test]
+    fn test_run_day_custom_input() {
+        let input = Temp::file(
+            "runner.txt",
+            "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82",
+        );
+        let result = run_day(
+            find_day(1),
+            None,
+            &InputSource::Path(input.to_path_buf()),
+            &defaults(1),
+        )
+        .unwrap();
+        assert_eq!(
+            output::text(&result, &Answers::default()),
+            "Day 01: Secret Entrance\n  Part 1: 3\n  Part 2: 6\n"
+        );
+        assert_eq!(
+            result.checksum,
+            input::checksum("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n")
+        );
+    }
+
+    #[test]
+    fn test_parse_query() {
+        assert_eq!(
+            parse_args(&args(&["query", "3", "2-7", "-P", "size=10"])),
+            Ok(Command::Query {
+                queries: vec![Query::Position(3), Query::Range(2, 7)],
+                input: InputSource::Default,
+                params: vec![("size".to_string(), "10".to_string())],
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["query", "-i", "moves.txt"])),
+            Ok(Command::Query {
+                queries: vec![],
+                input: InputSource::Path(PathBuf::from("moves.txt")),
+                params: vec![],
+            })
+        );
+        for (command, error) in [
+            (
+                &["query", "x"][..],
+                "invalid query: x, expected `k` or `i-j`",
+            ),
+            (&["query", "1-"], "invalid query: 1-, expected `k` or `i-j`"),
+            (&["query", "-1"], "invalid query: -1, expected `k` or `i-j`"),
+            (
+                &["query", "--input", "-"],
+                "queries must be given as arguments with --input -",
+            ),
+        ] {
+            assert_eq!(parse_args(&args(command)), Err(error.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_execute_query() {
+        let input = Temp::file("query.txt", "L68\nL30\nR48");
+        let path = input.to_path_buf();
+        let query = |queries: Vec<Query>, params: &[(&str, &str)]| {
+            execute(&Command::Query {
+                queries,
+                input: InputSource::Path(path.clone()),
+                params: params
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            })
+        };
+        assert!(query(vec![Query::Range(1, 3), Query::Position(2)], &[]));
+        assert!(query(vec![Query::Range(1, 3)], &[("markers", "0 32")]));
+        assert!(!query(vec![Query::Position(4)], &[]));
+        assert!(!query(vec![Query::Position(1)], &[("markers", "100")]));
+        drop(input);
+        assert!(!query(vec![Query::Position(1)], &[]));
+    }
+
+    #[test]
+    fn test_parse_trace() {
+        assert_eq!(
+            parse_args(&args(&["run", "1", "--trace", "-P", "size=10"])),
+            Ok(Command::Trace {
+                input: InputSource::Default,
+                params: vec![("size".to_string(), "10".to_string())],
+                delay: None,
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "1", "--trace", "--animate", "-i", "-"])),
+            Ok(Command::Trace {
+                input: InputSource::Stdin,
+                params: vec![],
+                delay: Some(Duration::from_millis(100)),
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "1", "--speed", "4", "--trace"])),
+            Ok(Command::Trace {
+                input: InputSource::Default,
+                params: vec![],
+                delay: Some(Duration::from_millis(250)),
+            })
+        );
+        for (command, error) in [
+            (
+                &["run", "2", "--trace"][..],
+                "--trace is only available for day 1",
+            ),
+            (
+                &["run", "all", "--trace"],
+                "--trace is only available for day 1",
+            ),
+            (
+                &["run", "1", "--trace", "-p", "1"],
+                "--trace shows both parts as text",
+            ),
+            (
+                &["run", "1", "--trace", "-f", "json"],
+                "--trace shows both parts as text",
+            ),
+            (
+                &["run", "1", "--animate"],
+                "--animate and --speed require --trace",
+            ),
+            (&["run", "1", "--trace", "--speed", "0"], "invalid speed: 0"),
+            (
+                &["run", "1", "--trace", "--speed", "fast"],
+                "invalid speed: fast",
+            ),
+            (
+                &["run", "1", "--trace", "--speed", "1e-300"],
+                "invalid speed: 1e-300",
+            ),
+        ] {
+            assert_eq!(parse_args(&args(command)), Err(error.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_execute_trace() {
+        let input = Temp::file("trace.txt", "L68\nR1000\n");
+        let path = input.to_path_buf();
+        let trace = |params: &[(&str, &str)], delay| {
+            execute(&Command::Trace {
+                input: InputSource::Path(path.clone()),
+                params: params
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+                delay,
+            })
+        };
+        assert!(trace(&[], None));
+        assert!(trace(&[("markers", "0 50")], Some(Duration::ZERO)));
+        assert!(!trace(&[("markers", "100")], None));
+        std::fs::write(&path, "L68\nX1\n").unwrap();
+        assert!(!trace(&[], None));
+        drop(input);
+        assert!(!trace(&[], None));
+    }
+
+    #[test]
+    fn test_parse_lock() {
+        assert_eq!(
+            parse_args(&args(&[
+                "lock", "--wheels", "3", "-i", "-", "-P", "size=10"
+            ])),
+            Ok(Command::Lock {
+                wheels: Some(3),
+                input: InputSource::Stdin,
+                params: vec![("size".to_string(), "10".to_string())],
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["lock"])),
+            Ok(Command::Lock {
+                wheels: None,
+                input: InputSource::Default,
+                params: vec![],
+            })
+        );
+        for (command, error) in [
+            (&["lock", "-w", "0"][..], "invalid wheels: 0"),
+            (&["lock", "-w", "65"][..], "invalid wheels: 65"),
+            (&["lock", "--wheels"], "missing value for --wheels"),
+            (&["lock", "3"], "unexpected argument: 3"),
+        ] {
+            assert_eq!(parse_args(&args(command)), Err(error.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_execute_lock() {
+        let input = Temp::file("lock.txt", "2:L50\nR150\n3:R10\n");
+        let source = InputSource::Path(input.to_path_buf());
+        let lock = |params: &[(&str, &str)]| {
+            execute(&Command::Lock {
+                wheels: None,
+                input: source.clone(),
+                params: params
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            })
+        };
+        assert!(lock(&[("size", "10"), ("start", "0")]));
+        assert!(!lock(&[("markers", "100")]));
+        std::fs::write(&input, "0:L1\n").unwrap();
+        assert!(!lock(&[]));
+        drop(input);
+        assert!(!lock(&[]));
+    }
+
+    #[test]
+    fn test_run_day_malformed_input() {
+        let input = Temp::file("malformed.txt", "L68\nL3O\n");
+        let error = run_day(
+            find_day(1),
+            None,
+            &InputSource::Path(input.to_path_buf()),
+            &defaults(1),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "day 01, line 2, column 2: expected distance as a non-negative integer, found `3O`\n  |\n2 | L3O\n  |  ^^"
+        );
+    }
+
+    #[test]
+    fn test_execute_missing_input() {
+        assert!(!execute(&Command::Run {
+            day: Some(1),
+            part: None,
+            input: InputSource::from_arg("does/not/exist.txt"),
+            format: Format::Text,
+            params: vec![],
+            jobs: None,
+            limit: Limit::default(),
+        }));
+    }
+
+    #[test]
+    fn test_parse_bench_defaults() {
+        let expected = Command::Bench {
+            day: None,
+            iterations: DEFAULT_ITERATIONS,
+            params: Vec::new(),
+            save: None,
+            compare: None,
+            threshold: DEFAULT_THRESHOLD,
+        };
+        assert_eq!(parse_args(&args(&["bench"])), Ok(expected.clone()));
+        assert_eq!(parse_args(&args(&["bench", "all"])), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_bench_options() {
+        assert_eq!(
+            parse_args(&args(&[
+                "bench",
+                "9",
+                "-n",
+                "50",
+                "--save",
+                "base.json",
+                "--compare",
+                "old.json",
+                "--threshold",
+                "2.5",
+            ])),
+            Ok(Command::Bench {
+                day: Some(9),
+                iterations: 50,
+                params: Vec::new(),
+                save: Some(PathBuf::from("base.json")),
+                compare: Some(PathBuf::from("old.json")),
+                threshold: 2.5,
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["bench", "--iterations", "3"])),
+            Ok(Command::Bench {
+                day: None,
+                iterations: 3,
+                params: Vec::new(),
+                save: None,
+                compare: None,
+                threshold: DEFAULT_THRESHOLD,
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["bench", "8", "-P", "connections=10"])),
+            Ok(Command::Bench {
+                day: Some(8),
+                iterations: DEFAULT_ITERATIONS,
+                params: vec![("connections".to_string(), "10".to_string())],
+                save: None,
+                compare: None,
+                threshold: DEFAULT_THRESHOLD,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_bench_errors() {
+        assert_eq!(
+            parse_args(&args(&["bench", "13"])),
+            Err("day 13 is not implemented".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["bench", "1", "-n", "0"])),
+            Err("invalid iterations: 0".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["bench", "1", "--iterations"])),
+            Err("missing value for --iterations".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["bench", "1", "--threshold", "-1"])),
+            Err("invalid threshold: -1".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["bench", "1", "--threshold"])),
+            Err("missing value for --threshold".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["bench", "1", "--save"])),
+            Err("missing value for --save".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["bench", "1", "--compare"])),
+            Err("missing value for --compare".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["bench", "-P", "connections=10"])),
+            Err("--param requires a single day".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["bench", "1", "--part", "1"])),
+            Err("unexpected argument: --part".to_string())
+        );
+    }
+
+    #[test]
+    fn test_bench_save_then_compare() {
+        let path = Temp::path("bench-baseline.json");
+        assert!(bench_days(Some(1), 2, &[], Some(&path), None, 10.0));
+        let baseline = load_baseline(&path).unwrap();
+        assert_eq!(baseline.len(), 1);
+        assert_eq!(baseline[0].day, 1);
+
+        // Unlimited threshold: timing noise can never count as a regression.
+        assert!(bench_days(
+            Some(1),
+            2,
+            &[],
+            None,
+            Some(&path),
+            f64::INFINITY
+        ));
+    }
+
+    #[test]
+    fn test_bench_resolves_params() {
+        let overrides = [("connections".to_string(), "nope".to_string())];
+        assert!(!bench_days(Some(8), 1, &overrides, None, None, 10.0));
+        let overrides = [("connections".to_string(), "10".to_string())];
+        assert!(bench_days(Some(8), 1, &overrides, None, None, 10.0));
+    }
+
+    #[test]
+    fn test_bench_flags_regression() {
+        let path = Temp::path("bench-fast.json");
+        let mut fast = bench_day(find_day(1), 1, &defaults(1)).unwrap();
+        for phase in bench::Phase::ALL {
+            let stats = match phase {
+                bench::Phase::Parse => &mut fast.parse,
+                bench::Phase::Part1 => &mut fast.part1,
+                bench::Phase::Part2 => &mut fast.part2,
+            };
+            stats.median = std::time::Duration::from_nanos(1);
+        }
+        std::fs::write(&path, bench::to_json(&[fast])).unwrap();
+        assert!(!bench_days(Some(1), 1, &[], None, Some(&path), 10.0));
+    }
+
+    #[test]
+    fn test_bench_invalid_baseline() {
+        let baseline = Temp::file("bench-invalid.json", "{}");
+        let path = baseline.to_path_buf();
+        let error = load_baseline(&path).unwrap_err();
+        assert!(error.starts_with("invalid baseline "));
+        assert!(!bench_days(Some(1), 1, &[], None, Some(&path), 10.0));
+        drop(baseline);
+        assert!(load_baseline(&path)
+            .unwrap_err()
+            .starts_with("failed to read "));
+    }
+
+    #[test]
+    fn test_bench_unwritable_save_path() {
+        let path = PathBuf::from("does/not/exist/baseline.json");
+        assert!(!bench_days(Some(1), 1, &[], Some(&path), None, 10.0));
+    }
+
+    #[test]
+    fn test_run_day_wrong_answer() {
+        let wrong = Answers::parse("[day01]\npart1 = 1\npart2 = 6599\n").unwrap();
+        let result = run_day(find_day(1), None, &InputSource::Default, &defaults(1)).unwrap();
+        assert!(!is_correct(&result, &wrong));
+        assert!(is_correct(&result, &known()));
+        assert!(is_correct(&result, &Answers::default()));
+    }
+
+    #[test]
+    fn test_parse_run_format() {
+        assert_eq!(
+            parse_args(&args(&["run", "all", "--format", "json"])),
+            Ok(Command::Run {
+                day: None,
+                part: None,
+                input: InputSource::Default,
+                format: Format::Json,
+                params: vec![],
+                jobs: None,
+                limit: Limit::default(),
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "2", "-f", "csv"])),
+            Ok(Command::Run {
+                day: Some(2),
+                part: None,
+                input: InputSource::Default,
+                format: Format::Csv,
+                params: vec![],
+                jobs: None,
+                limit: Limit::default(),
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "2", "--format", "yaml"])),
+            Err("invalid format: yaml".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["run", "2", "--format"])),
+            Err("missing value for --format".to_string())
+        );
+    }
+
+    #[test]
+    fn test_execute_machine_formats() {
+        for format in [Format::Json, Format::Csv] {
+            assert!(execute(&Command::Run {
+                day: Some(3),
+                part: None,
+                input: InputSource::Default,
+                format,
+                params: vec![],
+                jobs: None,
+                limit: Limit::default(),
+            }));
+        }
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(
+            parse_args(&args(&["verify"])),
+            Ok(Command::Verify {
+                day: None,
+                update_readme: false,
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["verify", "all", "--update-readme"])),
+            Ok(Command::Verify {
+                day: None,
+                update_readme: true,
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["verify", "4"])),
+            Ok(Command::Verify {
+                day: Some(4),
+                update_readme: false,
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["verify", "4", "--fix"])),
+            Err("unexpected argument: --fix".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["verify", "0"])),
+            Err("day 0 is not implemented".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        assert_eq!(
+            parse_args(&args(&["fetch", "12"])),
+            Ok(Command::Fetch { day: 12 })
+        );
+        assert_eq!(
+            parse_args(&args(&["fetch"])),
+            Err("missing day".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["fetch", "13"])),
+            Err("invalid day: 13".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["fetch", "0"])),
+            Err("invalid day: 0".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["fetch", "1", "--force"])),
+            Err("unexpected argument: --force".to_string())
+        );
+    }
+
+    #[test]
+    fn test_fetch_existing_input_is_cached() {
+        // Every committed input is already on disk, so no session or network is needed.
+        assert!(execute(&Command::Fetch { day: 1 }));
+    }
+
+    #[test]
+    fn test_parse_submit() {
+        assert_eq!(
+            parse_args(&args(&["submit", "3", "2"])),
+            Ok(Command::Submit {
+                day: 3,
+                part: Part::Two,
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["submit", "3"])),
+            Err("missing part".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["submit"])),
+            Err("missing day".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["submit", "3", "3"])),
+            Err("invalid part: 3".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["submit", "13", "1"])),
+            Err("day 13 is not implemented".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["submit", "3", "1", "now"])),
+            Err("unexpected argument: now".to_string())
+        );
+    }
+
+    #[test]
+    fn test_submit_known_answer_is_refused() {
+        assert_eq!(
+            submit_answer(8, Part::One),
+            Err("day 08 part 1 is already solved (115885 in answers.toml)".to_string())
+        );
+        assert!(!execute(&Command::Submit {
+            day: 8,
+            part: Part::One,
+        }));
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(
+            parse_args(&args(&["new", "12"])),
+            Ok(Command::New {
+                day: 12,
+                title: "Day 12".to_string(),
+                dir: None,
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&[
+                "new",
+                "12",
+                "--title",
+                "Frozen Lake",
+                "--dir",
+                "aoc"
+            ])),
+            Ok(Command::New {
+                day: 12,
+                title: "Frozen Lake".to_string(),
+                dir: Some(PathBuf::from("aoc")),
+            })
+        );
+        assert_eq!(parse_args(&args(&["new"])), Err("missing day".to_string()));
+        assert_eq!(
+            parse_args(&args(&["new", "13"])),
+            Err("invalid day: 13".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["new", "12", "-t"])),
+            Err("missing value for --title".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["new", "12", "-d"])),
+            Err("missing value for --dir".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["new", "12", "--force"])),
+            Err("unexpected argument: --force".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_gen() {
+        assert_eq!(
+            parse_args(&args(&["gen", "7"])),
+            Ok(Command::Gen {
+                day: 7,
+                seed: DEFAULT_SEED,
+                size: None,
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["gen", "11", "--seed", "9", "-n", "50"])),
+            Ok(Command::Gen {
+                day: 11,
+                seed: 9,
+                size: Some(50),
+            })
+        );
+        assert_eq!(parse_args(&args(&["gen"])), Err("missing day".to_string()));
+        assert_eq!(
+            parse_args(&args(&["gen", "3"])),
+            Err("no generator for day 3".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["gen", "1", "--seed", "-1"])),
+            Err("invalid seed: -1".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["gen", "1", "--size", "0"])),
+            Err("invalid size: 0".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["gen", "1", "--size"])),
+            Err("missing value for --size".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["gen", "1", "--part", "1"])),
+            Err("unexpected argument: --part".to_string())
+        );
+    }
+
+    #[test]
+    fn test_execute_gen() {
+        assert!(execute(&Command::Gen {
+            day: 12,
+            seed: 1,
+            size: Some(3),
+        }));
+    }
+
+    #[test]
+    fn test_parse_shrink() {
+        assert_eq!(
+            parse_args(&args(&["shrink", "3", "-i", "big.txt", "--panics"])),
+            Ok(Command::Shrink {
+                day: 3,
+                input: PathBuf::from("big.txt"),
+                predicate: Predicate::Panics,
+                part: None,
+                output: None,
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&[
+                "shrink",
+                "9",
+                "--input",
+                "a.txt",
+                "--reference",
+                "python3 ref.py",
+                "--part",
+                "2",
+                "-o",
+                "b.txt",
+            ])),
+            Ok(Command::Shrink {
+                day: 9,
+                input: PathBuf::from("a.txt"),
+                predicate: Predicate::Differs {
+                    reference: "python3 ref.py".to_string(),
+                },
+                part: Some(Part::Two),
+                output: Some(PathBuf::from("b.txt")),
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["shrink", "3", "--panics"])),
+            Err("missing --input".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["shrink", "3", "-i", "a.txt"])),
+            Err("missing --panics or --reference".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["shrink", "30"])),
+            Err("day 30 is not implemented".to_string())
+        );
+        assert_eq!(
+            parse_args(&args(&["shrink", "3", "--reference"])),
+            Err("missing value for --reference".to_string())
+        );
+    }
+
+    #[test]
+    fn test_shrink_writes_reproducer() {
+        let mut banks = "987654321111111\n".repeat(20);
+        banks.push_str("12345\n");
+        let input = Temp::file("shrink-input", banks);
+        let output = Temp::path("shrink-input.min");
+
+        assert!(execute(&Command::Shrink {
+            day: 3,
+            input: input.to_path_buf(),
+            predicate: Predicate::Panics,
+            part: Some(Part::Two),
+            output: None,
+        }));
+        assert_eq!(std::fs::read_to_string(&output).unwrap(), "12345\n");
+
+        // Part 1 only needs two batteries, so nothing fails.
+        assert!(!execute(&Command::Shrink {
+            day: 3,
+            input: input.to_path_buf(),
+            predicate: Predicate::Panics,
+            part: Some(Part::One),
+            output: Some(output.to_path_buf()),
+        }));
+    }
+
+    #[test]
+    fn test_new_existing_day_is_refused() {
+        // Tests run in the project root, which `new` writes to by default.
+        assert!(!execute(&Command::New {
+            day: 3,
+            title: "Lobby".to_string(),
+            dir: None,
+        }));
+    }
+
+    #[test]
+    fn test_verify_days() {
+        assert!(verify_days(Some(4), None));
+        assert!(execute(&Command::Verify {
+            day: Some(5),
+            update_readme: false,
+        }));
+    }
+
+    #[test]
+    fn test_verify_updates_readme() {
+        let path = Temp::file("readme.md", "# AoC\n\n## Progress\n\n| stale |\n");
+        assert!(verify_days(Some(6), Some(&path)));
+        let readme = std::fs::read_to_string(&path).unwrap();
+        assert!(readme.starts_with("# AoC\n\n## Progress\n\n| Day | Part 1 | Part 2 |\n"));
+        assert!(readme.ends_with("| 12  | 595    | N/A    |\n"));
+    }
+
+    #[test]
+    fn test_update_readme_errors() {
+        let known = Answers::default();
+        let missing = update_readme(Path::new("does/not/exist.md"), &known).unwrap_err();
+        assert!(missing.starts_with("failed to read "));
+
+        let path = Temp::file("no-table.md", "# Nothing\n");
+        assert!(!verify_days(Some(6), Some(&path)));
     }
 }
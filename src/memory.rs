@@ -0,0 +1,206 @@
+//! A counting global allocator. Allocations are charged to a per-thread [`Budget`], so the
+//! runner can report each part's peak memory and stop a part that exceeds its limit.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// The system allocator, counting bytes against the current thread's [`Budget`].
+///
+/// Install it with `#[global_allocator]`; without it budgets see no allocations.
+pub struct Counting;
+
+thread_local! {
+    static BUDGET: Cell<*const Budget> = const { Cell::new(ptr::null()) };
+}
+
+/// Live and peak bytes of the allocations made while [`track`]ing, and a limit on them.
+#[derive(Debug)]
+pub struct Budget {
+    limit: usize,
+    live: AtomicIsize,
+    peak: AtomicUsize,
+    exceeded: AtomicBool,
+}
+
+impl Budget {
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            live: AtomicIsize::new(0),
+            peak: AtomicUsize::new(0),
+            exceeded: AtomicBool::new(false),
+        }
+    }
+
+    /// The most bytes live at once.
+    pub fn peak(&self) -> usize {
+        self.peak.load(Ordering::Relaxed)
+    }
+
+    /// Whether an allocation would have gone over the limit.
+    pub fn exceeded(&self) -> bool {
+        self.exceeded.load(Ordering::Relaxed)
+    }
+
+    fn charge(&self, bytes: usize) {
+        let live = self.live.fetch_add(bytes as isize, Ordering::Relaxed) + bytes as isize;
+        let live = live.max(0) as usize;
+        if live > self.limit {
+            // The refused allocation never happens, so it is not part of the peak.
+            self.live.fetch_sub(bytes as isize, Ordering::Relaxed);
+            self.exceeded.store(true, Ordering::Relaxed);
+            // The allocation cannot fail without aborting the process, and a thread cannot
+            // be stopped from outside, so it is parked here for good. Sleeping does not
+            // allocate.
+            loop {
+                thread::sleep(Duration::from_secs(3600));
+            }
+        }
+        self.peak.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn refund(&self, bytes: usize) {
+        self.live.fetch_sub(bytes as isize, Ordering::Relaxed);
+    }
+}
+
+/// Run `f`, charging the current thread's allocations to `budget`.
+///
+/// An allocation over the limit never returns: the thread sleeps forever, holding what it
+/// has, while whoever watches `budget` reports it.
+pub fn track<T>(budget: &Budget, f: impl FnOnce() -> T) -> T {
+    struct Restore(*const Budget);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            BUDGET.with(|current| current.set(self.0));
+        }
+    }
+
+    let _restore = Restore(BUDGET.with(|current| current.replace(budget)));
+    f()
+}
+
+fn with_budget(f: impl FnOnce(&Budget)) {
+    // The thread-local is gone while a thread is being torn down.
+    let budget = BUDGET.try_with(Cell::get).unwrap_or(ptr::null());
+    // SAFETY: `track` only installs budgets that outlive the call it wraps.
+    if let Some(budget) = unsafe { budget.as_ref() } {
+        f(budget);
+    }
+}
+
+// SAFETY: every call is forwarded to `System` unchanged.
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        with_budget(|budget| budget.charge(layout.size()));
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        with_budget(|budget| budget.charge(layout.size()));
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        with_budget(|budget| budget.refund(layout.size()));
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        with_budget(|budget| match new_size.checked_sub(layout.size()) {
+            Some(grown) => budget.charge(grown),
+            None => budget.refund(layout.size() - new_size),
+        });
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// `bytes` in MiB with one decimal, e.g. `12.5 MiB`.
+pub fn format_mib(bytes: usize) -> String {
+    format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn test_track_counts_peak() {
+        let budget = Budget::new(usize::MAX);
+        let total = track(&budget, || {
+            let big = black_box(vec![0u8; 1 << 20]);
+            drop(big);
+            let small = black_box(vec![0u8; 1000]);
+            small.len()
+        });
+        assert_eq!(total, 1000);
+        assert!(budget.peak() >= 1 << 20);
+        assert!(budget.peak() < 1 << 21);
+        assert!(!budget.exceeded());
+    }
+
+    #[test]
+    fn test_track_only_counts_inside() {
+        let budget = Budget::new(usize::MAX);
+        track(&budget, || black_box(1 + 1));
+        let outside = black_box(vec![0u8; 1 << 20]);
+        assert!(budget.peak() < 1 << 10);
+        drop(outside);
+    }
+
+    #[test]
+    fn test_track_nested_restores_outer() {
+        let outer = Budget::new(usize::MAX);
+        let inner = Budget::new(usize::MAX);
+        track(&outer, || {
+            track(&inner, || {
+                black_box(vec![0u8; 4096]);
+            });
+            black_box(vec![0u8; 100]);
+        });
+        assert!(inner.peak() >= 4096);
+        assert!(outer.peak() >= 100 && outer.peak() < 4096);
+    }
+
+    #[test]
+    fn test_track_realloc() {
+        let budget = Budget::new(usize::MAX);
+        track(&budget, || {
+            let mut v: Vec<u8> = Vec::with_capacity(10);
+            v.extend(std::iter::repeat_n(1, 100_000));
+            v.shrink_to(10);
+            black_box(v);
+        });
+        assert!(budget.peak() >= 100_000);
+    }
+
+    #[test]
+    fn test_over_limit_parks_thread() {
+        let budget = Arc::new(Budget::new(1 << 20));
+        let watched = Arc::clone(&budget);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        thread::spawn(move || {
+            track(&watched, || black_box(vec![0u8; 8 << 20]));
+            let _ = sender.send(());
+        });
+        assert!(receiver.recv_timeout(Duration::from_millis(500)).is_err());
+        assert!(budget.exceeded());
+        assert!(budget.peak() < 1 << 20);
+    }
+
+    #[test]
+    fn test_format_mib() {
+        assert_eq!(format_mib(0), "0.0 MiB");
+        assert_eq!(format_mib(3 << 19), "1.5 MiB");
+    }
+}
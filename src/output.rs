@@ -0,0 +1,399 @@
+//! Runner output as human-readable text or machine-readable JSON/CSV.
+
+use std::time::Duration;
+
+use crate::answers::{Answers, Verdict};
+use crate::json::Value;
+use crate::memory;
+use crate::registry::Run;
+use crate::solution::{Answer, Part};
+
+/// Output format selected with `--format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn from_arg(arg: &str) -> Option<Self> {
+        match arg {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        }
+    }
+}
+
+/// A solved day together with the identity of the input it ran on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayResult {
+    pub day: u8,
+    pub title: &'static str,
+    /// See [`crate::input::checksum`].
+    pub checksum: String,
+    pub run: Run,
+}
+
+/// The runner's text report for one day, marking answers that contradict `known`.
+pub fn text(result: &DayResult, known: &Answers) -> String {
+    let mut output = format!("Day {:02}: {}\n", result.day, result.title);
+    for solved in &result.run.parts {
+        output += &format!("  Part {}: {}", solved.part, solved.answer);
+        if let Verdict::Wrong { expected } = known.check(result.day, solved.part, &solved.answer) {
+            output += &format!(" (wrong, expected {expected})");
+        }
+        output += "\n";
+    }
+    output
+}
+
+/// How one part of a run ended.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Solved(Answer),
+    /// The input was missing or malformed.
+    Failed,
+    Panicked,
+    TimedOut,
+    OutOfMemory,
+}
+
+impl Status {
+    /// The mark shown for a part that has no answer.
+    fn mark(&self) -> Option<&'static str> {
+        match self {
+            Status::Solved(_) => None,
+            Status::Failed => Some("ERROR"),
+            Status::Panicked => Some("PANIC"),
+            Status::TimedOut => Some("TIMEOUT"),
+            Status::OutOfMemory => Some("OOM"),
+        }
+    }
+}
+
+/// One row of the [`summary`] table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SummaryRow {
+    pub day: u8,
+    pub title: &'static str,
+    /// The requested parts, in order.
+    pub parts: Vec<(Part, Status)>,
+    /// Wall-clock time of every part, each including parsing the input.
+    pub wall: Duration,
+    /// The most bytes any part had allocated at once.
+    pub peak_memory: usize,
+}
+
+/// A table of every day's answers, wall-clock time, peak memory and verdict against
+/// `known`, with a closing line of totals.
+///
+/// A part without an answer shows why instead, and that mark is also the day's status.
+pub fn summary(rows: &[SummaryRow], known: &Answers, wall: Duration) -> String {
+    let cells: Vec<[String; 7]> = rows
+        .iter()
+        .map(|row| {
+            let cell = |part: Part| match row.parts.iter().find(|(p, _)| *p == part) {
+                Some((_, Status::Solved(answer))) => answer.to_string(),
+                Some((_, status)) => status.mark().unwrap_or_default().to_string(),
+                None => "-".to_string(),
+            };
+            let verdicts: Vec<Verdict> = row
+                .parts
+                .iter()
+                .filter_map(|(part, status)| match status {
+                    Status::Solved(answer) => Some(known.check(row.day, *part, answer)),
+                    _ => None,
+                })
+                .collect();
+            let status = match row.parts.iter().find_map(|(_, status)| status.mark()) {
+                Some(mark) => mark,
+                None if verdicts.iter().any(|v| matches!(v, Verdict::Wrong { .. })) => "FAIL",
+                None if verdicts.iter().all(|v| *v == Verdict::Correct) => "PASS",
+                None => "SKIP",
+            };
+            [
+                format!("{:02}", row.day),
+                row.title.to_string(),
+                cell(Part::One),
+                cell(Part::Two),
+                format!("{:.2?}", row.wall),
+                memory::format_mib(row.peak_memory),
+                status.to_string(),
+            ]
+        })
+        .collect();
+
+    let header = [
+        "Day", "Title", "Part 1", "Part 2", "Time", "Memory", "Status",
+    ]
+    .map(String::from);
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            cells
+                .iter()
+                .chain([&header])
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut output = String::new();
+    for row in [&header].into_iter().chain(&cells) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect();
+        output += line.join("  ").trim_end();
+        output += "\n";
+    }
+
+    let count = |status: &str| cells.iter().filter(|row| row[6] == status).count();
+    output += &format!(
+        "{} days in {wall:.2?}: {} passed, {} failed, {} unverified, {} errors, {} panicked, {} timed out, {} out of memory\n",
+        rows.len(),
+        count("PASS"),
+        count("FAIL"),
+        count("SKIP"),
+        count("ERROR"),
+        count("PANIC"),
+        count("TIMEOUT"),
+        count("OOM"),
+    );
+    output
+}
+
+/// One JSON array with an object per day and part.
+///
+/// Answers are strings so large integers survive tools that read numbers as doubles.
+pub fn json(results: &[DayResult]) -> String {
+    let rows = rows(results)
+        .map(|row| {
+            Value::Object(vec![
+                ("day".to_string(), Value::from(row.day as u64)),
+                ("part".to_string(), Value::from(row.part as u64)),
+                ("answer".to_string(), Value::from(row.answer)),
+                ("parse_ns".to_string(), Value::from(row.parse_ns)),
+                ("solve_ns".to_string(), Value::from(row.solve_ns)),
+                ("checksum".to_string(), Value::from(row.checksum)),
+            ])
+        })
+        .collect();
+    format!("{}\n", Value::Array(rows))
+}
+
+/// CSV with a header row and one row per day and part.
+pub fn csv(results: &[DayResult]) -> String {
+    let mut output = String::from("day,part,answer,parse_ns,solve_ns,checksum\n");
+    for row in rows(results) {
+        output += &format!(
+            "{},{},{},{},{},{}\n",
+            row.day,
+            row.part,
+            csv_field(&row.answer),
+            row.parse_ns,
+            row.solve_ns,
+            row.checksum
+        );
+    }
+    output
+}
+
+struct Row<'a> {
+    day: u8,
+    part: u8,
+    answer: String,
+    parse_ns: u64,
+    solve_ns: u64,
+    checksum: &'a str,
+}
+
+fn rows(results: &[DayResult]) -> impl Iterator<Item = Row<'_>> {
+    results.iter().flat_map(|result| {
+        result.run.parts.iter().map(|solved| Row {
+            day: result.day,
+            part: solved.part.number(),
+            answer: solved.answer.to_string(),
+            parse_ns: result.run.parse_time.as_nanos() as u64,
+            solve_ns: solved.time.as_nanos() as u64,
+            checksum: &result.checksum,
+        })
+    })
+}
+
+/// Quote a field containing separators, quotes or line breaks.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json;
+    use crate::registry::Solved;
+
+    fn result(day: u8, answers: &[(Part, Answer)]) -> DayResult {
+        DayResult {
+            day,
+            title: "Sample",
+            checksum: "00ff".to_string(),
+            run: Run {
+                parse_time: Duration::from_nanos(1500),
+                parts: answers
+                    .iter()
+                    .map(|(part, answer)| Solved {
+                        part: *part,
+                        answer: answer.clone(),
+                        time: Duration::from_nanos(20 + part.number() as u64),
+                    })
+                    .collect(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_format_from_arg() {
+        assert_eq!(Format::from_arg("text"), Some(Format::Text));
+        assert_eq!(Format::from_arg("json"), Some(Format::Json));
+        assert_eq!(Format::from_arg("csv"), Some(Format::Csv));
+        assert_eq!(Format::from_arg("xml"), None);
+        assert_eq!(Format::default(), Format::Text);
+    }
+
+    #[test]
+    fn test_text() {
+        let known = Answers::parse("[day03]\npart1 = 5\npart2 = 7\n").unwrap();
+        let result = result(
+            3,
+            &[(Part::One, Answer::Int(5)), (Part::Two, Answer::Int(8))],
+        );
+        assert_eq!(
+            text(&result, &known),
+            "Day 03: Sample\n  Part 1: 5\n  Part 2: 8 (wrong, expected 7)\n"
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let known = Answers::parse("[day01]\npart1 = 3\npart2 = 6\n[day02]\npart1 = 1\n").unwrap();
+        let solved = |n: i64| Status::Solved(Answer::Int(n));
+        let row = |day, parts: Vec<(Part, Status)>, millis, mib: usize| SummaryRow {
+            day,
+            title: "Sample",
+            parts,
+            wall: Duration::from_millis(millis),
+            peak_memory: mib << 20,
+        };
+        let rows = [
+            row(
+                1,
+                vec![(Part::One, solved(3)), (Part::Two, solved(6))],
+                12,
+                1,
+            ),
+            row(2, vec![(Part::One, solved(2))], 3, 0),
+            row(3, vec![(Part::Two, solved(12345678))], 1500, 250),
+            row(
+                4,
+                vec![(Part::One, Status::Failed), (Part::Two, Status::Failed)],
+                0,
+                0,
+            ),
+            row(
+                5,
+                vec![(Part::One, solved(1)), (Part::Two, Status::Panicked)],
+                1,
+                0,
+            ),
+            row(
+                6,
+                vec![(Part::One, Status::TimedOut), (Part::Two, solved(9))],
+                60000,
+                3,
+            ),
+            row(7, vec![(Part::One, Status::OutOfMemory)], 40, 1025),
+        ];
+        assert_eq!(
+            summary(&rows, &known, Duration::from_millis(60010)),
+            "\
+Day  Title   Part 1   Part 2    Time     Memory      Status
+01   Sample  3        6         12.00ms  1.0 MiB     PASS
+02   Sample  2        -         3.00ms   0.0 MiB     FAIL
+03   Sample  -        12345678  1.50s    250.0 MiB   SKIP
+04   Sample  ERROR    ERROR     0.00ns   0.0 MiB     ERROR
+05   Sample  1        PANIC     1.00ms   0.0 MiB     PANIC
+06   Sample  TIMEOUT  9         60.00s   3.0 MiB     TIMEOUT
+07   Sample  OOM      -         40.00ms  1025.0 MiB  OOM
+7 days in 60.01s: 1 passed, 1 failed, 1 unverified, 1 errors, 1 panicked, 1 timed out, 1 out of memory
+"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let results = [
+            result(
+                1,
+                &[(Part::One, Answer::Int(3)), (Part::Two, Answer::Int(6))],
+            ),
+            result(12, &[(Part::Two, Answer::NotApplicable)]),
+        ];
+        let output = json(&results);
+        assert_eq!(
+            output.lines().next().unwrap(),
+            concat!(
+                r#"[{"day":1,"part":1,"answer":"3","parse_ns":1500,"solve_ns":21,"checksum":"00ff"},"#,
+                r#"{"day":1,"part":2,"answer":"6","parse_ns":1500,"solve_ns":22,"checksum":"00ff"},"#,
+                r#"{"day":12,"part":2,"answer":"N/A","parse_ns":1500,"solve_ns":22,"checksum":"00ff"}]"#
+            )
+        );
+        assert!(json::parse(&output).is_ok());
+    }
+
+    #[test]
+    fn test_json_empty() {
+        assert_eq!(json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn test_json_keeps_big_answers_exact() {
+        let big = i128::MAX;
+        let output = json(&[result(2, &[(Part::One, Answer::BigInt(big))])]);
+        let value = json::parse(&output).unwrap();
+        let answer = value.as_array().unwrap()[0].get("answer").unwrap();
+        assert_eq!(answer.as_str(), Some(big.to_string().as_str()));
+    }
+
+    #[test]
+    fn test_csv() {
+        let results = [result(
+            5,
+            &[
+                (Part::One, Answer::Int(694)),
+                (Part::Two, Answer::from("a,\"b\"")),
+            ],
+        )];
+        assert_eq!(
+            csv(&results),
+            "\
+day,part,answer,parse_ns,solve_ns,checksum
+5,1,694,1500,21,00ff
+5,2,\"a,\"\"b\"\"\",1500,22,00ff
+"
+        );
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+}
@@ -0,0 +1,412 @@
+//! A small std-thread pool for running days concurrently, each job with a wall-clock and
+//! memory limit and isolated from panics in the others.
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::memory::{self, Budget};
+
+/// How long one job may run before it is reported as timed out.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+/// How many bytes one job may have allocated at once before it is reported as out of memory.
+pub const DEFAULT_MEMORY: usize = 1 << 30;
+/// How often running jobs are checked against their memory limit.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+/// Stack of each job's thread. Solvers may recurse as deep as their input is long, such as
+/// day 11 along a chain of devices, far past the 2 MiB that threads get by default; the
+/// memory is only reserved, not committed.
+pub const STACK_SIZE: usize = 256 << 20;
+
+/// What one job may use. Memory is only counted with [`memory::Counting`] installed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limit {
+    pub time: Duration,
+    /// Bytes.
+    pub memory: usize,
+}
+
+impl Default for Limit {
+    fn default() -> Self {
+        Self {
+            time: DEFAULT_TIMEOUT,
+            memory: DEFAULT_MEMORY,
+        }
+    }
+}
+
+/// How a job ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Finished(T),
+    Panicked(String),
+    TimedOut,
+    OutOfMemory,
+}
+
+/// A job's outcome, the wall-clock time it took, or was given before being stopped, and
+/// the most bytes it had allocated at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report<T> {
+    pub outcome: Outcome<T>,
+    pub elapsed: Duration,
+    pub peak_memory: usize,
+    /// Threads of timed-out jobs still running when this one started, competing with it
+    /// for the CPU.
+    pub abandoned: usize,
+}
+
+/// The number of threads to use when none is given: one per available core.
+pub fn default_workers() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Run `jobs` on at most `workers` threads at a time, each within its [`Limit`], returning
+/// a report per job in order.
+///
+/// A job still running after its time limit is reported as [`Outcome::TimedOut`], and one
+/// that allocates past its memory limit as [`Outcome::OutOfMemory`]. Its thread cannot be
+/// stopped and is abandoned, running on until it returns or the runner exits. A timed-out
+/// thread keeps its worker slot until it ends, or for one more time limit, so that it does
+/// not slow down the jobs after it; a thread parked out of memory no longer uses the CPU
+/// and gives up its slot at once.
+pub fn run<T, F>(jobs: Vec<(Limit, F)>, workers: usize) -> Vec<Report<T>>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    struct Running {
+        index: usize,
+        start: Instant,
+        limit: Limit,
+        budget: Arc<Budget>,
+        handle: JoinHandle<()>,
+        abandoned: usize,
+    }
+
+    /// A timed-out job's thread and when it gives up its slot.
+    struct Abandoned {
+        handle: JoinHandle<()>,
+        release: Instant,
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let mut reports: Vec<Option<Report<T>>> = jobs.iter().map(|_| None).collect();
+    let mut pending = jobs.into_iter().enumerate().peekable();
+    let mut running: Vec<Running> = Vec::new();
+    let mut abandoned: Vec<Abandoned> = Vec::new();
+
+    loop {
+        abandoned.retain(|thread| !thread.handle.is_finished());
+        let now = Instant::now();
+        let held = abandoned
+            .iter()
+            .filter(|thread| now < thread.release)
+            .count();
+        while running.len() + held < workers.max(1) {
+            let Some((index, (limit, job))) = pending.next() else {
+                break;
+            };
+            let budget = Arc::new(Budget::new(limit.memory));
+            let sender = sender.clone();
+            let tracked = Arc::clone(&budget);
+            let handle = thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn(move || {
+                    let start = Instant::now();
+                    let result =
+                        memory::track(&tracked, || panic::catch_unwind(AssertUnwindSafe(job)));
+                    let outcome = match result {
+                        Ok(value) => Outcome::Finished(value),
+                        Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+                    };
+                    let report = Report {
+                        outcome,
+                        elapsed: start.elapsed(),
+                        peak_memory: tracked.peak(),
+                        abandoned: 0,
+                    };
+                    // The receiver is gone once every job has been reported.
+                    let _ = sender.send((index, report));
+                })
+                .expect("failed to spawn a job thread");
+            running.push(Running {
+                index,
+                start: Instant::now(),
+                limit,
+                budget,
+                handle,
+                abandoned: abandoned.len(),
+            });
+        }
+
+        if running.is_empty() && pending.peek().is_none() {
+            break;
+        }
+        let deadline = running
+            .iter()
+            .map(|job| job.start + job.limit.time)
+            .chain(abandoned.iter().map(|thread| thread.release))
+            .min()
+            .unwrap_or(now);
+        let wait = deadline
+            .saturating_duration_since(Instant::now())
+            .min(POLL_INTERVAL);
+        match receiver.recv_timeout(wait) {
+            // A job that was already stopped may still finish late; its report stands.
+            Ok((index, report)) => {
+                if let Some(position) = running.iter().position(|job| job.index == index) {
+                    let job = running.remove(position);
+                    reports[index] = Some(Report {
+                        abandoned: job.abandoned,
+                        ..report
+                    });
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => unreachable!("the pool holds a sender"),
+        }
+
+        // Checked after every message too, so jobs finishing one after another cannot
+        // delay reporting one that is out of memory.
+        let now = Instant::now();
+        let (stopped, still_running): (Vec<Running>, Vec<Running>) = running
+            .into_iter()
+            .partition(|job| job.budget.exceeded() || now - job.start >= job.limit.time);
+        running = still_running;
+        for job in stopped {
+            let outcome = if job.budget.exceeded() {
+                Outcome::OutOfMemory
+            } else {
+                abandoned.push(Abandoned {
+                    handle: job.handle,
+                    release: now + job.limit.time,
+                });
+                Outcome::TimedOut
+            };
+            reports[job.index] = Some(Report {
+                outcome,
+                elapsed: now - job.start,
+                peak_memory: job.budget.peak(),
+                abandoned: job.abandoned,
+            });
+        }
+    }
+
+    reports
+        .into_iter()
+        .map(|report| report.expect("every job is reported"))
+        .collect()
+}
+
+/// The message a panic was raised with, or an empty string for non-string payloads.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+
+    fn unlimited<F>(jobs: Vec<F>) -> Vec<(Limit, F)> {
+        jobs.into_iter()
+            .map(|job| (Limit::default(), job))
+            .collect()
+    }
+
+    fn outcomes<T>(reports: Vec<Report<T>>) -> Vec<Outcome<T>> {
+        reports.into_iter().map(|report| report.outcome).collect()
+    }
+
+    #[test]
+    fn test_run_keeps_job_order() {
+        let jobs: Vec<_> = (0..20u64)
+            .map(|i| {
+                move || {
+                    thread::sleep(Duration::from_millis(20 - i));
+                    i * i
+                }
+            })
+            .collect();
+        let expected: Vec<_> = (0..20).map(|i| Outcome::Finished(i * i)).collect();
+        assert_eq!(outcomes(run(unlimited(jobs), 4)), expected);
+    }
+
+    #[test]
+    fn test_run_isolates_panics() {
+        let jobs: Vec<Box<dyn FnOnce() -> u8 + Send>> = vec![
+            Box::new(|| 1),
+            Box::new(|| panic!("day 2 broke")),
+            Box::new(|| panic!("{}", String::from("owned"))),
+            Box::new(|| 4),
+        ];
+        assert_eq!(
+            outcomes(run(unlimited(jobs), 2)),
+            vec![
+                Outcome::Finished(1),
+                Outcome::Panicked("day 2 broke".to_string()),
+                Outcome::Panicked("owned".to_string()),
+                Outcome::Finished(4),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_times_out_slow_jobs() {
+        let jobs: Vec<Box<dyn FnOnce() -> u8 + Send>> = vec![
+            Box::new(|| {
+                thread::sleep(Duration::from_secs(30));
+                1
+            }),
+            Box::new(|| 2),
+            Box::new(|| 3),
+        ];
+        let start = Instant::now();
+        let limit = Limit {
+            time: Duration::from_millis(100),
+            ..Limit::default()
+        };
+        let reports = run(jobs.into_iter().map(|job| (limit, job)).collect(), 2);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(reports[0].elapsed >= Duration::from_millis(100));
+        assert_eq!(
+            outcomes(reports),
+            vec![
+                Outcome::TimedOut,
+                Outcome::Finished(2),
+                Outcome::Finished(3)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_limits_workers() {
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let jobs: Vec<_> = (0..12)
+            .map(|_| {
+                let (active, peak) = (Arc::clone(&active), Arc::clone(&peak));
+                move || {
+                    let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(10));
+                    active.fetch_sub(1, Ordering::SeqCst);
+                }
+            })
+            .collect();
+        let reports = run(unlimited(jobs), 3);
+        assert_eq!(reports.len(), 12);
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+        assert_eq!(run(unlimited(Vec::<fn() -> u8>::new()), 3), vec![]);
+        assert_eq!(
+            outcomes(run(unlimited(vec![|| 7]), 0)),
+            vec![Outcome::Finished(7)]
+        );
+    }
+
+    #[test]
+    fn test_run_stops_jobs_out_of_memory() {
+        let jobs: Vec<Box<dyn FnOnce() -> usize + Send>> = vec![
+            Box::new(|| std::hint::black_box(vec![0u8; 64 << 20]).len()),
+            Box::new(|| std::hint::black_box(vec![0u8; 1 << 10]).len()),
+        ];
+        let limit = Limit {
+            memory: 16 << 20,
+            ..Limit::default()
+        };
+        let reports = run(jobs.into_iter().map(|job| (limit, job)).collect(), 2);
+        assert_eq!(reports[0].outcome, Outcome::OutOfMemory);
+        // The allocation that was refused is not part of the peak.
+        assert!(reports[0].peak_memory < 16 << 20);
+        assert_eq!(reports[1].outcome, Outcome::Finished(1 << 10));
+        assert!(reports[1].peak_memory >= 1 << 10 && reports[1].peak_memory < 16 << 20);
+    }
+
+    #[test]
+    fn test_run_notices_out_of_memory_between_jobs() {
+        // A stream of jobs finishing every few milliseconds must not hide the one that is
+        // parked out of memory until they are all done.
+        let mut jobs: Vec<Box<dyn FnOnce() -> usize + Send>> =
+            vec![Box::new(|| std::hint::black_box(vec![0u8; 64 << 20]).len())];
+        for _ in 0..100 {
+            jobs.push(Box::new(|| {
+                thread::sleep(Duration::from_millis(5));
+                0
+            }));
+        }
+        let limit = Limit {
+            memory: 16 << 20,
+            ..Limit::default()
+        };
+        let reports = run(jobs.into_iter().map(|job| (limit, job)).collect(), 2);
+        assert_eq!(reports[0].outcome, Outcome::OutOfMemory);
+        assert!(
+            reports[0].elapsed < Duration::from_millis(250),
+            "{:?}",
+            reports[0]
+        );
+    }
+
+    #[test]
+    fn test_run_holds_slots_of_timed_out_threads() {
+        let start = Instant::now();
+        let job = |sleep: u64| {
+            move || {
+                thread::sleep(Duration::from_millis(sleep));
+                start.elapsed()
+            }
+        };
+        let limit = Limit {
+            time: Duration::from_millis(100),
+            ..Limit::default()
+        };
+        // The first thread ends at 150ms, within its extra time limit, so the second job
+        // waits for it.
+        let reports = run(vec![(limit, job(150)), (limit, job(0))], 1);
+        assert_eq!(reports[0].outcome, Outcome::TimedOut);
+        assert!(
+            matches!(reports[1].outcome, Outcome::Finished(at) if at >= Duration::from_millis(150))
+        );
+        assert_eq!(reports[1].abandoned, 0);
+
+        // One that runs on gives up its slot after another 100ms, and the job after it
+        // reports running alongside it.
+        let start = Instant::now();
+        let reports = run(vec![(limit, job(2000)), (limit, job(0))], 1);
+        assert!(start.elapsed() < Duration::from_millis(1500));
+        assert_eq!(reports[0].outcome, Outcome::TimedOut);
+        assert!(matches!(reports[1].outcome, Outcome::Finished(_)));
+        assert_eq!(reports[1].abandoned, 1);
+    }
+
+    #[test]
+    fn test_run_gives_jobs_deep_stacks() {
+        fn depth(n: u64) -> u64 {
+            let frame = std::hint::black_box([0u8; 256]);
+            match n {
+                0 => frame[0] as u64,
+                _ => 1 + depth(std::hint::black_box(n - 1)),
+            }
+        }
+        // About 30 MiB of stack, far past the 2 MiB default.
+        assert_eq!(
+            outcomes(run(unlimited(vec![|| depth(100_000)]), 1)),
+            vec![Outcome::Finished(100_000)]
+        );
+    }
+
+    #[test]
+    fn test_default_workers() {
+        assert!(default_workers() >= 1);
+    }
+}
@@ -0,0 +1,325 @@
+//! Tunable puzzle parameters: declared with defaults by each day, set per day in `aoc.toml`
+//! and overridden with `--param key=value`.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::toml::{self, Value};
+
+/// `aoc.toml` at the project root.
+pub fn default_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("aoc.toml")
+}
+
+/// The values a parameter accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Int { min: i64, max: i64 },
+    Text,
+}
+
+/// A parameter a day reads, with its default as it would be written in `aoc.toml`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub kind: Kind,
+    pub default: &'static str,
+    pub help: &'static str,
+}
+
+impl Param {
+    pub const fn int(
+        name: &'static str,
+        default: &'static str,
+        min: i64,
+        max: i64,
+        help: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            kind: Kind::Int { min, max },
+            default,
+            help,
+        }
+    }
+
+    pub const fn text(name: &'static str, default: &'static str, help: &'static str) -> Self {
+        Self {
+            name,
+            kind: Kind::Text,
+            default,
+            help,
+        }
+    }
+
+    fn check(&self, value: &str) -> Result<(), String> {
+        match self.kind {
+            Kind::Int { min, max } => match value.parse::<i64>() {
+                Ok(n) if (min..=max).contains(&n) => Ok(()),
+                _ => Err(format!(
+                    "parameter `{}`: expected an integer from {min} to {max}, got `{value}`",
+                    self.name
+                )),
+            },
+            Kind::Text if value.trim().is_empty() => {
+                Err(format!("parameter `{}`: expected text", self.name))
+            }
+            Kind::Text => Ok(()),
+        }
+    }
+}
+
+/// Checked values for every parameter of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<&'static str, String>,
+    is_default: bool,
+}
+
+impl Params {
+    /// Every parameter at its default.
+    pub fn defaults(declared: &[Param]) -> Self {
+        Self {
+            values: declared
+                .iter()
+                .map(|param| (param.name, param.default.to_string()))
+                .collect(),
+            is_default: true,
+        }
+    }
+
+    /// The defaults of `declared` with `overrides` applied in order, rejecting unknown names
+    /// and values of the wrong kind.
+    pub fn resolve(declared: &[Param], overrides: &[(String, String)]) -> Result<Self, String> {
+        let mut params = Self::defaults(declared);
+        for (name, value) in overrides {
+            let param = declared.iter().find(|p| p.name == name).ok_or_else(|| {
+                let known: Vec<&str> = declared.iter().map(|p| p.name).collect();
+                if known.is_empty() {
+                    format!("unknown parameter `{name}`: the day has none")
+                } else {
+                    format!(
+                        "unknown parameter `{name}`, expected one of: {}",
+                        known.join(", ")
+                    )
+                }
+            })?;
+            param.check(value)?;
+            params.values.insert(param.name, value.clone());
+        }
+        params.is_default = declared
+            .iter()
+            .all(|param| params.values[param.name] == param.default);
+        Ok(params)
+    }
+
+    /// Whether every parameter has its default, so known answers still apply.
+    pub fn is_default(&self) -> bool {
+        self.is_default
+    }
+
+    /// An integer parameter. Panics when the day did not declare `name` as one.
+    pub fn int(&self, name: &str) -> i64 {
+        self.text(name)
+            .parse()
+            .unwrap_or_else(|_| panic!("parameter `{name}` is not an integer"))
+    }
+
+    /// A text parameter. Panics when the day did not declare `name`.
+    pub fn text(&self, name: &str) -> &str {
+        self.values
+            .get(name)
+            .unwrap_or_else(|| panic!("undeclared parameter `{name}`"))
+    }
+}
+
+/// Parameter values by day, from `aoc.toml`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Config {
+    days: BTreeMap<u8, Vec<(String, String)>>,
+}
+
+impl Config {
+    /// Parse `[dayXX]` sections of `name = value` pairs; values are checked by
+    /// [`Params::resolve`] once the day is known.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let document = toml::parse(text)?;
+        let mut days = BTreeMap::new();
+
+        for section in &document.sections {
+            if section.name.is_empty() && section.entries.is_empty() {
+                continue;
+            }
+            let day = section
+                .name
+                .strip_prefix("day")
+                .and_then(|n| n.parse::<u8>().ok())
+                .ok_or_else(|| format!("invalid section `{}`, expected `dayXX`", section.name))?;
+            let values = section
+                .entries
+                .iter()
+                .map(|(key, value)| match value {
+                    Value::Integer(n) => Ok((key.clone(), n.to_string())),
+                    Value::String(s) => Ok((key.clone(), s.clone())),
+                    _ => Err(format!("day{day:02}.{key}: expected integer or string")),
+                })
+                .collect::<Result<_, _>>()?;
+            days.insert(day, values);
+        }
+
+        Ok(Self { days })
+    }
+
+    /// Load `path`, treating a missing file as no settings.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|error| format!("{}: {error}", path.display())),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("failed to read {}: {error}", path.display())),
+        }
+    }
+
+    pub fn load_default() -> Result<Self, String> {
+        Self::load(&default_path())
+    }
+
+    /// The parameters of `day`: its declared defaults, then this config, then `overrides`.
+    pub fn params(
+        &self,
+        day: u8,
+        declared: &[Param],
+        overrides: &[(String, String)],
+    ) -> Result<Params, String> {
+        let mut values = self.days.get(&day).cloned().unwrap_or_default();
+        values.extend_from_slice(overrides);
+        Params::resolve(declared, &values).map_err(|error| format!("day {day:02}: {error}"))
+    }
+}
+
+/// Split a `--param key=value` argument.
+pub fn parse_override(arg: &str) -> Result<(String, String), String> {
+    arg.split_once('=')
+        .filter(|(key, value)| !key.is_empty() && !value.is_empty())
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .ok_or_else(|| format!("invalid parameter `{arg}`, expected `key=value`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECLARED: &[Param] = &[
+        Param::int("connections", "1000", 0, 1_000_000, "pairs to connect"),
+        Param::text("from", "you", "start device"),
+    ];
+
+    fn overrides(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_defaults() {
+        let params = Params::defaults(DECLARED);
+        assert_eq!(params.int("connections"), 1000);
+        assert_eq!(params.text("from"), "you");
+        assert!(params.is_default());
+    }
+
+    #[test]
+    fn test_resolve() {
+        let params = Params::resolve(DECLARED, &overrides(&[("connections", "10")])).unwrap();
+        assert_eq!(params.int("connections"), 10);
+        assert_eq!(params.text("from"), "you");
+        assert!(!params.is_default());
+
+        let params = Params::resolve(
+            DECLARED,
+            &overrides(&[("from", "svr"), ("from", "you"), ("connections", "1000")]),
+        )
+        .unwrap();
+        assert!(params.is_default());
+    }
+
+    #[test]
+    fn test_resolve_errors() {
+        assert_eq!(
+            Params::resolve(DECLARED, &overrides(&[("k", "2")])),
+            Err("unknown parameter `k`, expected one of: connections, from".to_string())
+        );
+        assert_eq!(
+            Params::resolve(&[], &overrides(&[("k", "2")])),
+            Err("unknown parameter `k`: the day has none".to_string())
+        );
+        for value in ["ten", "-1", "1000001", "1.5"] {
+            assert_eq!(
+                Params::resolve(DECLARED, &overrides(&[("connections", value)])),
+                Err(format!(
+                    "parameter `connections`: expected an integer from 0 to 1000000, got `{value}`"
+                ))
+            );
+        }
+        assert_eq!(
+            Params::resolve(DECLARED, &overrides(&[("from", " ")])),
+            Err("parameter `from`: expected text".to_string())
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "undeclared parameter `k`")]
+    fn test_undeclared_parameter() {
+        Params::defaults(DECLARED).int("k");
+    }
+
+    #[test]
+    fn test_config() {
+        let config = Config::parse("[day08]\nconnections = 10\nfrom = \"svr\"\n").unwrap();
+        let params = config
+            .params(8, DECLARED, &overrides(&[("connections", "20")]))
+            .unwrap();
+        assert_eq!(params.int("connections"), 20);
+        assert_eq!(params.text("from"), "svr");
+        assert!(config.params(3, DECLARED, &[]).unwrap().is_default());
+        assert_eq!(
+            config.params(3, &[], &overrides(&[("x", "1")])),
+            Err("day 03: unknown parameter `x`: the day has none".to_string())
+        );
+    }
+
+    #[test]
+    fn test_config_errors() {
+        assert_eq!(
+            Config::parse("[eighth]\nx = 1"),
+            Err("invalid section `eighth`, expected `dayXX`".to_string())
+        );
+        assert_eq!(
+            Config::parse("[day08]\nx = 1.5"),
+            Err("day08.x: expected integer or string".to_string())
+        );
+        assert_eq!(
+            Config::load(Path::new("does/not/exist.toml")),
+            Ok(Config::default())
+        );
+        assert!(Config::load_default().is_ok());
+    }
+
+    #[test]
+    fn test_parse_override() {
+        assert_eq!(
+            parse_override("k=12"),
+            Ok(("k".to_string(), "12".to_string()))
+        );
+        assert_eq!(
+            parse_override("via=a=b"),
+            Ok(("via".to_string(), "a=b".to_string()))
+        );
+        for arg in ["k", "=1", "k="] {
+            assert_eq!(
+                parse_override(arg),
+                Err(format!("invalid parameter `{arg}`, expected `key=value`"))
+            );
+        }
+    }
+}
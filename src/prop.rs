@@ -0,0 +1,181 @@
+//! Seeded property testing: run a property over thousands of generated cases and report the
+//! seed of the first failure so it can be replayed.
+
+use std::fmt;
+
+use crate::rng::Rng;
+
+/// Cases per property unless `AOC_PROP_CASES` says otherwise.
+pub const DEFAULT_CASES: u64 = 2000;
+/// Seed of the first case unless `AOC_PROP_SEED` says otherwise.
+pub const DEFAULT_SEED: u64 = 2025;
+
+/// Which cases to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub seed: u64,
+    pub cases: u64,
+}
+
+impl Config {
+    /// `AOC_PROP_SEED=<seed>` replays a single case; `AOC_PROP_CASES=<n>` changes the count.
+    pub fn from_env() -> Self {
+        let var = |name| std::env::var(name).ok().and_then(|v| v.parse().ok());
+        Self::from_vars(var("AOC_PROP_SEED"), var("AOC_PROP_CASES"))
+    }
+
+    pub fn from_vars(seed: Option<u64>, cases: Option<u64>) -> Self {
+        match seed {
+            Some(seed) => Self {
+                seed,
+                cases: cases.unwrap_or(1),
+            },
+            None => Self {
+                seed: DEFAULT_SEED,
+                cases: cases.unwrap_or(DEFAULT_CASES),
+            },
+        }
+    }
+}
+
+/// A failing case: its seed, the generated input and what went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub seed: u64,
+    pub input: String,
+    pub message: String,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "seed {} (replay with AOC_PROP_SEED={}): {}\ninput: {}",
+            self.seed, self.seed, self.message, self.input
+        )
+    }
+}
+
+/// Run `property` on inputs from `generate`, one fresh [`Rng`] per case seeded with
+/// `config.seed + i`, stopping at the first failure.
+pub fn run<T: fmt::Debug>(
+    config: Config,
+    generate: impl Fn(&mut Rng) -> T,
+    property: impl Fn(&T) -> Result<(), String>,
+) -> Result<(), Failure> {
+    for i in 0..config.cases {
+        let seed = config.seed.wrapping_add(i);
+        let input = generate(&mut Rng::new(seed));
+        if let Err(message) = property(&input) {
+            return Err(Failure {
+                seed,
+                input: format!("{input:?}"),
+                message,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// [`run`] with [`Config::from_env`], panicking with the failing seed.
+pub fn check<T: fmt::Debug>(
+    name: &str,
+    generate: impl Fn(&mut Rng) -> T,
+    property: impl Fn(&T) -> Result<(), String>,
+) {
+    if let Err(failure) = run(Config::from_env(), generate, property) {
+        panic!("property `{name}` failed for {failure}");
+    }
+}
+
+/// Compare an optimized result with its naive reference.
+pub fn agree<T: PartialEq + fmt::Debug>(optimized: T, naive: T) -> Result<(), String> {
+    if optimized == naive {
+        Ok(())
+    } else {
+        Err(format!("optimized {optimized:?}, naive {naive:?}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_from_vars() {
+        assert_eq!(
+            Config::from_vars(None, None),
+            Config {
+                seed: DEFAULT_SEED,
+                cases: DEFAULT_CASES,
+            }
+        );
+        assert_eq!(
+            Config::from_vars(Some(9), None),
+            Config { seed: 9, cases: 1 }
+        );
+        assert_eq!(
+            Config::from_vars(None, Some(10)),
+            Config {
+                seed: DEFAULT_SEED,
+                cases: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn test_run_passes() {
+        let config = Config {
+            seed: 0,
+            cases: 500,
+        };
+        assert_eq!(
+            run(config, |rng| rng.range(0..=100), |n| agree(n + n, 2 * n)),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_run_reports_seed_and_replays() {
+        let config = Config {
+            seed: 100,
+            cases: 1000,
+        };
+        let generate = |rng: &mut Rng| rng.range(0..=50);
+        let property = |n: &i64| agree(n % 7 == 3, false);
+
+        let failure = run(config, generate, property).unwrap_err();
+        assert_eq!(failure.message, "optimized true, naive false");
+        assert_eq!(failure.input.parse::<i64>().unwrap() % 7, 3);
+
+        // The reported seed alone reproduces the same input.
+        let replay = Config {
+            seed: failure.seed,
+            cases: 1,
+        };
+        assert_eq!(run(replay, generate, property), Err(failure.clone()));
+        assert!(failure.to_string().starts_with(&format!(
+            "seed {0} (replay with AOC_PROP_SEED={0}): ",
+            failure.seed
+        )));
+    }
+
+    #[test]
+    #[should_panic(expected = "property `always fails` failed for seed ")]
+    fn test_check_panics_with_seed() {
+        check(
+            "always fails",
+            |rng| rng.bool(),
+            |_| Err("nope".to_string()),
+        );
+    }
+
+    #[test]
+    fn test_agree() {
+        assert_eq!(agree(1, 1), Ok(()));
+        assert_eq!(
+            agree("a", "b"),
+            Err("optimized \"a\", naive \"b\"".to_string())
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! `aoc-2025 query`: where day 1's dial pointed, and how often it hit a marker, partway
+//! through its moves.
+
+use crate::day01;
+use crate::dial::DialHistory;
+use crate::input::InputSource;
+
+/// A question about day 1's dial over its move list, numbering moves from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Query {
+    /// `k`: where the dial pointed after move `k`; 0 is the start.
+    Position(usize),
+    /// `i-j`: zero passes and landings during moves `i` to `j`, both included.
+    Range(usize, usize),
+}
+
+/// Read a query written as `k` or `i-j`.
+pub fn parse(value: &str) -> Result<Query, String> {
+    let invalid = || format!("invalid query: {value}, expected `k` or `i-j`");
+    match value.split_once('-') {
+        Some((first, last)) => {
+            let first = first.parse().map_err(|_| invalid())?;
+            let last = last.parse().map_err(|_| invalid())?;
+            Ok(Query::Range(first, last))
+        }
+        None => value.parse().map(Query::Position).map_err(|_| invalid()),
+    }
+}
+
+/// The line answering `query`, or why `history` cannot answer it.
+pub fn answer(history: &DialHistory, query: Query) -> Result<String, String> {
+    let moves = history.len();
+    match query {
+        Query::Position(k) => history
+            .position_after(k)
+            .map(|position| format!("after move {k}: {position}"))
+            .ok_or_else(|| format!("move {k} is past the last move ({moves})")),
+        Query::Range(first, last) => {
+            match (
+                history.hits_between(first, last),
+                history.landings_between(first, last),
+            ) {
+                (Some(passes), Some(landings)) => Ok(format!(
+                    "moves {first}-{last}: {passes} passes, {landings} landings"
+                )),
+                _ => Err(format!(
+                    "moves {first}-{last} are not a range within 1-{moves}"
+                )),
+            }
+        }
+    }
+}
+
+/// Answer `queries` about day 1's dial, as set up by `aoc.toml` and `overrides`, over the
+/// moves in `source`, reading them from stdin, one per line, when there are none.
+///
+/// Fails before any query when the dial or the moves cannot be set up; otherwise returns
+/// each query's answer or error in order.
+pub fn run(
+    queries: &[Query],
+    source: &InputSource,
+    overrides: &[(String, String)],
+) -> Result<Vec<Result<String, String>>, String> {
+    let (dial, moves) = day01::load_with(source, overrides, day01::parse_moves)?;
+    let history = day01::history_on(&moves, dial);
+
+    let queries: Vec<Result<Query, String>> = if queries.is_empty() {
+        std::io::stdin()
+            .lines()
+            .map_while(Result::ok)
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| parse(line.trim()))
+            .collect()
+    } else {
+        queries.iter().copied().map(Ok).collect()
+    };
+    Ok(queries
+        .into_iter()
+        .map(|query| query.and_then(|query| answer(&history, query)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::temp::Temp;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("3"), Ok(Query::Position(3)));
+        assert_eq!(parse("2-7"), Ok(Query::Range(2, 7)));
+        for value in ["x", "1-", "-1", "1-2-3"] {
+            assert_eq!(
+                parse(value),
+                Err(format!("invalid query: {value}, expected `k` or `i-j`"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_answer() {
+        let history = day01::history("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82").unwrap();
+        for (query, expected) in [
+            (Query::Position(0), Ok("after move 0: 50")),
+            (Query::Position(3), Ok("after move 3: 0")),
+            (Query::Range(1, 10), Ok("moves 1-10: 6 passes, 3 landings")),
+            (Query::Range(4, 5), Ok("moves 4-5: 1 passes, 0 landings")),
+            (
+                Query::Position(11),
+                Err("move 11 is past the last move (10)"),
+            ),
+            (
+                Query::Range(0, 2),
+                Err("moves 0-2 are not a range within 1-10"),
+            ),
+        ] {
+            assert_eq!(
+                answer(&history, query),
+                expected.map(str::to_string).map_err(str::to_string)
+            );
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let input = Temp::file("query-run.txt", "L68\nL30\nR48");
+        let source = InputSource::Path(input.to_path_buf());
+        let markers = |markers: &str| [("markers".to_string(), markers.to_string())];
+        assert_eq!(
+            run(&[Query::Range(1, 3), Query::Position(4)], &source, &[]),
+            Ok(vec![
+                Ok("moves 1-3: 2 passes, 1 landings".to_string()),
+                Err("move 4 is past the last move (3)".to_string()),
+            ])
+        );
+        assert_eq!(
+            run(&[Query::Position(1)], &source, &markers("x")),
+            Err("parameter `markers`: `x` is not a number".to_string())
+        );
+        drop(input);
+        let missing = InputSource::Path(PathBuf::from("does/not/exist"));
+        assert!(run(&[Query::Position(1)], &missing, &[]).is_err());
+    }
+}
@@ -0,0 +1,243 @@
+//! Registry of every implemented day, used by the runner, tests and benchmarks.
+
+use std::time::{Duration, Instant};
+
+use crate::bench::{self, DayBench};
+use crate::error::ParseError;
+use crate::input;
+use crate::params::{Config, Param, Params};
+use crate::solution::{Answer, Part, Solution};
+use crate::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12};
+
+type Runner = fn(&str, &[Part], &Params) -> Result<Run, ParseError>;
+type Bencher = fn(&str, usize, &Params) -> Result<DayBench, ParseError>;
+
+/// One solved part and how long solving it took.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solved {
+    pub part: Part,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+/// The outcome of parsing an input once and solving the requested parts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub parts: Vec<Solved>,
+}
+
+/// A type-erased registered day.
+pub struct Day {
+    pub number: u8,
+    pub title: &'static str,
+    pub params: &'static [Param],
+    run: Runner,
+    bench: Bencher,
+    check: fn(&Params) -> Result<(), String>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            number: S::DAY,
+            title: S::TITLE,
+            params: S::PARAMS,
+            run: run::<S>,
+            bench: bench::measure::<S>,
+            check: S::check_params,
+        }
+    }
+
+    /// The day's parameters from `config` and `overrides`, as [`Config::params`] resolves
+    /// them, also checked by [`Solution::check_params`].
+    pub fn resolve_params(
+        &self,
+        config: &Config,
+        overrides: &[(String, String)],
+    ) -> Result<Params, String> {
+        let params = config.params(self.number, self.params, overrides)?;
+        (self.check)(&params).map_err(|error| format!("day {:02}: {error}", self.number))?;
+        Ok(params)
+    }
+
+    /// Parse `input`, in its [`input::normalize`]d form, once and solve the requested parts
+    /// in order.
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<Vec<(Part, Answer)>, ParseError> {
+        let run = self.run_timed(input, parts)?;
+        Ok(run.parts.into_iter().map(|s| (s.part, s.answer)).collect())
+    }
+
+    /// Like [`Day::run`], also timing the parse and each part.
+    pub fn run_timed(&self, input: &str, parts: &[Part]) -> Result<Run, ParseError> {
+        self.run_with(input, parts, &Params::defaults(self.params))
+    }
+
+    /// Like [`Day::run_timed`], with `params` resolved against [`Day::params`].
+    pub fn run_with(
+        &self,
+        input: &str,
+        parts: &[Part],
+        params: &Params,
+    ) -> Result<Run, ParseError> {
+        (self.run)(&input::normalize(input), parts, params)
+    }
+
+    /// Time parsing and both parts over `iterations` runs each.
+    pub fn bench(&self, input: &str, iterations: usize) -> Result<DayBench, ParseError> {
+        self.bench_with(input, iterations, &Params::defaults(self.params))
+    }
+
+    /// Like [`Day::bench`], with `params` resolved against [`Day::params`].
+    pub fn bench_with(
+        &self,
+        input: &str,
+        iterations: usize,
+        params: &Params,
+    ) -> Result<DayBench, ParseError> {
+        (self.bench)(&input::normalize(input), iterations, params)
+    }
+}
+
+fn run<S: Solution>(input: &str, parts: &[Part], params: &Params) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => S::part1_with(&parsed, params),
+                Part::Two => S::part2_with(&parsed, params),
+            };
+            Solved {
+                part,
+                answer,
+                time: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Run { parse_time, parts })
+}
+
+pub const DAYS: &[Day] = &[
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::of::<day09::Day09>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Assert that `input` solves to the same answers when saved by a Windows editor.
+#[cfg(test)]
+pub(crate) fn assert_windows_agnostic(number: u8, input: &str) {
+    let day = find(number).unwrap();
+    let expected = day.run(input, &Part::ALL).unwrap();
+    assert_eq!(day.run(&input::windows(input), &Part::ALL), Ok(expected));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_registered_in_order() {
+        let numbers: Vec<u8> = DAYS.iter().map(|d| d.number).collect();
+        assert_eq!(numbers, (1..=12).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(7).map(|d| d.title), Some("Laboratories"));
+        assert!(find(0).is_none());
+        assert!(find(13).is_none());
+    }
+
+    #[test]
+    fn test_run_selected_parts() {
+        let day = find(1).unwrap();
+        let example = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
+        assert_eq!(
+            day.run(example, &Part::ALL),
+            Ok(vec![
+                (Part::One, Answer::Int(3)),
+                (Part::Two, Answer::Int(6))
+            ])
+        );
+        assert_eq!(
+            day.run(example, &[Part::Two]),
+            Ok(vec![(Part::Two, Answer::Int(6))])
+        );
+        assert_eq!(day.run(example, &[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_run_day_without_part2() {
+        let day = find(12).unwrap();
+        assert_eq!(
+            day.run("", &[Part::Two]),
+            Ok(vec![(Part::Two, Answer::NotApplicable)])
+        );
+    }
+
+    #[test]
+    fn test_run_parse_error() {
+        let error = find(1).unwrap().run("L10\nX5", &Part::ALL).unwrap_err();
+        assert_eq!((error.day, error.line, error.column), (1, 2, 1));
+    }
+
+    #[test]
+    fn test_resolve_params() {
+        let config = Config::parse("[day01]\nsize = 10\n").unwrap();
+        let resolve = |markers: &str| {
+            let overrides = [("markers".to_string(), markers.to_string())];
+            find(1).unwrap().resolve_params(&config, &overrides)
+        };
+        assert_eq!(resolve("0 5").unwrap().int("size"), 10);
+        assert_eq!(
+            resolve("x"),
+            Err("day 01: parameter `markers`: `x` is not a number".to_string())
+        );
+        assert_eq!(
+            resolve("10"),
+            Err(
+                "day 01: parameter `markers`: marker 10 is not on a dial of 10 positions"
+                    .to_string()
+            )
+        );
+        assert!(find(8).unwrap().resolve_params(&config, &[]).is_ok());
+    }
+
+    #[test]
+    fn test_bench() {
+        let bench = find(5).unwrap().bench("3-5\n\n4", 2).unwrap();
+        assert_eq!((bench.day, bench.iterations), (5, 2));
+        assert!(find(5).unwrap().bench("3-5", 2).is_err());
+    }
+
+    #[test]
+    fn test_run_timed() {
+        let run = find(1)
+            .unwrap()
+            .run_timed("L68\nR10", &[Part::Two])
+            .unwrap();
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.parts[0].part, Part::Two);
+        assert_eq!(run.parts[0].answer, Answer::Int(1));
+        assert!(find(1).unwrap().run_timed("Q", &Part::ALL).is_err());
+    }
+}
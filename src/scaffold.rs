@@ -0,0 +1,500 @@
+//! `aoc-2025 new`: generate a day's module, binary, input and feature doc from `templates/`.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::client;
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+const BIN_TEMPLATE: &str = include_str!("../templates/bin_main.rs.tmpl");
+const DOC_TEMPLATE: &str = include_str!("../templates/IMPLEMENTATION.md.tmpl");
+
+/// rustfmt's default line width, used when rewriting the registry imports.
+const MAX_WIDTH: usize = 100;
+
+/// What happened to one file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Created,
+    /// Already present and left alone (only the input, which `fetch` may have written).
+    Kept,
+    Updated,
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Action::Created => "created",
+            Action::Kept => "kept",
+            Action::Updated => "updated",
+        })
+    }
+}
+
+/// Fill `{{DAY}}`, `{{DD}}` and `{{TITLE}}` in `template`.
+pub fn render(template: &str, day: u8, title: &str) -> String {
+    template
+        .replace("{{DAY}}", &day.to_string())
+        .replace("{{DD}}", &format!("{day:02}"))
+        .replace("{{TITLE}}", title)
+}
+
+/// Generate day `day` under the project `root` and register it, with the feature doc
+/// stamped `now` (seconds since the Unix epoch, UTC).
+///
+/// Nothing is written if any generated file other than `input.txt` already exists.
+pub fn generate(
+    root: &Path,
+    day: u8,
+    title: &str,
+    now: u64,
+) -> Result<Vec<(PathBuf, Action)>, String> {
+    if !(1..=client::LAST_DAY).contains(&day) {
+        return Err(format!("invalid day: {day}"));
+    }
+    if title.contains(['"', '\\', '\n']) {
+        return Err(format!("invalid title: {title}"));
+    }
+
+    let module = root.join(format!("src/day{day:02}.rs"));
+    let bin = root.join(format!("src/bin/day{day:02}/main.rs"));
+    let input = root.join(format!("src/bin/day{day:02}/input.txt"));
+    let docs = root.join("docs/features");
+    let doc = docs.join(format!("{}_DAY{day:02}_IMPLEMENTATION.md", timestamp(now)));
+    let lib = root.join("src/lib.rs");
+    let registry = root.join("src/registry.rs");
+
+    let existing_doc = find_doc(&docs, day)?;
+    for path in [Some(&module), Some(&bin), existing_doc.as_ref()]
+        .into_iter()
+        .flatten()
+    {
+        if path.exists() {
+            return Err(format!("refusing to overwrite {}", path.display()));
+        }
+    }
+    let lib_text = read(&lib)?;
+    let registry_text = read(&registry)?;
+    let lib_text = register_module(&lib_text, day)?;
+    let registry_text = register_day(&registry_text, day)?;
+
+    let mut actions = Vec::new();
+    write(&module, &render(DAY_TEMPLATE, day, title))?;
+    actions.push((module, Action::Created));
+    write(&bin, &render(BIN_TEMPLATE, day, title))?;
+    actions.push((bin, Action::Created));
+    if input.exists() {
+        actions.push((input, Action::Kept));
+    } else {
+        write(&input, "")?;
+        actions.push((input, Action::Created));
+    }
+    write(&doc, &render(DOC_TEMPLATE, day, title))?;
+    actions.push((doc, Action::Created));
+    write(&lib, &lib_text)?;
+    actions.push((lib, Action::Updated));
+    write(&registry, &registry_text)?;
+    actions.push((registry, Action::Updated));
+
+    Ok(actions)
+}
+
+/// `YYYY-MM-DD-HHMM` in UTC, as used by feature doc names.
+pub fn timestamp(seconds: u64) -> String {
+    let days = (seconds / 86_400) as i64;
+    let minutes = seconds % 86_400 / 60;
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}-{:02}{:02}",
+        minutes / 60,
+        minutes % 60
+    )
+}
+
+/// An existing `*_DAYXX_IMPLEMENTATION.md` for `day`, whatever its date.
+fn find_doc(docs: &Path, day: u8) -> Result<Option<PathBuf>, String> {
+    let suffix = format!("_DAY{day:02}_IMPLEMENTATION.md");
+    let entries = match std::fs::read_dir(docs) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(format!("failed to read {}: {error}", docs.display())),
+    };
+    Ok(entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(&suffix))
+        }))
+}
+
+/// Add `pub mod dayXX;` to `lib.rs`, keeping the declarations sorted.
+fn register_module(lib: &str, day: u8) -> Result<String, String> {
+    let line = format!("pub mod day{day:02};");
+    if lib.lines().any(|l| l == line) {
+        return Err(format!("src/lib.rs already declares day{day:02}"));
+    }
+    let mut lines: Vec<&str> = lib.lines().collect();
+    let last = lines
+        .iter()
+        .rposition(|l| l.starts_with("pub mod "))
+        .ok_or("src/lib.rs has no `pub mod` declarations")?;
+    let position = lines
+        .iter()
+        .position(|l| l.starts_with("pub mod ") && *l > line.as_str())
+        .unwrap_or(last + 1);
+    lines.insert(position, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add `dayXX` to the `use crate::{...}` import and `Day::of::<dayXX::DayXX>()` to `DAYS`.
+fn register_day(registry: &str, day: u8) -> Result<String, String> {
+    let start = registry
+        .match_indices("use crate::{")
+        .map(|(i, _)| i)
+        .find(|&i| {
+            registry[i + "use crate::{".len()..]
+                .trim_start()
+                .starts_with("day")
+        })
+        .ok_or("src/registry.rs has no `use crate::{dayXX, ...}` import")?;
+    let end = start + registry[start..].find("};").ok_or("unterminated import")? + 2;
+    let mut modules: Vec<String> = registry[start + "use crate::{".len()..end - 2]
+        .split(',')
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .map(String::from)
+        .collect();
+    modules.push(format!("day{day:02}"));
+    modules.sort();
+    let registry = format!(
+        "{}{}{}",
+        &registry[..start],
+        import(&modules),
+        &registry[end..]
+    );
+
+    let entry = format!("    Day::of::<day{day:02}::Day{day:02}>(),");
+    let mut lines: Vec<&str> = registry.lines().collect();
+    let array = lines
+        .iter()
+        .position(|l| l.starts_with("pub const DAYS: &[Day] = &["))
+        .ok_or("src/registry.rs has no `DAYS` array")?;
+    let close = array
+        + lines[array..]
+            .iter()
+            .position(|l| *l == "];")
+            .ok_or("unterminated `DAYS` array")?;
+    let position = (array + 1..close)
+        .find(|&i| day_number(lines[i].trim(), "Day::of::<day", "::").is_some_and(|n| n > day))
+        .unwrap_or(close);
+    lines.insert(position, &entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Format `use crate::{...};` the way rustfmt does: one line if it fits, otherwise the
+/// items filled onto indented lines.
+fn import(modules: &[String]) -> String {
+    let single = format!("use crate::{{{}}};", modules.join(", "));
+    if single.len() <= MAX_WIDTH {
+        return single;
+    }
+    let mut out = String::from("use crate::{\n");
+    let mut line = String::from("   ");
+    for module in modules {
+        if line.len() + 1 + module.len() + 1 > MAX_WIDTH {
+            out += line.trim_end();
+            out += "\n";
+            line = String::from("   ");
+        }
+        line += &format!(" {module},");
+    }
+    out += &line;
+    out += "\n}";
+    out + ";"
+}
+
+/// The day number in `line` between `prefix` and `suffix`, e.g. `pub mod day07;`.
+fn day_number(line: &str, prefix: &str, suffix: &str) -> Option<u8> {
+    line.strip_prefix(prefix)?
+        .split(suffix)
+        .next()?
+        .parse()
+        .ok()
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path)
+        .map_err(|error| format!("failed to read {}: {error}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    let error = |e: std::io::Error| format!("failed to write {}: {e}", path.display());
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(error)?;
+    }
+    std::fs::write(path, contents).map_err(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples;
+    use crate::registry;
+    use crate::temp::Temp;
+
+    use super::*;
+
+    const NOW: u64 = 1_765_541_220; // 2025-12-12 12:07 UTC
+
+    /// A throwaway project root holding copies of the real `lib.rs` and `registry.rs`, with
+    /// days 11 and 12 unregistered so they can be generated again.
+    fn project(name: &str) -> Temp {
+        let root = Temp::path(&format!("new-{name}"));
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let lib = std::fs::read_to_string(source.join("lib.rs")).unwrap();
+        let lib = lib.replace("pub mod day11;\npub mod day12;\n", "");
+        let registry = std::fs::read_to_string(source.join("registry.rs")).unwrap();
+        let registry = registry.replace(", day11, day12};", "};").replace(
+            "    Day::of::<day11::Day11>(),\n    Day::of::<day12::Day12>(),\n",
+            "",
+        );
+        write(&root.join("src/lib.rs"), &lib).unwrap();
+        write(&root.join("src/registry.rs"), &registry).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_generate() {
+        let root = project("generate");
+        let actions = generate(&root, 11, "Frozen Lake", NOW).unwrap();
+        let names: Vec<(String, Action)> = actions
+            .iter()
+            .map(|(path, action)| {
+                let name = path.strip_prefix(&root).unwrap().display().to_string();
+                (name, *action)
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("src/day11.rs".to_string(), Action::Created),
+                ("src/bin/day11/main.rs".to_string(), Action::Created),
+                ("src/bin/day11/input.txt".to_string(), Action::Created),
+                (
+                    "docs/features/2025-12-12-1207_DAY11_IMPLEMENTATION.md".to_string(),
+                    Action::Created
+                ),
+                ("src/lib.rs".to_string(), Action::Updated),
+                ("src/registry.rs".to_string(), Action::Updated),
+            ]
+        );
+
+        let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap();
+        let module = read("src/day11.rs");
+        assert!(module.starts_with("//! Day 11: Frozen Lake\n"));
+        assert!(module.contains("pub struct Day11;"));
+        assert!(module.contains("const TITLE: &'static str = \"Frozen Lake\";"));
+        assert!(module.contains("fn test_part1_example()"));
+
+        let bin = read("src/bin/day11/main.rs");
+        assert!(bin.contains("use aoc_2025::day11::solve_part1;"));
+        assert!(bin.contains("input::load(11, &InputSource::Default)"));
+        assert!(bin.contains("fn test_main()"));
+
+        assert_eq!(read("src/bin/day11/input.txt"), "");
+        assert!(
+            read("docs/features/2025-12-12-1207_DAY11_IMPLEMENTATION.md")
+                .starts_with("# Day 11: Frozen Lake\n")
+        );
+        assert!(read("src/lib.rs").contains("pub mod day10;\npub mod day11;\n"));
+
+        let registry = read("src/registry.rs");
+        assert!(
+            registry.contains("    Day::of::<day10::Day10>(),\n    Day::of::<day11::Day11>(),\n];")
+        );
+        assert!(registry.contains("day09, day10, day11};"));
+    }
+
+    #[test]
+    fn test_generated_doc_example() {
+        let root = project("doc");
+        generate(&root, 11, "Frozen Lake", NOW).unwrap();
+        // The stub answers 0 for the empty example, and so does the registered day 11; the
+        // other days' docs are not in the project.
+        let failures = examples::check_docs(&root.join("docs/features")).unwrap_err();
+        let missing: Vec<String> = registry::DAYS
+            .iter()
+            .filter(|day| day.number != 11)
+            .map(|day| format!("day {} has no example with an expected answer", day.number))
+            .collect();
+        assert_eq!(failures, missing);
+    }
+
+    #[test]
+    fn test_generate_refuses_to_overwrite() {
+        let root = project("overwrite");
+        generate(&root, 11, "First", NOW).unwrap();
+        let lib = std::fs::read_to_string(root.join("src/lib.rs")).unwrap();
+
+        let error = generate(&root, 11, "Second", NOW + 3600).unwrap_err();
+        assert!(error.starts_with("refusing to overwrite "), "{error}");
+        assert!(error.ends_with("day11.rs"), "{error}");
+        assert_eq!(
+            std::fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+            lib
+        );
+
+        // The doc is found by day, not by timestamp.
+        std::fs::remove_file(root.join("src/day11.rs")).unwrap();
+        std::fs::remove_dir_all(root.join("src/bin")).unwrap();
+        let error = generate(&root, 11, "Second", NOW + 3600).unwrap_err();
+        assert!(
+            error.ends_with("2025-12-12-1207_DAY11_IMPLEMENTATION.md"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_generate_twice() {
+        // The second registration builds on the import rewritten by the first.
+        let root = project("twice");
+        generate(&root, 11, "A", NOW).unwrap();
+        generate(&root, 12, "B", NOW).unwrap();
+        let registry = std::fs::read_to_string(root.join("src/registry.rs")).unwrap();
+        assert!(registry.contains("day10, day11, day12};"));
+        assert!(registry.contains("<day11::Day11>(),\n    Day::of::<day12::Day12>(),\n];"));
+    }
+
+    #[test]
+    fn test_generate_existing_day() {
+        let root = project("existing");
+        assert_eq!(
+            generate(&root, 7, "Laboratories", NOW),
+            Err("src/lib.rs already declares day07".to_string())
+        );
+        assert!(!root.join("src/day07.rs").exists());
+    }
+
+    #[test]
+    fn test_generate_keeps_fetched_input() {
+        let root = project("input");
+        let input = root.join("src/bin/day12/input.txt");
+        write(&input, "fetched\n").unwrap();
+        let actions = generate(&root, 12, "Later", NOW).unwrap();
+        assert!(actions.contains(&(input.clone(), Action::Kept)));
+        assert_eq!(std::fs::read_to_string(&input).unwrap(), "fetched\n");
+    }
+
+    #[test]
+    fn test_generate_invalid_arguments() {
+        let root = Path::new("does/not/exist");
+        assert_eq!(
+            generate(root, 0, "x", NOW),
+            Err("invalid day: 0".to_string())
+        );
+        assert_eq!(
+            generate(root, 13, "x", NOW),
+            Err("invalid day: 13".to_string())
+        );
+        assert_eq!(
+            generate(root, 11, "say \"hi\"", NOW),
+            Err("invalid title: say \"hi\"".to_string())
+        );
+        assert!(generate(root, 11, "x", NOW)
+            .unwrap_err()
+            .starts_with("failed to read "));
+    }
+
+    #[test]
+    fn test_action_display() {
+        assert_eq!(format!("{:>8}|", Action::Kept), "    kept|");
+        assert_eq!(Action::Created.to_string(), "created");
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render("day{{DD}} {{DAY}} {{TITLE}}", 7, "Laboratories"),
+            "day07 7 Laboratories"
+        );
+    }
+
+    #[test]
+    fn test_templates_match_existing_days() {
+        // The bin template is the shape every part-1-only day binary already has.
+        let day12 = std::fs::read_to_string(
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("src/bin/day12/main.rs"),
+        )
+        .unwrap();
+        assert_eq!(render(BIN_TEMPLATE, 12, "Christmas Tree Farm"), day12);
+    }
+
+    #[test]
+    fn test_timestamp() {
+        assert_eq!(timestamp(0), "1970-01-01-0000");
+        assert_eq!(timestamp(NOW), "2025-12-12-1207");
+        assert_eq!(timestamp(951_825_600), "2000-02-29-1200");
+        assert_eq!(timestamp(4_107_542_399), "2100-02-28-2359");
+    }
+
+    #[test]
+    fn test_register_module() {
+        assert_eq!(
+            register_module(
+                "//! x\n\npub mod a;\npub mod day01;\npub mod day03;\npub mod z;\n",
+                2
+            ),
+            Ok(
+                "//! x\n\npub mod a;\npub mod day01;\npub mod day02;\npub mod day03;\npub mod z;\n"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            register_module("pub mod answers;\npub mod day02;\n", 1),
+            Ok("pub mod answers;\npub mod day01;\npub mod day02;\n".to_string())
+        );
+        assert_eq!(
+            register_module("pub mod answers;\n\nfn x() {}\n", 1),
+            Ok("pub mod answers;\npub mod day01;\n\nfn x() {}\n".to_string())
+        );
+        assert!(register_module("//! empty\n", 1).is_err());
+    }
+
+    #[test]
+    fn test_register_day() {
+        let registry = "use crate::{day01, day03};\n\npub const DAYS: &[Day] = &[\n    Day::of::<day01::Day01>(),\n    Day::of::<day03::Day03>(),\n];\n";
+        assert_eq!(
+            register_day(registry, 2),
+            Ok("use crate::{day01, day02, day03};\n\npub const DAYS: &[Day] = &[\n    Day::of::<day01::Day01>(),\n    Day::of::<day02::Day02>(),\n    Day::of::<day03::Day03>(),\n];\n".to_string())
+        );
+        assert!(register_day("", 2).is_err());
+        assert!(register_day("use crate::{day01};\n", 2).is_err());
+    }
+
+    #[test]
+    fn test_import_wraps_like_rustfmt() {
+        let modules: Vec<String> = (1..=12).map(|n| format!("day{n:02}")).collect();
+        assert_eq!(
+            import(&modules),
+            "use crate::{day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12};"
+        );
+        let modules: Vec<String> = (1..=14).map(|n| format!("day{n:02}")).collect();
+        assert_eq!(
+            import(&modules),
+            "use crate::{\n    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,\n    day14,\n};"
+        );
+    }
+}
@@ -0,0 +1,487 @@
+//! Delta-debugging shrinker: cut a failing input down to a minimal one that still parses
+//! and still fails.
+
+use std::io::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::fuzz::{self, Outcome};
+use crate::registry::{self, Day};
+use crate::solution::Part;
+
+/// How long solving one candidate may take before it counts as hung and is skipped.
+pub const SOLVE_LIMIT: Duration = Duration::from_secs(10);
+
+/// What counts as failing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Predicate {
+    /// Solving the parts panics.
+    Panics,
+    /// The answers differ from the output of `sh -c <command>`, which gets the input on
+    /// stdin and prints one answer per line, in part order.
+    Differs { reference: String },
+}
+
+/// How a day's input splits into removable units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    Lines,
+    /// Day 2: comma-separated ranges.
+    Commas,
+    /// Day 6: problems, i.e. blocks of columns between all-space separator columns.
+    Columns,
+}
+
+impl Layout {
+    pub fn of(day: u8) -> Self {
+        match day {
+            2 => Layout::Commas,
+            6 => Layout::Columns,
+            _ => Layout::Lines,
+        }
+    }
+
+    /// Units of `input`; for [`Layout::Columns`] each unit is the block's rows joined by
+    /// newlines.
+    pub fn split(self, input: &str) -> Vec<String> {
+        match self {
+            Layout::Lines => input.lines().map(str::to_string).collect(),
+            Layout::Commas => input.trim().split(',').map(str::to_string).collect(),
+            Layout::Columns => {
+                let rows: Vec<Vec<char>> = input
+                    .lines()
+                    .filter(|line| !line.is_empty())
+                    .map(|line| line.chars().collect())
+                    .collect();
+                let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+                let cell = |row: &Vec<char>, col: usize| row.get(col).copied().unwrap_or(' ');
+                let separator = |col: usize| rows.iter().all(|row| cell(row, col) == ' ');
+
+                let mut units = Vec::new();
+                let mut col = 0;
+                while col < width {
+                    if separator(col) {
+                        col += 1;
+                        continue;
+                    }
+                    let start = col;
+                    while col < width && !separator(col) {
+                        col += 1;
+                    }
+                    let block: Vec<String> = rows
+                        .iter()
+                        .map(|row| (start..col).map(|c| cell(row, c)).collect())
+                        .collect();
+                    units.push(block.join("\n"));
+                }
+                units
+            }
+        }
+    }
+
+    pub fn join(self, units: &[String]) -> String {
+        match self {
+            Layout::Lines => units.iter().map(|unit| format!("{unit}\n")).collect(),
+            Layout::Commas => format!("{}\n", units.join(",")),
+            Layout::Columns => {
+                let blocks: Vec<Vec<&str>> = units.iter().map(|u| u.lines().collect()).collect();
+                let rows = blocks.iter().map(Vec::len).max().unwrap_or(0);
+                (0..rows)
+                    .map(|row| {
+                        let cells: Vec<&str> = blocks
+                            .iter()
+                            .map(|block| block.get(row).copied().unwrap_or(""))
+                            .collect();
+                        format!("{}\n", cells.join(" "))
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// A shrunk input and how many candidates were tried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shrunk {
+    pub input: String,
+    pub tests: usize,
+}
+
+/// Whether `input` still looks like a puzzle input for `day`: it parses within
+/// [`fuzz::HANG_LIMIT`], and day 9's red tiles stay connected by horizontal and vertical
+/// lines. A parse that panics is left to the predicate, which may count it as a failure.
+pub fn is_valid(day: &'static Day, input: &str) -> bool {
+    if !matches!(
+        fuzz::parse(day, input, fuzz::HANG_LIMIT),
+        Outcome::Parsed | Outcome::Panicked(_)
+    ) {
+        return false;
+    }
+    if day.number == 9 {
+        let tiles: Vec<&str> = input.lines().collect();
+        return (0..tiles.len()).all(|i| {
+            let a = tiles[i].split_once(',');
+            let b = tiles[(i + 1) % tiles.len()].split_once(',');
+            a.zip(b).is_some_and(|(a, b)| a.0 == b.0 || a.1 == b.1)
+        });
+    }
+    true
+}
+
+/// Whether `input` makes `parts` of `day` fail as `predicate` describes. A panic while
+/// parsing counts like one while solving, and a candidate that takes longer than
+/// [`SOLVE_LIMIT`] does not fail.
+pub fn fails(day: &'static Day, parts: &[Part], predicate: &Predicate, input: &str) -> bool {
+    let (candidate, parts) = (input.to_string(), parts.to_vec());
+    let solved = within(SOLVE_LIMIT, move || day.run(&candidate, &parts));
+    match (predicate, solved) {
+        (_, None) => false,
+        (Predicate::Panics, Some(solved)) => solved.is_err(),
+        (Predicate::Differs { .. }, Some(Err(_) | Ok(Err(_)))) => false,
+        (Predicate::Differs { reference }, Some(Ok(Ok(answers)))) => {
+            let answers: Vec<String> = answers.iter().map(|(_, a)| a.to_string()).collect();
+            // A reference that fails says nothing about the input.
+            reference_answers(reference, input).is_ok_and(|expected| expected != answers)
+        }
+    }
+}
+
+/// Run `work` on a separate thread, catching a panic, or `None` if it takes longer than
+/// `limit`.
+///
+/// Work that hangs cannot be stopped: its thread keeps running while shrinking moves on to
+/// the next candidate, so each hung candidate holds a thread until `shrink` exits.
+fn within<T: Send + 'static>(
+    limit: Duration,
+    work: impl FnOnce() -> T + Send + 'static,
+) -> Option<thread::Result<T>> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(panic::catch_unwind(AssertUnwindSafe(work)));
+    });
+    receiver.recv_timeout(limit).ok()
+}
+
+/// Run `command` through `sh -c` with `input` on stdin, returning its non-empty output lines.
+pub fn reference_answers(command: &str, input: &str) -> Result<Vec<String>, String> {
+    let mut child = Command::new("sh")
+        .args(["-c", command])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|error| format!("failed to run `{command}`: {error}"))?;
+    // A reference that stops reading early closes the pipe; its exit status decides.
+    let _ = child
+        .stdin
+        .take()
+        .expect("piped")
+        .write_all(input.as_bytes());
+    let output = child
+        .wait_with_output()
+        .map_err(|error| format!("failed to run `{command}`: {error}"))?;
+    if !output.status.success() {
+        return Err(format!("`{command}` failed with {}", output.status));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Shrink `input` for `day` while `failing` holds and the input stays valid: first whole
+/// units (see [`Layout`]), then for line-based days the space-separated words of each line.
+pub fn shrink(
+    day: u8,
+    input: &str,
+    mut failing: impl FnMut(&str) -> bool,
+) -> Result<Shrunk, String> {
+    let solver = registry::find(day).ok_or_else(|| format!("day {day} is not registered"))?;
+    let layout = Layout::of(day);
+    let mut tests = 0;
+    let mut test = |candidate: &str| {
+        tests += 1;
+        is_valid(solver, candidate) && failing(candidate)
+    };
+
+    if !test(input) {
+        return Err("the input does not fail".to_string());
+    }
+    let units = ddmin(layout.split(input), |units| test(&layout.join(units)));
+
+    let mut lines = units;
+    if layout == Layout::Lines {
+        for i in 0..lines.len() {
+            let words: Vec<String> = lines[i].split(' ').map(str::to_string).collect();
+            let words = ddmin(words, |words| {
+                let mut candidate = lines.clone();
+                candidate[i] = words.join(" ");
+                test(&layout.join(&candidate))
+            });
+            lines[i] = words.join(" ");
+        }
+    }
+
+    Ok(Shrunk {
+        input: layout.join(&lines),
+        tests,
+    })
+}
+
+/// Zeller's ddmin: a 1-minimal sublist of `units` for which `test` holds, assuming it holds
+/// for `units`.
+pub fn ddmin<T: Clone>(mut units: Vec<T>, mut test: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut granularity = 2;
+    while units.len() >= 2 {
+        let chunk = units.len().div_ceil(granularity);
+        let starts: Vec<usize> = (0..units.len()).step_by(chunk).collect();
+
+        let subset = starts.iter().find_map(|&start| {
+            let subset = &units[start..(start + chunk).min(units.len())];
+            test(subset).then(|| subset.to_vec())
+        });
+        if let Some(subset) = subset {
+            units = subset;
+            granularity = 2;
+            continue;
+        }
+
+        let complement = starts.iter().find_map(|&start| {
+            let mut complement = units[..start].to_vec();
+            complement.extend_from_slice(&units[(start + chunk).min(units.len())..]);
+            test(&complement).then_some(complement)
+        });
+        if let Some(complement) = complement {
+            units = complement;
+            granularity = (granularity - 1).max(2);
+            continue;
+        }
+
+        if granularity >= units.len() {
+            break;
+        }
+        granularity = (granularity * 2).min(units.len());
+    }
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ParseError;
+    use crate::solution::{Answer, Solution};
+
+    use super::*;
+
+    /// Parsing panics on a line `boom`.
+    struct Fragile;
+
+    impl Solution for Fragile {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Fragile";
+        type Input<'a> = usize;
+
+        fn parse(input: &str) -> Result<usize, ParseError> {
+            if input.lines().any(|line| line == "boom") {
+                panic!("boom");
+            }
+            Ok(input.lines().count())
+        }
+
+        fn part1(lines: &usize) -> Answer {
+            (*lines).into()
+        }
+    }
+
+    static FRAGILE: Day = Day::of::<Fragile>();
+
+    const DAY01: &str = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+
+    fn day(number: u8) -> &'static Day {
+        registry::find(number).unwrap()
+    }
+
+    #[test]
+    fn test_ddmin() {
+        let units: Vec<u32> = (0..100).collect();
+        let mut tests = 0;
+        let minimal = ddmin(units, |units| {
+            tests += 1;
+            units.contains(&17) && units.contains(&64)
+        });
+        assert_eq!(minimal, vec![17, 64]);
+        assert!(tests < 100, "{tests}");
+
+        assert_eq!(ddmin(vec![1], |_| true), vec![1]);
+        assert_eq!(ddmin(Vec::<u8>::new(), |_| true), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_ddmin_is_one_minimal() {
+        // Needs any three of the even numbers; the result must not drop any of them.
+        let minimal = ddmin((0..40).collect(), |units: &[u32]| {
+            units.iter().filter(|n| *n % 2 == 0).count() >= 3
+        });
+        assert_eq!(minimal.len(), 3);
+        assert!(minimal.iter().all(|n| n % 2 == 0));
+    }
+
+    #[test]
+    fn test_layout_lines_and_commas() {
+        assert_eq!(Layout::of(1), Layout::Lines);
+        let lines = Layout::Lines.split("a b\nc\n");
+        assert_eq!(lines, vec!["a b", "c"]);
+        assert_eq!(Layout::Lines.join(&lines), "a b\nc\n");
+
+        let ranges = Layout::of(2).split("11-22,95-115\n");
+        assert_eq!(ranges, vec!["11-22", "95-115"]);
+        assert_eq!(Layout::Commas.join(&ranges[1..]), "95-115\n");
+    }
+
+    #[test]
+    fn test_layout_columns() {
+        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n";
+        let problems = Layout::of(6).split(input);
+        assert_eq!(problems.len(), 4);
+        assert_eq!(problems[1], "328\n64 \n98 \n+  ");
+        assert_eq!(problems[3], "64 \n23 \n314\n+  ");
+        assert_eq!(
+            Layout::Columns.join(&[problems[1].clone(), problems[3].clone()]),
+            "328 64 \n64  23 \n98  314\n+   +  \n"
+        );
+        assert_eq!(Layout::Columns.join(&problems), input);
+    }
+
+    #[test]
+    fn test_shrink_lines() {
+        let shrunk = shrink(1, DAY01, |input| {
+            input.contains("L68") && input.contains("R14")
+        })
+        .unwrap();
+        assert_eq!(shrunk.input, "L68\nR14\n");
+        assert!(shrunk.tests > 1);
+        assert_eq!(
+            shrink(1, DAY01, |input| input.contains('Q')),
+            Err("the input does not fail".to_string())
+        );
+    }
+
+    #[test]
+    fn test_shrink_words() {
+        let input = "aaa: you bbb\nyou: ccc ddd out\nccc: out\nddd: out\n";
+        // `ddd out` alone would do, but a line without `device:` does not parse.
+        let shrunk = shrink(11, input, |input| {
+            input.contains("ddd") && input.contains("out")
+        });
+        assert_eq!(shrunk.unwrap().input, "you: ddd out\n");
+        let shrunk = shrink(11, input, |input| input.contains("ccc ddd")).unwrap();
+        assert_eq!(shrunk.input, "you: ccc ddd\n");
+    }
+
+    #[test]
+    fn test_shrink_columns() {
+        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  \n";
+        let shrunk = shrink(6, input, |input| input.contains("387")).unwrap();
+        assert_eq!(shrunk.input, " 51\n387\n215\n*  \n");
+    }
+
+    #[test]
+    fn test_shrink_day09_stays_rectilinear() {
+        let input = "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3\n";
+        let shrunk = shrink(9, input, |input| input.contains("9,5")).unwrap();
+        assert!(is_valid(day(9), &shrunk.input), "{}", shrunk.input);
+        assert!(shrunk.input.lines().count() < 8);
+        assert!(!is_valid(day(9), "7,1\n11,7\n"));
+        assert!(!is_valid(day(9), "7,1\n11\n"));
+    }
+
+    #[test]
+    fn test_shrink_panic() {
+        // Part 2 needs banks of at least 12 batteries.
+        let mut input = "987654321111111\n".repeat(30);
+        input.insert_str(16 * 12, "12345\n");
+        let shrunk = shrink(3, &input, |input| {
+            fails(day(3), &[Part::Two], &Predicate::Panics, input)
+        })
+        .unwrap();
+        assert_eq!(shrunk.input, "12345\n");
+    }
+
+    #[test]
+    fn test_fails_differs() {
+        let differs = |reference: &str| Predicate::Differs {
+            reference: reference.to_string(),
+        };
+        let both = [Part::One, Part::Two];
+        assert!(!fails(day(1), &both, &differs("printf '3\\n6\\n'"), DAY01));
+        assert!(fails(day(1), &both, &differs("echo 3; echo 7"), DAY01));
+        assert!(!fails(day(1), &[Part::One], &differs("echo 3"), DAY01));
+        // Parse errors and failing references are not failures.
+        assert!(!fails(day(1), &both, &differs("echo 0"), "X1\n"));
+        assert!(!fails(day(1), &both, &differs("exit 1"), DAY01));
+        assert!(!fails(day(1), &both, &Predicate::Panics, DAY01));
+
+        let shrunk = shrink(1, DAY01, |input| {
+            fails(day(1), &[Part::One], &differs("echo 4"), input)
+        })
+        .unwrap();
+        assert_eq!(shrunk.input.lines().count(), 1);
+    }
+
+    #[test]
+    fn test_fails_on_parse_panic() {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        assert!(is_valid(&FRAGILE, "a\nboom\n"));
+        assert!(fails(
+            &FRAGILE,
+            &[Part::One],
+            &Predicate::Panics,
+            "a\nboom\n"
+        ));
+        assert!(!fails(&FRAGILE, &[Part::One], &Predicate::Panics, "a\n"));
+        let differs = Predicate::Differs {
+            reference: "echo 0".to_string(),
+        };
+        assert!(!fails(&FRAGILE, &[Part::One], &differs, "a\nboom\n"));
+        panic::set_hook(hook);
+    }
+
+    #[test]
+    fn test_within() {
+        assert_eq!(within(SOLVE_LIMIT, || 7).map(Result::unwrap), Some(7));
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let panicked = within(SOLVE_LIMIT, || panic!("boom"));
+        panic::set_hook(hook);
+        assert!(panicked.is_some_and(|result: thread::Result<()>| result.is_err()));
+        let hung = within(Duration::from_millis(10), || {
+            thread::sleep(Duration::from_secs(1));
+        });
+        assert!(hung.is_none());
+    }
+
+    #[test]
+    fn test_reference_answers() {
+        assert_eq!(
+            reference_answers("wc -l", "a\nb\n"),
+            Ok(vec!["2".to_string()])
+        );
+        assert_eq!(
+            reference_answers("exit 3", ""),
+            Err("`exit 3` failed with exit status: 3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unknown_day() {
+        assert_eq!(
+            shrink(30, "", |_| true),
+            Err("day 30 is not registered".to_string())
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! The `Solution` trait implemented by every day, and the `Answer` it produces.
+
+use std::fmt;
+
+use crate::error::ParseError;
+use crate::params::{Param, Params};
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A puzzle answer, independent of the integer type a solver happens to return.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Text(String),
+    NotApplicable,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::BigInt(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::NotApplicable => write!(f, "N/A"),
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        i64::try_from(n).map_or(Answer::BigInt(n), Answer::Int)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::from(n as i128)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        i128::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::from)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::from(n as i128)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Int(n)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Answer::Int(n as i64)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+/// A day's puzzle: parse the input once, then solve each part from the parsed form.
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    /// Parsed puzzle input, which may borrow from the raw text.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> Answer;
+
+    /// Days without a second part keep the default.
+    fn part2(_input: &Self::Input<'_>) -> Answer {
+        Answer::NotApplicable
+    }
+
+    /// Tunable parameters the parts read, such as day 8's number of connections.
+    const PARAMS: &'static [Param] = &[];
+
+    /// [`Solution::part1`] with `params` resolved against [`Solution::PARAMS`]; days with
+    /// parameters override this and solve `part1` with the defaults.
+    fn part1_with(input: &Self::Input<'_>, _params: &Params) -> Answer {
+        Self::part1(input)
+    }
+
+    /// [`Solution::part2`] with `params`, as for [`Solution::part1_with`].
+    fn part2_with(input: &Self::Input<'_>, _params: &Params) -> Answer {
+        Self::part2(input)
+    }
+
+    /// Reject `params` of the declared kinds that the parts still cannot solve with, such
+    /// as day 1's markers off the dial, before any part runs.
+    fn check_params(_params: &Params) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_numbers() {
+        assert_eq!(Part::One.number(), 1);
+        assert_eq!(Part::Two.number(), 2);
+        assert_eq!(Part::from_number(1), Some(Part::One));
+        assert_eq!(Part::from_number(2), Some(Part::Two));
+        assert_eq!(Part::from_number(3), None);
+        assert_eq!(Part::Two.to_string(), "2");
+    }
+
+    #[test]
+    fn test_answer_from_integers() {
+        assert_eq!(Answer::from(42u64), Answer::Int(42));
+        assert_eq!(Answer::from(42usize), Answer::Int(42));
+        assert_eq!(Answer::from(-42i64), Answer::Int(-42));
+        assert_eq!(Answer::from(-42i32), Answer::Int(-42));
+    }
+
+    #[test]
+    fn test_answer_from_large_integers() {
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+        assert_eq!(Answer::from(i128::MIN), Answer::BigInt(i128::MIN));
+        assert_eq!(Answer::from(7i128), Answer::Int(7));
+        assert_eq!(
+            Answer::from(u128::from(u64::MAX) * 2),
+            Answer::BigInt(u64::MAX as i128 * 2)
+        );
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+    }
+
+    #[test]
+    fn test_answer_from_text() {
+        assert_eq!(Answer::from("abc"), Answer::Text("abc".to_string()));
+        assert_eq!(
+            Answer::from("abc".to_string()),
+            Answer::Text("abc".to_string())
+        );
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::Int(-5).to_string(), "-5");
+        assert_eq!(
+            Answer::BigInt(u64::MAX as i128 + 1).to_string(),
+            "18446744073709551616"
+        );
+        assert_eq!(Answer::Text("HELLO".to_string()).to_string(), "HELLO");
+        assert_eq!(Answer::NotApplicable.to_string(), "N/A");
+    }
+
+    struct Sample;
+
+    impl Solution for Sample {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Sample";
+        type Input<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Ok(input.lines().collect())
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Answer {
+            input.len().into()
+        }
+    }
+
+    #[test]
+    fn test_default_part2_not_applicable() {
+        let input = Sample::parse("a\nb").unwrap();
+        assert_eq!(Sample::part1(&input), Answer::Int(2));
+        assert_eq!(Sample::part2(&input), Answer::NotApplicable);
+    }
+}
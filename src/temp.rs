@@ -0,0 +1,80 @@
+//! Throwaway files and directories for tests, removed when dropped so that a failing
+//! assertion does not leave them behind.
+//!
+//! The runner binary includes this file as its own module, since it cannot see the
+//! library's test-only code.
+
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// A path under the system temp dir, unique to this process and a name, that is removed
+/// when dropped, whether it ended up a file or a directory.
+#[derive(Debug)]
+pub(crate) struct Temp(PathBuf);
+
+impl Temp {
+    /// The path for `name`, with nothing there yet; a test can write it or create a
+    /// directory at it.
+    pub(crate) fn path(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("aoc-2025-{}-{name}", std::process::id()));
+        remove(&path);
+        Self(path)
+    }
+
+    /// A file for `name` holding `contents`.
+    pub(crate) fn file(name: &str, contents: impl AsRef<[u8]>) -> Self {
+        let temp = Self::path(name);
+        std::fs::write(&temp, contents).unwrap();
+        temp
+    }
+}
+
+impl Deref for Temp {
+    type Target = PathBuf;
+
+    fn deref(&self) -> &PathBuf {
+        &self.0
+    }
+}
+
+impl AsRef<Path> for Temp {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for Temp {
+    fn drop(&mut self) {
+        remove(&self.0);
+    }
+}
+
+/// Remove whatever is at `path`, if anything.
+fn remove(path: &Path) {
+    let _ = if path.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_removed_on_drop() {
+        let file = Temp::file("temp-file", "x");
+        let path = file.to_path_buf();
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "x");
+        drop(file);
+        assert!(!path.exists());
+
+        let dir = Temp::path("temp-dir");
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::write(dir.join("nested/file"), "y").unwrap();
+        let path = dir.to_path_buf();
+        drop(dir);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,358 @@
+//! Minimal TOML subset: `[section]` headers and `key = value` pairs of integers, floats,
+//! booleans and strings, with `#` comments.
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+    String(String),
+}
+
+impl Value {
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            Value::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Integer(n) => write!(f, "{n}"),
+            Value::Float(n) if n.fract() == 0.0 && n.is_finite() => write!(f, "{n:.1}"),
+            Value::Float(n) => write!(f, "{n}"),
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::String(s) => {
+                write!(f, "\"")?;
+                for c in s.chars() {
+                    match c {
+                        '"' => write!(f, "\\\"")?,
+                        '\\' => write!(f, "\\\\")?,
+                        '\n' => write!(f, "\\n")?,
+                        '\r' => write!(f, "\\r")?,
+                        '\t' => write!(f, "\\t")?,
+                        c => write!(f, "{c}")?,
+                    }
+                }
+                write!(f, "\"")
+            }
+        }
+    }
+}
+
+/// A `[name]` table; the root table, before any header, has an empty name.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Section {
+    pub name: String,
+    pub entries: Vec<(String, Value)>,
+}
+
+impl Section {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Document {
+    pub sections: Vec<Section>,
+}
+
+impl Document {
+    pub fn section(&self, name: &str) -> Option<&Section> {
+        self.sections.iter().find(|s| s.name == name)
+    }
+
+    pub fn get(&self, section: &str, key: &str) -> Option<&Value> {
+        self.section(section)?.get(key)
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for section in &self.sections {
+            if section.name.is_empty() && section.entries.is_empty() {
+                continue;
+            }
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+            if !section.name.is_empty() {
+                writeln!(f, "[{}]", section.name)?;
+            }
+            for (key, value) in &section.entries {
+                writeln!(f, "{key} = {value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse `text`, reporting the first problem as `line N: ...`.
+pub fn parse(text: &str) -> Result<Document, String> {
+    let mut document = Document {
+        sections: vec![Section::default()],
+    };
+
+    for (i, raw) in text.lines().enumerate() {
+        let error = |message: String| format!("line {}: {message}", i + 1);
+        let line = strip_comment(raw).trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let name = header
+                .strip_suffix(']')
+                .map(str::trim)
+                .filter(|name| is_key(name))
+                .ok_or_else(|| error(format!("invalid section header `{line}`")))?;
+            if document.section(name).is_some() {
+                return Err(error(format!("duplicate section `{name}`")));
+            }
+            document.sections.push(Section {
+                name: name.to_string(),
+                entries: Vec::new(),
+            });
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| error(format!("expected `key = value`, found `{line}`")))?;
+        let key = key.trim();
+        if !is_key(key) {
+            return Err(error(format!("invalid key `{key}`")));
+        }
+        let value = parse_value(value.trim()).map_err(error)?;
+
+        let section = document.sections.last_mut().expect("root section");
+        if section.get(key).is_some() {
+            return Err(error(format!("duplicate key `{key}`")));
+        }
+        section.entries.push((key.to_string(), value));
+    }
+
+    Ok(document)
+}
+
+fn is_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+/// Drop a trailing `# comment` that is not inside a string.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            }
+            (Some(q), c) if c == q && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => return &line[..i],
+            _ => {}
+        }
+        escaped = false;
+    }
+    line
+}
+
+fn parse_value(text: &str) -> Result<Value, String> {
+    if let Some(rest) = text.strip_prefix('"') {
+        return parse_basic_string(rest);
+    }
+    if let Some(rest) = text.strip_prefix('\'') {
+        return rest
+            .strip_suffix('\'')
+            .filter(|s| !s.contains('\''))
+            .map(|s| Value::String(s.to_string()))
+            .ok_or_else(|| format!("unterminated string `{text}`"));
+    }
+    match text {
+        "true" => return Ok(Value::Bool(true)),
+        "false" => return Ok(Value::Bool(false)),
+        "" => return Err("missing value".to_string()),
+        _ => {}
+    }
+
+    let digits = text.replace('_', "");
+    if let Ok(n) = digits.parse::<i64>() {
+        return Ok(Value::Integer(n));
+    }
+    if digits.contains(['.', 'e', 'E']) {
+        if let Ok(n) = digits.parse::<f64>() {
+            return Ok(Value::Float(n));
+        }
+    }
+    Err(format!("invalid value `{text}`"))
+}
+
+fn parse_basic_string(rest: &str) -> Result<Value, String> {
+    let mut out = String::new();
+    let mut chars = rest.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if chars.as_str().is_empty() => return Ok(Value::String(out)),
+            '"' => return Err(format!("unexpected text after string `{}`", chars.as_str())),
+            '\\' => match chars.next() {
+                Some('"') => out.push('"'),
+                Some('\\') => out.push('\\'),
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                Some('r') => out.push('\r'),
+                other => {
+                    return Err(format!(
+                        "invalid escape `\\{}`",
+                        other.map_or(String::new(), String::from)
+                    ))
+                }
+            },
+            c => out.push(c),
+        }
+    }
+    Err(format!("unterminated string `\"{rest}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sections_and_values() {
+        let document = parse(
+            "\
+title = \"AoC\"
+
+[day08]
+connections = 1_000
+ratio = 2.5
+enabled = true
+name = 'you'
+",
+        )
+        .unwrap();
+
+        assert_eq!(
+            document.get("", "title"),
+            Some(&Value::String("AoC".to_string()))
+        );
+        assert_eq!(
+            document.get("day08", "connections"),
+            Some(&Value::Integer(1000))
+        );
+        assert_eq!(document.get("day08", "ratio"), Some(&Value::Float(2.5)));
+        assert_eq!(document.get("day08", "enabled"), Some(&Value::Bool(true)));
+        assert_eq!(
+            document.get("day08", "name").and_then(Value::as_str),
+            Some("you")
+        );
+        assert_eq!(document.get("day09", "connections"), None);
+    }
+
+    #[test]
+    fn test_parse_comments() {
+        let document = parse("# header\n[a] # table\nx = 1 # one\ny = \"#not\" # two\n").unwrap();
+        assert_eq!(document.get("a", "x").and_then(Value::as_integer), Some(1));
+        assert_eq!(document.get("a", "y").and_then(Value::as_str), Some("#not"));
+    }
+
+    #[test]
+    fn test_parse_string_escapes() {
+        let document = parse(r#"s = "a\"b\\c\nd\te\r" # "x""#).unwrap();
+        assert_eq!(
+            document.get("", "s").and_then(Value::as_str),
+            Some("a\"b\\c\nd\te\r")
+        );
+    }
+
+    #[test]
+    fn test_parse_negative_and_exponent() {
+        let document = parse("a = -5\nb = 1e3\n").unwrap();
+        assert_eq!(document.get("", "a"), Some(&Value::Integer(-5)));
+        assert_eq!(document.get("", "b"), Some(&Value::Float(1000.0)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse("[a]\nnonsense"),
+            Err("line 2: expected `key = value`, found `nonsense`".to_string())
+        );
+        assert_eq!(
+            parse("[a]\n[a]"),
+            Err("line 2: duplicate section `a`".to_string())
+        );
+        assert_eq!(
+            parse("x = 1\nx = 2"),
+            Err("line 2: duplicate key `x`".to_string())
+        );
+        assert_eq!(
+            parse("[a b]"),
+            Err("line 1: invalid section header `[a b]`".to_string())
+        );
+        assert_eq!(parse("= 1"), Err("line 1: invalid key ``".to_string()));
+        assert_eq!(parse("x ="), Err("line 1: missing value".to_string()));
+        assert_eq!(
+            parse("x = abc"),
+            Err("line 1: invalid value `abc`".to_string())
+        );
+        assert_eq!(
+            parse("x = \"abc"),
+            Err("line 1: unterminated string `\"abc`".to_string())
+        );
+        assert_eq!(
+            parse("x = 'abc"),
+            Err("line 1: unterminated string `'abc`".to_string())
+        );
+        assert_eq!(
+            parse(r#"x = "a\q""#),
+            Err(r#"line 1: invalid escape `\q`"#.to_string())
+        );
+        assert_eq!(
+            parse(r#"x = "a" b"#),
+            Err("line 1: unexpected text after string ` b`".to_string())
+        );
+        assert!(parse("x = 1.2.3").is_err());
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let text = "top = 1\n\n[day01]\npart1 = 1105\npart2 = \"a\\\"b\"\n\n[x]\nf = 2.0\ng = 0.5\nb = false\n";
+        let document = parse(text).unwrap();
+        assert_eq!(document.to_string(), text);
+        assert_eq!(parse(&document.to_string()), Ok(document));
+    }
+
+    #[test]
+    fn test_display_skips_empty_root() {
+        let document = parse("[a]\nx = \"t\\tn\\n\"\n").unwrap();
+        assert_eq!(document.to_string(), "[a]\nx = \"t\\tn\\n\"\n");
+    }
+
+    #[test]
+    fn test_accessors_on_wrong_types() {
+        assert_eq!(Value::Bool(true).as_integer(), None);
+        assert_eq!(Value::Integer(1).as_str(), None);
+        assert_eq!(Document::default().get("a", "b"), None);
+    }
+}
@@ -0,0 +1,318 @@
+//! A move-by-move trace of a [`Dial`], with an ASCII rendering of its ring and an
+//! animated terminal playback.
+
+use std::f64::consts::TAU;
+use std::fmt;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crate::day01;
+use crate::dial::{Dial, Turn};
+use crate::input::InputSource;
+
+/// Rows of the ring above and below its centre; it is twice as wide, since terminal cells
+/// are about twice as tall as they are wide.
+const RADIUS: usize = 6;
+/// Most points drawn on the ring; larger dials share a point between neighbouring positions.
+const SLOTS: u64 = 36;
+/// Clears the terminal and moves the cursor home before each frame.
+const CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// One move of a trace, with the counts so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    /// Counting from 1.
+    pub number: usize,
+    pub is_left: bool,
+    pub distance: u64,
+    pub before: u64,
+    pub turn: Turn,
+    /// Landings on a marker over the moves up to and including this one.
+    pub landings: u64,
+    /// Clicks at a marker over the moves up to and including this one.
+    pub passes: u64,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let direction = if self.is_left { 'L' } else { 'R' };
+        let turn = format!("{direction}{}", self.distance);
+        write!(
+            f,
+            "{:>5}  {turn:<6} {:>4} -> {:<4} {:>3} zeros{}  ({} passes, {} landings)",
+            self.number,
+            self.before,
+            self.turn.position,
+            self.turn.hits,
+            if self.turn.landed { ", landed" } else { "" },
+            self.passes,
+            self.landings
+        )
+    }
+}
+
+/// Turns a dial one move at a time, keeping the running counts.
+#[derive(Debug, Clone)]
+pub struct Tracer {
+    dial: Dial,
+    moves: usize,
+    landings: u64,
+    passes: u64,
+}
+
+impl Tracer {
+    pub fn new(dial: Dial) -> Self {
+        Self {
+            dial,
+            moves: 0,
+            landings: 0,
+            passes: 0,
+        }
+    }
+
+    pub fn dial(&self) -> &Dial {
+        &self.dial
+    }
+
+    pub fn step(&mut self, is_left: bool, distance: u64) -> Step {
+        let before = self.dial.position();
+        let turn = self.dial.turn(is_left, distance);
+        self.moves += 1;
+        self.landings += turn.landed as u64;
+        self.passes += turn.hits;
+        Step {
+            number: self.moves,
+            is_left,
+            distance,
+            before,
+            turn,
+            landings: self.landings,
+            passes: self.passes,
+        }
+    }
+
+    /// Landings on a marker so far: part 1 of day 1.
+    pub fn landings(&self) -> u64 {
+        self.landings
+    }
+
+    /// Clicks at a marker so far: part 2 of day 1.
+    pub fn passes(&self) -> u64 {
+        self.passes
+    }
+}
+
+/// The dial as a ring of points, with 0 at the top and right turns going clockwise, and
+/// its position in the middle. `#` is the point the dial is at, `o` a point with a marker,
+/// `@` both, and `.` any other.
+pub fn ring(dial: &Dial) -> String {
+    let (size, position) = (dial.size(), dial.position());
+    let slots = size.min(SLOTS);
+    let mut grid = vec![vec![' '; 4 * RADIUS + 1]; 2 * RADIUS + 1];
+    for slot in 0..slots {
+        let first = (slot as u128 * size as u128 / slots as u128) as u64;
+        let next = ((slot + 1) as u128 * size as u128 / slots as u128) as u64;
+        let point = match (
+            (first..next).contains(&position),
+            dial.markers_in(first, next - first) > 0,
+        ) {
+            (true, true) => '@',
+            (true, false) => '#',
+            (false, true) => 'o',
+            (false, false) => '.',
+        };
+        let angle = TAU * slot as f64 / slots as f64;
+        let row = (RADIUS as f64 * (1.0 - angle.cos())).round() as usize;
+        let column = (2.0 * RADIUS as f64 * (1.0 + angle.sin())).round() as usize;
+        grid[row][column] = point;
+    }
+    let label: Vec<char> = position.to_string().chars().collect();
+    let start = (4 * RADIUS + 1 - label.len()) / 2;
+    grid[RADIUS][start..start + label.len()].copy_from_slice(&label);
+
+    let mut rendered = String::new();
+    for row in grid {
+        let line: String = row.into_iter().collect();
+        rendered += line.trim_end();
+        rendered.push('\n');
+    }
+    rendered
+}
+
+/// The closing line of a trace.
+pub fn totals(tracer: &Tracer) -> String {
+    format!(
+        "Total: {} landings (part 1), {} passes (part 2)",
+        tracer.landings(),
+        tracer.passes()
+    )
+}
+
+/// Write the ring before the first move, a line per move, then the ring after the last.
+pub fn write(
+    out: &mut impl Write,
+    dial: Dial,
+    moves: impl IntoIterator<Item = (bool, u64)>,
+) -> io::Result<Tracer> {
+    let mut tracer = Tracer::new(dial);
+    write!(out, "{}", ring(tracer.dial()))?;
+    for (is_left, distance) in moves {
+        writeln!(out, "{}", tracer.step(is_left, distance))?;
+    }
+    write!(out, "{}", ring(tracer.dial()))?;
+    writeln!(out, "{}", totals(&tracer))?;
+    Ok(tracer)
+}
+
+/// Play the moves back as frames `delay` apart, each clearing the terminal and drawing the
+/// ring under the move that led to it.
+pub fn animate(
+    out: &mut impl Write,
+    dial: Dial,
+    moves: impl IntoIterator<Item = (bool, u64)>,
+    delay: Duration,
+) -> io::Result<Tracer> {
+    let mut tracer = Tracer::new(dial);
+    write!(out, "{CLEAR}start\n{}", ring(tracer.dial()))?;
+    out.flush()?;
+    for (is_left, distance) in moves {
+        std::thread::sleep(delay);
+        let step = tracer.step(is_left, distance);
+        write!(out, "{CLEAR}{step}\n{}", ring(tracer.dial()))?;
+        out.flush()?;
+    }
+    writeln!(out, "{}", totals(&tracer))?;
+    Ok(tracer)
+}
+
+/// Trace day 1's dial, as set up by `aoc.toml` and `overrides`, through the moves in
+/// `source` to `out`, playing it back as an animation when there is a `delay` between
+/// frames.
+pub fn run(
+    out: &mut impl Write,
+    source: &InputSource,
+    overrides: &[(String, String)],
+    delay: Option<Duration>,
+) -> Result<Tracer, String> {
+    let (dial, moves) = day01::load_with(source, overrides, day01::parse_moves)?;
+    let moves = moves
+        .into_iter()
+        .map(|(is_left, distance)| (is_left, distance as u64));
+    match delay {
+        Some(delay) => animate(out, dial, moves, delay),
+        None => write(out, dial, moves),
+    }
+    .map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::temp::Temp;
+
+    use super::*;
+
+    fn dial(size: u64, start: u64, markers: &[u64]) -> Dial {
+        Dial::new(size, start, markers.iter().copied()).unwrap()
+    }
+
+    #[test]
+    fn test_step() {
+        let mut tracer = Tracer::new(dial(100, 0, &[0]));
+        // Starting on zero and turning away does not count it again.
+        let step = tracer.step(true, 5);
+        assert_eq!(
+            (step.before, step.turn.position, step.turn.hits),
+            (0, 95, 0)
+        );
+        let step = tracer.step(false, 1005);
+        assert_eq!(
+            (step.before, step.turn.position, step.turn.hits),
+            (95, 0, 11)
+        );
+        assert_eq!(
+            step.to_string(),
+            "    2  R1005    95 -> 0     11 zeros, landed  (11 passes, 1 landings)"
+        );
+        assert_eq!((tracer.landings(), tracer.passes()), (1, 11));
+        assert_eq!(
+            tracer.step(true, 68).to_string(),
+            "    3  L68       0 -> 32     0 zeros  (11 passes, 1 landings)"
+        );
+    }
+
+    #[test]
+    fn test_ring() {
+        assert_eq!(
+            ring(&dial(4, 1, &[0])),
+            "            o\n\n\n\n\n\n.           1           #\n\n\n\n\n\n            .\n"
+        );
+        assert_eq!(
+            ring(&dial(4, 0, &[0, 2])),
+            "            @\n\n\n\n\n\n.           0           .\n\n\n\n\n\n            o\n"
+        );
+        // Every position of a larger dial shows up as exactly one point, and no two points
+        // of the ring share a cell.
+        for size in [100, 1 << 40] {
+            for position in [0, 1, 2, size / 3, size / 2, size - 1] {
+                let rendered = ring(&dial(size, position, &[size / 2]));
+                let count = |point| rendered.chars().filter(|&c| c == point).count();
+                assert_eq!(count('#') + count('@'), 1, "{size} {position}");
+                assert_eq!(count('o') + count('@'), 1, "{size} {position}");
+                assert_eq!(count('.') + count('o') + count('#') + count('@'), 36);
+                assert!(rendered.contains(&position.to_string()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_write() {
+        let mut out = Vec::new();
+        let moves = [(true, 68), (true, 30), (false, 48)];
+        let tracer = write(&mut out, dial(100, 50, &[0]), moves).unwrap();
+        assert_eq!((tracer.landings(), tracer.passes()), (1, 2));
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().filter(|line| line.contains("->")).collect();
+        assert_eq!(
+            lines,
+            [
+                "    1  L68      50 -> 82     1 zeros  (1 passes, 0 landings)",
+                "    2  L30      82 -> 52     0 zeros  (1 passes, 0 landings)",
+                "    3  R48      52 -> 0      1 zeros, landed  (2 passes, 1 landings)",
+            ]
+        );
+        assert!(out.starts_with(&ring(&dial(100, 50, &[0]))));
+        assert!(out.ends_with(&format!(
+            "{}Total: 1 landings (part 1), 2 passes (part 2)\n",
+            ring(&dial(100, 0, &[0]))
+        )));
+    }
+
+    #[test]
+    fn test_run() {
+        let input = Temp::file("trace-run.txt", "L68\nR1000\n");
+        let source = InputSource::Path(input.to_path_buf());
+        let mut out = Vec::new();
+        let tracer = run(&mut out, &source, &[], None).unwrap();
+        assert_eq!((tracer.landings(), tracer.passes()), (0, 11));
+        assert!(String::from_utf8(out).unwrap().contains("    2  R1000"));
+        let markers = [("markers".to_string(), "x".to_string())];
+        assert_eq!(
+            run(&mut Vec::new(), &source, &markers, None).unwrap_err(),
+            "parameter `markers`: `x` is not a number"
+        );
+    }
+
+    #[test]
+    fn test_animate() {
+        let mut out = Vec::new();
+        let moves = [(true, 68), (false, 18)];
+        let tracer = animate(&mut out, dial(100, 50, &[0]), moves, Duration::ZERO).unwrap();
+        assert_eq!((tracer.landings(), tracer.passes()), (1, 2));
+        let out = String::from_utf8(out).unwrap();
+        let frames: Vec<&str> = out.split(CLEAR).skip(1).collect();
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0], format!("start\n{}", ring(&dial(100, 50, &[0]))));
+        assert!(frames[1].starts_with("    1  L68"));
+        assert!(frames[2].ends_with("Total: 1 landings (part 1), 2 passes (part 2)\n"));
+    }
+}